@@ -75,6 +75,8 @@
 
     /**
      * @dev Transfer tokens from one address to another
+     * Reverts once the deadline of the approval, set with approveWithDeadline of the assets
+     * factory, has passed.
      * Selector: 23b872dd
      * @param from address The address which you want to send tokens from
      * @param to address The address which you want to transfer to
@@ -0,0 +1,105 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Deadlines of the approvals of the assets, after which the delegated transfers revert.
+//!
+//! `pallet_assets` approvals never expire, so the deadlines set through the assets factory are
+//! kept here, and enforced by the `transferFrom` of this precompile as well as by the delegated
+//! transfers of the factory. An approval made without a deadline removes the one of the previous
+//! approval. They are keyed by the index of the `pallet_assets` instance as well, so factories
+//! over different instances do not share the deadlines of assets with the same id.
+
+use crate::{instance_index, AssetIdOf};
+use fp_evm::PrecompileHandle;
+use precompile_utils::{
+	prelude::{revert, PrecompileHandleExt},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+use sp_runtime::SaturatedConversion;
+
+pub struct AllowanceDeadlineNames;
+impl StorageItemNames for AllowanceDeadlineNames {
+	const PRECOMPILE: &'static str = "Erc20Assets";
+	const ITEM: &'static str = "AllowanceDeadline";
+}
+
+/// Last block at which `spender` can transfer the asset of `owner`, by instance, asset, owner and
+/// spender.
+pub type AllowanceDeadlineOf<Runtime, Instance> = PrecompileStorageMap<
+	AllowanceDeadlineNames,
+	(
+		u8,
+		AssetIdOf<Runtime, Instance>,
+		<Runtime as frame_system::Config>::AccountId,
+		<Runtime as frame_system::Config>::AccountId,
+	),
+	u64,
+>;
+
+/// Returns the deadline of the approval of `owner` to `spender`, if any.
+pub fn get<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	owner: Runtime::AccountId,
+	spender: Runtime::AccountId,
+) -> EvmResult<Option<u64>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: AllowanceDeadline
+	handle.record_db_read::<Runtime>(
+		AllowanceDeadlineOf::<Runtime, Instance>::entry_max_encoded_len(),
+	)?;
+
+	Ok(AllowanceDeadlineOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+		owner,
+		spender,
+	)))
+}
+
+/// Sets the deadline of the approval of `owner` to `spender`, removing it if `deadline` is `None`.
+pub fn set<Runtime, Instance>(
+	asset_id: AssetIdOf<Runtime, Instance>,
+	owner: Runtime::AccountId,
+	spender: Runtime::AccountId,
+	deadline: Option<u64>,
+) where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	AllowanceDeadlineOf::<Runtime, Instance>::set(
+		&(instance_index::<Runtime, Instance>(), asset_id, owner, spender),
+		deadline.as_ref(),
+	);
+}
+
+/// Reverts if the approval of `owner` to `spender` has a deadline before the current block.
+pub fn ensure_not_expired<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	owner: Runtime::AccountId,
+	spender: Runtime::AccountId,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	if let Some(deadline) = get::<Runtime, Instance>(handle, asset_id, owner, spender)? {
+		if current_block::<Runtime>() > deadline {
+			return Err(revert("allowance expired"))
+		}
+	}
+
+	Ok(())
+}
+
+/// Number of the current block, compared with the deadlines.
+pub fn current_block<Runtime: frame_system::Config>() -> u64 {
+	frame_system::Pallet::<Runtime>::block_number().saturated_into()
+}
@@ -0,0 +1,77 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Pauses of single assets, set by their owners, blocking their mints, burns and transfers.
+//!
+//! Unlike the freezing of `pallet_assets`, which only blocks the transfers of non-admins, a pause
+//! blocks the mints, burns and transfers made through the precompiles, whoever makes them, and
+//! leaves the reads alone. The pauses are set through the assets factory, so they are kept here
+//! for this precompile to enforce them as well. They are keyed by the index of the
+//! `pallet_assets` instance as well, so factories over different instances do not share the
+//! pauses of assets with the same id.
+
+use crate::{instance_index, AssetIdOf};
+use fp_evm::PrecompileHandle;
+use precompile_utils::{
+	prelude::{revert, PrecompileHandleExt},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+
+pub struct AssetPausedNames;
+impl StorageItemNames for AssetPausedNames {
+	const PRECOMPILE: &'static str = "Erc20Assets";
+	const ITEM: &'static str = "AssetPaused";
+}
+
+/// Assets of a `pallet_assets` instance which are paused.
+pub type AssetPausedOf<Runtime, Instance> =
+	PrecompileStorageMap<AssetPausedNames, (u8, AssetIdOf<Runtime, Instance>), ()>;
+
+/// Returns whether `asset_id` is paused.
+pub fn is_paused<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<bool>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: AssetPaused
+	handle.record_db_read::<Runtime>(AssetPausedOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	Ok(AssetPausedOf::<Runtime, Instance>::contains_key(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	)))
+}
+
+/// Pauses `asset_id` or unpauses it.
+pub fn set<Runtime, Instance>(asset_id: AssetIdOf<Runtime, Instance>, paused: bool)
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	AssetPausedOf::<Runtime, Instance>::set(
+		&(instance_index::<Runtime, Instance>(), asset_id),
+		paused.then_some(&()),
+	);
+}
+
+/// Reverts if `asset_id` is paused.
+pub fn ensure_not_paused<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	if is_paused::<Runtime, Instance>(handle, asset_id)? {
+		return Err(revert("asset paused"))
+	}
+
+	Ok(())
+}
@@ -41,11 +41,12 @@ use fp_evm::{ExitError, PrecompileHandle};
 use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
 	sp_runtime::traits::StaticLookup,
+	storage::TransactionOutcome,
 	traits::{
 		fungibles::{
 			approvals::Inspect as ApprovalInspect, metadata::Inspect as MetadataInspect, Inspect,
 		},
-		OriginTrait,
+		OriginTrait, PalletInfoAccess,
 	},
 };
 use pallet_evm::AddressMapping;
@@ -82,6 +83,10 @@ use sp_std::{
 	marker::PhantomData,
 };
 
+pub mod allowance_deadline;
+pub mod asset_pause;
+pub mod transfer_fee;
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
@@ -99,6 +104,16 @@ pub type BalanceOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<I
 /// Alias for the Asset Id type for the provided Runtime and Instance.
 pub type AssetIdOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<Instance>>::AssetId;
 
+/// Index of the `pallet_assets` instance, keying the storage of the precompile so that assets with
+/// the same id in different instances do not share it.
+fn instance_index<Runtime, Instance>() -> u8
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	pallet_assets::Pallet::<Runtime, Instance>::index() as u8
+}
+
 /// The following distribution has been decided for the precompiles
 /// 0-1023: Ethereum Mainnet Precompiles
 /// 1024-2047 Precompiles that are not in Ethereum Mainnet but are neither Astar specific
@@ -264,14 +279,28 @@ where
 			// Dispatch call (if enough gas).
 			RuntimeHelper::<Runtime>::try_dispatch(
 				handle,
-				Some(origin).into(),
+				Some(origin.clone()).into(),
 				pallet_assets::Call::<Runtime, Instance>::approve_transfer {
-					id: asset_id.into(),
-					delegate: Runtime::Lookup::unlookup(spender),
+					id: asset_id.clone().into(),
+					delegate: Runtime::Lookup::unlookup(spender.clone()),
 					amount,
 				},
 				0,
 			)?;
+
+			// The new approval has no deadline, unlike the one it replaces might have had.
+			if allowance_deadline::get::<Runtime, Instance>(
+				handle,
+				asset_id.clone(),
+				origin.clone(),
+				spender.clone(),
+			)?
+			.is_some()
+			{
+				// Storage item: AllowanceDeadline
+				handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+				allowance_deadline::set::<Runtime, Instance>(asset_id, origin, spender, None);
+			}
 		}
 
 		LogsBuilder::new(handle.context().address)
@@ -286,6 +315,8 @@ where
 		Ok(true)
 	}
 
+	/// Transfers `amount` to `to`. If the asset has a transfer fee, its share of the amount is
+	/// transferred to the treasury of the fee instead, and logged as a second transfer.
 	#[precompile::public("transfer(address,uint256)")]
 	fn transfer(
 		asset_id: AssetIdOf<Runtime, Instance>,
@@ -294,40 +325,79 @@ where
 		amount: U256,
 	) -> EvmResult<bool> {
 		handle.record_log_costs_manual(3, 32)?;
+		asset_pause::ensure_not_paused::<Runtime, Instance>(handle, asset_id.clone())?;
 
 		let to: H160 = to.into();
 		let amount = Self::u256_to_amount(amount).in_field("value")?;
 
-		// Build call with origin.
-		{
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let to = Runtime::AddressMapping::into_account_id(to);
-
-			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
-				handle,
-				Some(origin).into(),
-				pallet_assets::Call::<Runtime, Instance>::transfer {
-					id: asset_id.into(),
-					target: Runtime::Lookup::unlookup(to),
-					amount,
-				},
-				SYSTEM_ACCOUNT_SIZE,
-			)?;
+		let (amount, fee) = transfer_fee::split_amount(
+			transfer_fee::get::<Runtime, Instance>(handle, asset_id.clone())?,
+			amount,
+		);
+		if fee.is_some() {
+			handle.record_log_costs_manual(3, 32)?;
 		}
 
+		let caller = handle.context().caller;
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = (|| -> EvmResult {
+				// Dispatch call (if enough gas).
+				RuntimeHelper::<Runtime>::try_dispatch(
+					handle,
+					Some(origin.clone()).into(),
+					pallet_assets::Call::<Runtime, Instance>::transfer {
+						id: asset_id.clone().into(),
+						target: Runtime::Lookup::unlookup(
+							Runtime::AddressMapping::into_account_id(to),
+						),
+						amount,
+					},
+					SYSTEM_ACCOUNT_SIZE,
+				)?;
+
+				if let Some((treasury, fee)) = fee {
+					// Dispatch call (if enough gas).
+					RuntimeHelper::<Runtime>::try_dispatch(
+						handle,
+						Some(origin).into(),
+						pallet_assets::Call::<Runtime, Instance>::transfer {
+							id: asset_id.into(),
+							target: Runtime::Lookup::unlookup(
+								Runtime::AddressMapping::into_account_id(treasury),
+							),
+							amount: fee,
+						},
+						SYSTEM_ACCOUNT_SIZE,
+					)?;
+				}
+
+				Ok(())
+			})();
+
+			if result.is_ok() {
+				TransactionOutcome::Commit(result)
+			} else {
+				TransactionOutcome::Rollback(result)
+			}
+		})?;
+
 		LogsBuilder::new(handle.context().address)
-			.log3(
-				SELECTOR_LOG_TRANSFER,
-				handle.context().caller,
-				to,
-				solidity::encode_event_data(amount),
-			)
+			.log3(SELECTOR_LOG_TRANSFER, caller, to, solidity::encode_event_data(amount))
 			.record(handle)?;
+		if let Some((treasury, fee)) = fee {
+			LogsBuilder::new(handle.context().address)
+				.log3(SELECTOR_LOG_TRANSFER, caller, treasury, solidity::encode_event_data(fee))
+				.record(handle)?;
+		}
 
 		Ok(true)
 	}
 
+	/// Transfers `amount` from `from` to `to`, out of the allowance of the caller unless it is
+	/// `from`. Like in `transfer`, the transfer fee of the asset, if any, is transferred to its
+	/// treasury out of the amount, and logged as a second transfer.
 	#[precompile::public("transferFrom(address,address,uint256)")]
 	fn transfer_from(
 		asset_id: AssetIdOf<Runtime, Instance>,
@@ -337,49 +407,94 @@ where
 		amount: U256,
 	) -> EvmResult<bool> {
 		handle.record_log_costs_manual(3, 32)?;
+		asset_pause::ensure_not_paused::<Runtime, Instance>(handle, asset_id.clone())?;
 
 		let from: H160 = from.into();
 		let to: H160 = to.into();
 		let amount = Self::u256_to_amount(amount).in_field("value")?;
 
+		let (amount, fee) = transfer_fee::split_amount(
+			transfer_fee::get::<Runtime, Instance>(handle, asset_id.clone())?,
+			amount,
+		);
+		if fee.is_some() {
+			handle.record_log_costs_manual(3, 32)?;
+		}
+
 		{
 			let caller: Runtime::AccountId =
 				Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let from: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from);
-			let to: Runtime::AccountId = Runtime::AddressMapping::into_account_id(to);
+			let owner: Runtime::AccountId = Runtime::AddressMapping::into_account_id(from);
 
 			// If caller is "from", it can spend as much as it wants from its own balance.
-			if caller != from {
-				// Dispatch call (if enough gas).
-				RuntimeHelper::<Runtime>::try_dispatch(
+			let approved = caller != owner;
+			if approved {
+				allowance_deadline::ensure_not_expired::<Runtime, Instance>(
 					handle,
-					Some(caller).into(),
-					pallet_assets::Call::<Runtime, Instance>::transfer_approved {
-						id: asset_id.into(),
-						owner: Runtime::Lookup::unlookup(from),
-						destination: Runtime::Lookup::unlookup(to),
-						amount,
-					},
-					SYSTEM_ACCOUNT_SIZE,
-				)?;
-			} else {
-				// Dispatch call (if enough gas).
-				RuntimeHelper::<Runtime>::try_dispatch(
-					handle,
-					Some(from).into(),
-					pallet_assets::Call::<Runtime, Instance>::transfer {
-						id: asset_id.into(),
-						target: Runtime::Lookup::unlookup(to),
-						amount,
-					},
-					SYSTEM_ACCOUNT_SIZE,
+					asset_id.clone(),
+					owner.clone(),
+					caller.clone(),
 				)?;
 			}
+
+			let transfer_call = |target: H160, amount| {
+				let target =
+					Runtime::Lookup::unlookup(Runtime::AddressMapping::into_account_id(target));
+				if approved {
+					(
+						caller.clone(),
+						pallet_assets::Call::<Runtime, Instance>::transfer_approved {
+							id: asset_id.clone().into(),
+							owner: Runtime::Lookup::unlookup(owner.clone()),
+							destination: target,
+							amount,
+						},
+					)
+				} else {
+					(
+						owner.clone(),
+						pallet_assets::Call::<Runtime, Instance>::transfer {
+							id: asset_id.clone().into(),
+							target,
+							amount,
+						},
+					)
+				}
+			};
+
+			frame_support::storage::with_transaction_unchecked(|| {
+				let result = (|| -> EvmResult {
+					for (target, amount) in sp_std::iter::once((to, amount)).chain(fee) {
+						let (origin, call) = transfer_call(target, amount);
+
+						// Dispatch call (if enough gas).
+						RuntimeHelper::<Runtime>::try_dispatch(
+							handle,
+							Some(origin).into(),
+							call,
+							SYSTEM_ACCOUNT_SIZE,
+						)?;
+					}
+
+					Ok(())
+				})();
+
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			})?;
 		}
 
 		LogsBuilder::new(handle.context().address)
 			.log3(SELECTOR_LOG_TRANSFER, from, to, solidity::encode_event_data(amount))
 			.record(handle)?;
+		if let Some((treasury, fee)) = fee {
+			LogsBuilder::new(handle.context().address)
+				.log3(SELECTOR_LOG_TRANSFER, from, treasury, solidity::encode_event_data(fee))
+				.record(handle)?;
+		}
 
 		// Build output.
 		Ok(true)
@@ -441,6 +556,7 @@ where
 		amount: U256,
 	) -> EvmResult<bool> {
 		handle.record_log_costs_manual(3, 32)?;
+		asset_pause::ensure_not_paused::<Runtime, Instance>(handle, asset_id.clone())?;
 
 		let addr: H160 = to.into();
 		let amount = Self::u256_to_amount(amount).in_field("value")?;
@@ -475,6 +591,7 @@ where
 		amount: U256,
 	) -> EvmResult<bool> {
 		handle.record_log_costs_manual(3, 32)?;
+		asset_pause::ensure_not_paused::<Runtime, Instance>(handle, asset_id.clone())?;
 
 		let addr: H160 = who.into();
 		let amount = Self::u256_to_amount(amount).in_field("value")?;
@@ -39,6 +39,7 @@ use std::str::from_utf8;
 
 use crate::{mock::*, *};
 
+use pallet_evm::Log;
 use precompile_utils::testing::*;
 // use precompile_utils::{prelude::LogsBuilder, testing::*, EvmDataWriter};
 use sha3::{Digest, Keccak256};
@@ -523,6 +524,202 @@ fn transfer_not_enough_founds() {
 		});
 }
 
+fn transfer_log(from: MockPeaqAccount, to: MockPeaqAccount, amount: u128) -> Log {
+	LogsBuilder::new(MockPeaqAccount::AssetId(MockAssetId(0u128)).into()).log3(
+		SELECTOR_LOG_TRANSFER,
+		from,
+		to,
+		solidity::encode_event_data(U256::from(amount)),
+	)
+}
+
+#[test]
+fn transfer_with_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let asset_id = MockAssetId(0u128);
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id,
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				asset_id,
+				MockPeaqAccount::Alice,
+				1000
+			));
+			transfer_fee::set::<Runtime, ()>(
+				asset_id,
+				Some(transfer_fee::TransferFee {
+					bps: 250,
+					treasury: MockPeaqAccount::Charlie.into(),
+				}),
+			);
+
+			let transfer = |amount: u128| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::AssetId(asset_id),
+					PCall::transfer {
+						to: Address(MockPeaqAccount::Bob.into()),
+						amount: U256::from(amount),
+					},
+				)
+			};
+
+			// 2.5% of the amount goes to the treasury.
+			transfer(400)
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::Bob, 390))
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 10))
+				.execute_returns(true);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Alice), 600);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Bob), 390);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 10);
+
+			// The fee is rounded down, and not transferred nor logged when it is zero.
+			transfer(39)
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::Bob, 39))
+				.execute_returns(true);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Bob), 429);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 10);
+
+			// Without a fee, the whole amount is transferred.
+			transfer_fee::set::<Runtime, ()>(asset_id, None);
+			transfer(100)
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100))
+				.execute_returns(true);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Bob), 529);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 10);
+		});
+}
+
+#[test]
+fn transfer_with_fee_reverts_entirely_on_failure() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let asset_id = MockAssetId(0u128);
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id,
+				MockPeaqAccount::Alice,
+				true,
+				20
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				asset_id,
+				MockPeaqAccount::Alice,
+				1000
+			));
+			transfer_fee::set::<Runtime, ()>(
+				asset_id,
+				Some(transfer_fee::TransferFee {
+					bps: 250,
+					treasury: MockPeaqAccount::Charlie.into(),
+				}),
+			);
+
+			// The fee is below the minimum balance, so the treasury cannot receive it.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::AssetId(asset_id),
+					PCall::transfer {
+						to: Address(MockPeaqAccount::Bob.into()),
+						amount: U256::from(400),
+					},
+				)
+				.execute_reverts(|output| from_utf8(output).unwrap().contains("BelowMinimum"));
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Alice), 1000);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Bob), 0);
+		});
+}
+
+#[test]
+fn paused_asset_blocks_mint_burn_and_transfers() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let paused = MockAssetId(0u128);
+			let other = MockAssetId(1u128);
+			for asset_id in [paused, other] {
+				assert_ok!(Assets::force_create(
+					RuntimeOrigin::root(),
+					asset_id,
+					MockPeaqAccount::Alice,
+					true,
+					1
+				));
+				assert_ok!(Assets::mint(
+					RuntimeOrigin::signed(MockPeaqAccount::Alice),
+					asset_id,
+					MockPeaqAccount::Alice,
+					1000
+				));
+			}
+			asset_pause::set::<Runtime, ()>(paused, true);
+
+			let bob = Address(MockPeaqAccount::Bob.into());
+			let inputs = [
+				PCall::transfer { to: bob, amount: U256::from(100) },
+				PCall::transfer_from {
+					from: Address(MockPeaqAccount::Alice.into()),
+					to: bob,
+					amount: U256::from(100),
+				},
+				PCall::mint { to: bob, amount: U256::from(100) },
+				PCall::burn {
+					who: Address(MockPeaqAccount::Alice.into()),
+					amount: U256::from(100),
+				},
+			];
+			for input in inputs {
+				precompiles
+					.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::AssetId(paused), input)
+					.execute_reverts(|output| output == b"asset paused");
+			}
+			assert_eq!(Assets::balance(paused, MockPeaqAccount::Alice), 1000);
+
+			// The reads are not paused.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::AssetId(paused),
+					PCall::balance_of { owner: Address(MockPeaqAccount::Alice.into()) },
+				)
+				.execute_returns(U256::from(1000));
+
+			// Nor are the other assets.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::AssetId(other),
+					PCall::transfer { to: bob, amount: U256::from(100) },
+				)
+				.execute_returns(true);
+
+			asset_pause::set::<Runtime, ()>(paused, false);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::AssetId(paused),
+					PCall::transfer { to: bob, amount: U256::from(100) },
+				)
+				.execute_returns(true);
+			assert_eq!(Assets::balance(paused, MockPeaqAccount::Bob), 100);
+		});
+}
+
 #[test]
 fn transfer_from() {
 	ExtBuilder::default()
@@ -616,6 +813,78 @@ fn transfer_from() {
 		});
 }
 
+#[test]
+fn transfer_from_with_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let asset_id = MockAssetId(0u128);
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id,
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				asset_id,
+				MockPeaqAccount::Alice,
+				1000
+			));
+			assert_ok!(Assets::approve_transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				asset_id,
+				MockPeaqAccount::Bob,
+				500
+			));
+			transfer_fee::set::<Runtime, ()>(
+				asset_id,
+				Some(transfer_fee::TransferFee {
+					bps: 250,
+					treasury: MockPeaqAccount::David.into(),
+				}),
+			);
+
+			let transfer_from = |caller: MockPeaqAccount, amount: u128| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::AssetId(asset_id),
+					PCall::transfer_from {
+						from: Address(MockPeaqAccount::Alice.into()),
+						to: Address(MockPeaqAccount::Charlie.into()),
+						amount: U256::from(amount),
+					},
+				)
+			};
+
+			// The fee is taken out of the allowance along with the amount.
+			transfer_from(MockPeaqAccount::Bob, 400)
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 390))
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::David, 10))
+				.execute_returns(true);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Alice), 600);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 390);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::David), 10);
+
+			// 99 fit in the 100 left, but not the fee of 2, so neither is transferred.
+			transfer_from(MockPeaqAccount::Bob, 101)
+				.execute_reverts(|output| from_utf8(output).unwrap().contains("Unapproved"));
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Alice), 600);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 390);
+
+			// The owner pays the fee when it transfers from itself as well.
+			transfer_from(MockPeaqAccount::Alice, 40)
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 39))
+				.expect_log(transfer_log(MockPeaqAccount::Alice, MockPeaqAccount::David, 1))
+				.execute_returns(true);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Alice), 560);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::David), 11);
+		});
+}
+
 #[test]
 fn transfer_from_non_incremental_approval() {
 	ExtBuilder::default()
@@ -807,6 +1076,79 @@ fn transfer_from_self() {
 		});
 }
 
+#[test]
+fn transfer_from_after_allowance_deadline() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 1000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let asset_id = MockAssetId(0u128);
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				asset_id,
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				asset_id,
+				MockPeaqAccount::Alice,
+				1000
+			));
+			assert_ok!(Assets::approve_transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				asset_id,
+				MockPeaqAccount::Bob,
+				500
+			));
+			// Deadlines are set through the assets factory.
+			allowance_deadline::set::<Runtime, ()>(
+				asset_id,
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::Bob,
+				Some(5),
+			);
+
+			let transfer_from = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::AssetId(asset_id),
+					PCall::transfer_from {
+						from: Address(MockPeaqAccount::Alice.into()),
+						to: Address(MockPeaqAccount::Charlie.into()),
+						amount: U256::from(100),
+					},
+				)
+			};
+
+			// The deadline block itself is still in the window.
+			System::set_block_number(5);
+			transfer_from().execute_returns(true);
+
+			System::set_block_number(6);
+			transfer_from()
+				.expect_no_logs()
+				.execute_reverts(|output| output == b"allowance expired");
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 100);
+
+			// Approving again without a deadline lifts it.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::AssetId(asset_id),
+					PCall::approve {
+						spender: Address(MockPeaqAccount::Bob.into()),
+						amount: U256::from(500),
+					},
+				)
+				.execute_returns(true);
+			transfer_from().execute_returns(true);
+			assert_eq!(Assets::balance(asset_id, MockPeaqAccount::Charlie), 200);
+		});
+}
+
 #[test]
 fn get_metadata() {
 	ExtBuilder::default()
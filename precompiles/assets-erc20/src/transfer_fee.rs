@@ -0,0 +1,108 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Fees taken on the transfers of the assets and sent to a treasury.
+//!
+//! The fees are set by the owners of the assets through the assets factory, which depends on
+//! this precompile, so they are kept here and the factory writes them with [`set`]. They are
+//! keyed by the index of the `pallet_assets` instance as well, so factories over different
+//! instances do not share the fees of assets with the same id.
+//!
+//! Every transfer made by holders or their spenders through the precompiles, i.e. the ERC20
+//! `transfer` and `transferFrom`, and the `transferApproved`, `transferAndTouch` and
+//! `batchTransfer` of the factory, splits its amount with [`split_amount`]. The transfers of the
+//! force origin and the ones made through the extrinsics of the pallet are not charged.
+
+use crate::{instance_index, AssetIdOf};
+use fp_evm::PrecompileHandle;
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use precompile_utils::{
+	prelude::PrecompileHandleExt,
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+use sp_core::H160;
+use sp_runtime::{
+	traits::{AtLeast32BitUnsigned, Zero},
+	PerThing, Permill,
+};
+
+/// Number of basis points in a whole.
+pub const BPS_DENOMINATOR: u16 = 10_000;
+
+/// Fee taken on the transfers of an asset.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode, MaxEncodedLen)]
+pub struct TransferFee {
+	/// Share of the transferred amount taken as fee, in basis points.
+	pub bps: u16,
+	/// Address the fees are transferred to.
+	pub treasury: H160,
+}
+
+impl TransferFee {
+	/// Splits `amount` into the amount received by the recipient and the fee, rounded down.
+	pub fn split<Balance: AtLeast32BitUnsigned + Copy>(
+		&self,
+		amount: Balance,
+	) -> (Balance, Balance) {
+		let bps = self.bps.min(BPS_DENOMINATOR);
+		let fee = Permill::from_parts(u32::from(bps) * 100).mul_floor(amount);
+
+		(amount - fee, fee)
+	}
+}
+
+pub struct TransferFeeNames;
+impl StorageItemNames for TransferFeeNames {
+	const PRECOMPILE: &'static str = "Erc20Assets";
+	const ITEM: &'static str = "TransferFee";
+}
+
+/// Transfer fee of an asset of a `pallet_assets` instance.
+pub type TransferFeeOf<Runtime, Instance> =
+	PrecompileStorageMap<TransferFeeNames, (u8, AssetIdOf<Runtime, Instance>), TransferFee>;
+
+/// Returns the transfer fee of `asset_id`, if any.
+pub fn get<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<Option<TransferFee>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: TransferFee
+	handle.record_db_read::<Runtime>(TransferFeeOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	Ok(TransferFeeOf::<Runtime, Instance>::get(&(instance_index::<Runtime, Instance>(), asset_id)))
+}
+
+/// Splits `amount` transferred of an asset whose transfer fee is `fee` into the amount received
+/// by the recipient and, unless the asset has no fee or it is rounded down to zero, the treasury
+/// and the fee it receives.
+pub fn split_amount<Balance: AtLeast32BitUnsigned + Copy>(
+	fee: Option<TransferFee>,
+	amount: Balance,
+) -> (Balance, Option<(H160, Balance)>) {
+	match fee {
+		Some(fee) => {
+			let (amount, fee_amount) = fee.split(amount);
+			(amount, Some((fee.treasury, fee_amount)).filter(|(_, fee)| !fee.is_zero()))
+		},
+		None => (amount, None),
+	}
+}
+
+/// Sets the transfer fee of `asset_id`, removing it if `fee` is `None`.
+pub fn set<Runtime, Instance>(asset_id: AssetIdOf<Runtime, Instance>, fee: Option<TransferFee>)
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	TransferFeeOf::<Runtime, Instance>::set(
+		&(instance_index::<Runtime, Instance>(), asset_id),
+		fee.as_ref(),
+	);
+}
@@ -10,26 +10,249 @@ AssetFactory constant ASSET_FACTORY_CONTRACT = AssetFactory(ASSET_FACTORY_ADDRES
 /// @author The Peaq Team
 /// @title AssetFactory Interface
 /// The interface through which solidity contracts will interact with xcm utils pallet
+/// The methods on an existing asset revert with "id: asset not found" if it does not exist
+/// Without enough gas for a dispatched call, the methods revert with "out of gas: dispatch needs
+/// <gas> gas", worth retrying with more gas unlike the other reverts
+/// While the runtime migrates the storage of the assets, the methods which are not views revert
+/// with "pallet migrating, try later", worth retrying in a later block
 /// @custom:address 0x0000000000000000000000000000000000000806
 interface AssetFactory {
 
+    /// Kind of an address, and id of the asset it belongs to if any
+    struct AddressClass {
+        uint8 kind;
+        uint64 id;
+    }
+
+    /// Reference counts of an asset
+    struct AccountCounts {
+        /// Accounts holding the asset
+        uint32 accounts;
+        /// Accounts existing because the asset is sufficient
+        uint32 sufficients;
+        uint32 approvals;
+    }
+
+    /// Details and metadata of an asset, zeroed if it does not exist
+    struct TokenInfo {
+        bool exists;
+        bytes name;
+        bytes symbol;
+        uint8 decimals;
+        uint256 totalSupply;
+        uint256 minBalance;
+        /// 0 if live, 1 if frozen, 2 if being destroyed
+        uint8 status;
+    }
+
+    /// Last failure of an atomic method in the transaction
+    /// Number of calls to a method
+    struct MethodCalls {
+        uint32 selector;
+        uint32 calls;
+    }
+
+    /// Calls to the mutating methods in the current block
+    struct CallStats {
+        uint64 block;
+        /// Calls to all the mutating methods
+        uint32 total;
+        /// Calls by method, sorted by selector, leaving out the methods not called
+        MethodCalls[] methods;
+    }
+
+    /// State kept by the precompiles about an asset besides the one of pallet-assets, zeroed
+    /// where nothing is kept
+    struct ExtendedConfig {
+        uint256 supplyCap;
+        uint16 transferFeeBps;
+        address transferFeeTreasury;
+        /// Account which reserved the id, and the block it did, even if the reservation expired
+        bytes32 reservedBy;
+        uint64 reservedAt;
+        uint64 destroyTokenIssuedAt;
+        uint64 destroyStartedAt;
+        bool paused;
+    }
+
+    /// Parameters of safeLaunch
+    struct LaunchParams {
+        uint64 id;
+        address admin;
+        uint128 minBalance;
+        bytes name;
+        bytes symbol;
+        uint8 decimals;
+        address issuer;
+        address freezer;
+        /// Minted by the caller, which must then be the issuer. Nothing is minted if zero
+        uint256 initialSupply;
+        address initialHolder;
+    }
+
+    struct LastError {
+        /// 0 if there was none, 1 if it is a revert, 2 if it is an EVM error like out of gas
+        uint8 code;
+        /// Reason of the revert, or name of the EVM error
+        bytes message;
+    }
+
     /// Get the address of the asset with the given id
     /// selector: a70174cb
     function convertAssetIdToAddress(uint64 id) external view returns (address);
 
-    /// Issue a new class of fungible assets from a public origin
+    /// Get the address of the ERC20 precompile the runtime wires for the asset with the given
+    /// id, reverting if it wires none
+    /// selector: 1a296d66
+    function erc20Address(uint64 id) external view returns (address);
+
+    /// Number of assets created through the factory, by create or forceCreate, and not destroyed
+    /// through it
+    /// selector: eafe7a74
+    function assetCount() external view returns (uint32);
+
+    /// Whether an address is the one of an existing local asset (kind 1), cross-chain asset
+    /// (kind 2) or neither (kind 0), along with the id of the asset
+    /// selector: 5b046ea2
+    function classifyAddress(address addr) external view returns (AddressClass memory);
+
+    /// Whether a given address is the one of an existing asset which is live, neither frozen nor
+    /// being destroyed
+    /// selector: 17fef253
+    function addressIsLiveAsset(address addr) external view returns (bool);
+
+    /// The account the caller's address is mapped to, and on behalf of which calls are dispatched
+    /// selector: 49e71f1d
+    function callerAccount() external view returns (bytes32);
+
+    /// Address owning an asset, along with its raw account. The address is zero where the account
+    /// cannot be mapped back to an address, e.g. when it is derived by hashing its address
+    /// selector: 7903a617
+    function ownerOf(uint64 id) external view returns (address owner, bytes32 account);
+
+    /// The chain id of the EVM, e.g. for the domain of EIP-712 signatures
+    /// selector: 9a8a0592
+    function chainId() external view returns (uint64);
+
+    /// The decimals setMetadataDefaults sets
+    /// selector: 30f08abd
+    function defaultDecimals() external view returns (uint8);
+
+    /// Whether setTeam rejects a team in which an account has several roles
+    /// selector: a9a85140
+    function rolesMustBeDistinct() external view returns (bool);
+
+    /// How the amounts not fitting the balance type are handled, 1 as they are rejected rather
+    /// than saturated (0)
+    /// selector: 394b6c5a
+    function amountConversionMode() external view returns (uint8);
+
+    /// The deposit reserved for an account of an asset when it is touched, e.g. by
+    /// transferAndTouch
+    /// selector: bb384740
+    function accountDeposit() external view returns (uint256);
+
+    /// The lowest minimum balance create and setMinBalance accept, zero if there is no floor.
+    /// Below it, they revert with "min_balance below floor"
+    /// selector: 29801d91
+    function minBalanceFloor() external view returns (uint128);
+
+    /// Issue a new class of fungible assets from a public origin. The asset deposit is reserved
+    /// from the caller, which must be able to afford it
     /// selector: 9c28547e
     function create(uint64 id, address admin, uint128 minBalance) external;
 
+    /// Issue a new class of fungible assets from a public origin, with the caller as its admin
+    /// selector: 1afcff92
+    function createSelfAdmin(uint64 id, uint128 minBalance) external;
+
+    /// Issue a new class of fungible assets from a public origin, succeeding without doing anything
+    /// if the asset already exists with the same admin and minimum balance
+    /// selector: 5c2597b7
+    function createIdempotent(uint64 id, address admin, uint128 minBalance) external;
+
+    /// Reserve the id of an asset which does not exist yet, so that only the caller can create it
+    /// for a number of blocks configured by the runtime. Creating the asset from another account
+    /// reverts with "id reserved by another account" until the reservation expires. Reservations
+    /// cannot be renewed, and an account holds a number of unexpired reservations at most
+    /// selector: 8ff49ee6
+    function reserveId(uint64 id) external;
+
+    /// Issue a new class of fungible assets and set its metadata atomically. If setting the
+    /// metadata fails, the asset is not created either
+    /// selector: 206bfee8
+    function createWithMetadata(
+        uint64 id,
+        address admin,
+        uint128 minBalance,
+        bytes memory name,
+        bytes memory symbol,
+        uint8 decimals
+    ) external;
+
+    /// Issue a new class of fungible assets for each of the ids, sharing the admin, minimum
+    /// balance and decimals and named after the name and symbol of the same index. If any of them
+    /// fails, none is created
+    /// selector: b9be431f
+    function createFromTemplate(
+        uint64[] memory ids,
+        bytes[] memory names,
+        bytes[] memory symbols,
+        address admin,
+        uint128 minBalance,
+        uint8 decimals
+    ) external;
+
+    /// Issue a new class of fungible assets and set its whole team atomically. If setting the
+    /// team fails, the asset is not created either
+    /// selector: fb589a2d
+    function createWithTeam(
+        uint64 id,
+        address admin,
+        uint128 minBalance,
+        address issuer,
+        address freezer
+    ) external;
+
+    /// Create an asset, set its metadata and team and mint its initial supply atomically, after
+    /// checking the parameters and that the caller can afford the deposits. If a step fails,
+    /// nothing is launched and the revert reason is prefixed with the step
+    /// selector: f2445571
+    function safeLaunch(LaunchParams memory params) external;
+
     /// Set the metadata for a given asset
     /// selector: f96ee86d
     function setMetadata(uint64 id, bytes memory name, bytes memory symbol, uint8 decimal) external;
 
-    /// Set the minimum balance for a given asset
+    /// Set the metadata for a given asset, with the default decimals of the runtime
+    /// selector: 87e3eed5
+    function setMetadataDefaults(uint64 id, bytes memory name, bytes memory symbol) external;
+
+    /// Set the name and symbol of a given asset, keeping the decimals of its current metadata,
+    /// reverting if it has none
+    /// selector: 9f41d4a2
+    function rename(uint64 id, bytes memory name, bytes memory symbol) external;
+
+    /// Set the metadata of a given asset to the one of another asset, both owned by the caller,
+    /// reverting if the latter has none
+    /// selector: ca14fa7c
+    function copyMetadata(uint64 from, uint64 to) external;
+
+    /// Set the minimum balance for a given asset, reverting if it does not fit the balance type
     /// selector: 28bfefa1
     function setMinBalance(uint64 id, uint128 minBalance) external;
 
-    /// Set the issuer, Admin and Freezer of a given asset
+    /// Set the minimum balance of each of the ids to the value of the same index. If any of them
+    /// fails, none is changed and the revert reason is prefixed with the index
+    /// selector: 91b14ce2
+    function setMinBalanceBatch(uint64[] memory ids, uint128[] memory minBalances) external;
+
+    /// Number of assets an account can still create in the current block
+    /// selector: dfc3dab8
+    function createsRemaining(address account) external view returns (uint32);
+
+    /// Set the issuer, Admin and Freezer of a given asset, emitting TeamChanged. Setting the
+    /// current team does nothing. Reverts with "roles must be distinct" if the chain requires it and an account has two roles
     /// selector: b6e6b7d4
     function setTeam(uint64 id, address issuer, address admin, address freezer) external;
 
@@ -37,11 +260,333 @@ interface AssetFactory {
     /// selector: 0a94864e
     function transferOwnership(uint64 id, address owner) external;
 
-     /// Start the process of destroying a fungible asset class
+    /// Transfer ownership of a given asset to a contract, reverting if the target has no code
+    /// selector: d17e790a
+    function transferOwnershipToContract(uint64 id, address contractAddr) external;
+
+    /// Set the team of a given asset and transfer its ownership atomically, so that the previous
+    /// team never controls the asset of the new owner. If either fails, neither is done
+    /// selector: c20ea395
+    function transferOwnershipAndSetTeam(
+        uint64 id,
+        address newOwner,
+        address issuer,
+        address admin,
+        address freezer
+    ) external;
+
+     /// Start the process of destroying a fungible asset class, returning the confirmation token
+    /// finishDestroy requires. Called by the owner on an asset already being destroyed, it
+    /// issues a new token
     /// selector: 13f946af
-    function startDestroy(uint64 id) external;
+    function startDestroy(uint64 id) external returns (bytes32);
+
+     /// Complete destroying asset and unreserve currency, given the unexpired confirmation token
+    /// returned by startDestroy
+    /// selector: acd19595
+    function finishDestroy(uint64 id, bytes32 token) external;
+
+    /// Block the destruction of a given asset started in, zero if it is not being destroyed or if
+    /// it was not started through the factory
+    /// selector: 8bbe21c7
+    function destroyStartedAt(uint64 id) external view returns (uint64);
+
+    /// Destroy the accounts and approvals of an asset being destroyed, then finish its destruction.
+    /// At most maxIterations steps are run, returning false if the destruction is not finished yet
+    /// Only callable by the force origin, with the confirmation token returned by startDestroy
+    /// selector: 4430b8fa
+    function forceFinishDestroy(uint64 id, bytes32 token, uint32 maxIterations) external returns (bool);
+
+    /// Block an account of a given asset, disallowing it to send or receive the asset
+    /// selector: 63422462
+    function block(uint64 id, address who) external;
+
+    /// Disallow further transfers of an asset from a given account, only callable by its freezer
+    /// selector: 4d1a1635
+    function freeze(uint64 id, address who) external;
+
+    /// Allow again transfers of an asset from a frozen account, only callable by its admin
+    /// selector: ef33e57a
+    function thaw(uint64 id, address who) external;
+
+    /// Disallow further non-admin transfers of an asset, only callable by its freezer
+    /// selector: fdade1d5
+    function freezeAsset(uint64 id) external;
+
+    /// Allow again non-admin transfers of a frozen asset, only callable by its admin
+    /// selector: c8ae38b6
+    function thawAsset(uint64 id) external;
+
+    /// Pause or unpause the minting, burning and transfers of an asset, only callable by its owner
+    /// selector: 13a30b0d
+    function setAssetPaused(uint64 id, bool paused) external;
+
+    /// Whether an asset is paused by its owner
+    /// selector: b1213295
+    function assetPaused(uint64 id) external view returns (bool);
+
+    /// Mint some assets to a beneficiary, within the supply cap of the asset if it has one,
+    /// emitting Minted
+    /// selector: f2be4599
+    function mint(uint64 id, address beneficiary, uint256 amount) external;
+
+    /// Burn some assets held by an account, only callable by the admin of the asset. Reverts with
+    /// "asset frozen" or "account frozen" if the asset or the account is frozen
+    /// selector: a4fa3313
+    function burn(uint64 id, address who, uint256 amount) external;
+
+    /// Mint amounts[i] to recipients[i] for every i, atomically. The total is checked once against
+    /// the supply cap of the asset if it has one. An empty batch is a no-op. Unless the runtime
+    /// opts out of the batch events, the Minted events are emitted in the order of the recipients
+    /// selector: 3c2dcdcf
+    function batchMint(uint64 id, address[] memory recipients, uint256[] memory amounts) external;
+
+    /// Cap the supply mints through the factory can reach, only callable by the owner of the asset.
+    /// A cap of zero removes it, any other cap cannot be below the current supply. Emits
+    /// SupplyCapChanged
+    /// selector: ab1c3510
+    function setSupplyCap(uint64 id, uint256 cap) external;
+
+    /// The supply cap of a given asset, zero if it is not capped
+    /// selector: 84e0e2ad
+    function supplyCap(uint64 id) external view returns (uint256);
+
+    /// Set the share, in basis points, of the transfers of a given asset sent to the treasury as
+    /// fee, only callable by the owner of the asset. A fee of zero removes it. The fee is taken
+    /// on the ERC20 transfer and transferFrom, and on transferApproved, transferAndTouch and
+    /// batchTransfer, but not on the force transfers
+    /// selector: 6947288f
+    function setTransferFeeBps(uint64 id, uint16 bps) external;
+
+    /// The transfer fee of a given asset in basis points, zero if it has none
+    /// selector: 49b7940b
+    function transferFeeBps(uint64 id) external view returns (uint16);
+
+    /// All the state kept by the precompiles about a given asset, which does not have to exist
+    /// selector: 1b48dd4d
+    function extendedConfig(uint64 id) external view returns (ExtendedConfig memory);
+
+    /// Numbers of accounts, sufficient accounts and approvals of a given asset
+    /// selector: 39d54988
+    function accountCounts(uint64 id) external view returns (AccountCounts memory);
+
+    /// Name of a given asset, empty if it has no metadata
+    /// selector: 20fc811e
+    function name(uint64 id) external view returns (bytes memory);
+
+    /// Symbol of a given asset, empty if it has no metadata
+    /// selector: 7904fe8c
+    function symbol(uint64 id) external view returns (bytes memory);
+
+    /// Decimals of a given asset, zero if it has no metadata
+    /// selector: ff01a6f7
+    function decimals(uint64 id) external view returns (uint8);
+
+    /// Details and metadata of each of the given assets, with exists set to false for the ones
+    /// which do not exist
+    /// selector: 6120b296
+    function tokenInfoBatch(uint64[] memory ids) external view returns (TokenInfo[] memory);
+
+    /// Last failure of batchMint, batchTransfer, createFromTemplate, createWithMetadata,
+    /// createWithTeam, safeLaunch, setMinBalanceBatch, transferAndTouch or transferOwnershipAndSetTeam in the current transaction, kept after their revert so that a contract catching it can read it
+    /// selector: 29f0de3f
+    function lastError() external view returns (LastError memory);
+
+    /// Number of calls to each mutating method in the current block, the counts starting over
+    /// every block
+    /// selector: 9e5de11e
+    function callStats() external view returns (CallStats memory);
+
+    /// Selectors of the methods of the factory, sorted, which depend on the features it is
+    /// compiled with
+    /// selector: 8d057a25
+    function supportedSelectors() external view returns (bytes4[] memory);
+
+    /// Whether the runtime is configured soundly for the factory, which is whether its default
+    /// metadata fits the string limit, its minimum balance floor fits the balance type, and its
+    /// maximum transfer fee is at most the whole of the amount
+    /// selector: 1785e185
+    function selfCheck() external view returns (bool);
+
+    /// Bitmask of the operations a given caller is allowed to perform on a given asset: 1 to
+    /// create it, 2 to mint, 4 to burn, 8 to transfer, 16 to set its metadata and 32 to destroy it
+    /// selector: fcb1d7ae
+    function permissions(uint64 id, address caller) external view returns (uint8);
+
+    /// Whether an account of a given asset is blocked
+    /// selector: d1dae163
+    function isBlocked(uint64 id, address who) external view returns (bool);
+
+    /// Asset and metadata deposits of a given asset reserved from an account
+    /// selector: 51c6b1e2
+    function reservedDepositOf(uint64 id, address account) external view returns (uint256);
+
+    /// Total deposit reserved from an owner for its approvals of a given asset
+    /// selector: e45eeaae
+    function approvalDepositOf(uint64 id, address owner) external view returns (uint256);
+
+    /// Transfer some assets, touching the destination account first at the caller's expense if
+    /// it does not exist yet. Touching requires the caller to be the admin or freezer of the asset
+    /// selector: a58745dd
+    function transferAndTouch(uint64 id, address to, uint256 amount) external;
+
+    /// Transfer some assets to many recipients at once, reverting all transfers if one fails.
+    /// Whether a Transfer event is emitted per recipient is configured by the runtime, in which
+    /// case they are emitted in the order of the recipients. An empty batch is a no-op
+    /// selector: adf58a37
+    function batchTransfer(
+        uint64 id,
+        address[] memory recipients,
+        uint256[] memory amounts
+    ) external;
+
+    /// Transfer some assets to many recipients at once, emitting the Transfer events only if
+    /// emitEvents is true, which saves their gas otherwise
+    /// selector: 1191da99
+    function batchTransfer(
+        uint64 id,
+        address[] memory recipients,
+        uint256[] memory amounts,
+        bool emitEvents
+    ) external;
+
+    /// Approve a spender to transfer some assets of the caller until a given block included,
+    /// replacing its previous approval. Emits an Approval event at the address of the asset
+    /// selector: d86d35ae
+    function approveWithDeadline(
+        uint64 id,
+        address spender,
+        uint256 amount,
+        uint64 deadlineBlock
+    ) external;
+
+    /// Transfer some assets of an owner approved to the caller, reverting once the deadline of
+    /// the approval has passed. Emits a Transfer event at the address of the asset
+    /// selector: 81051e8a
+    function transferApproved(
+        uint64 id,
+        address owner,
+        address destination,
+        uint256 amount
+    ) external;
+
+    /// Cancel the approval of the caller to a spender along with its deadline, including an
+    /// expired one
+    /// selector: 1074f6ea
+    function cancelApproval(uint64 id, address spender) external;
+
+    /// Last block at which a spender can transfer the assets approved by an owner, 0 if the
+    /// approval has no deadline
+    /// selector: db398b0a
+    function allowanceDeadline(
+        uint64 id,
+        address owner,
+        address spender
+    ) external view returns (uint64);
+
+    /// Approve a spender to transfer some assets of an owner who signed the approval as an
+    /// EIP-2612 permit, valid until the timestamp deadline in seconds. Anyone can submit the
+    /// permit, which replaces the previous approval. Signatures with a high s are rejected. Emits an
+    /// Approval event at the address of the asset
+    /// selector: 49448b04
+    function permit(
+        uint64 id,
+        address owner,
+        address spender,
+        uint256 value,
+        uint64 deadline,
+        uint8 v,
+        bytes32 r,
+        bytes32 s
+    ) external;
+
+    /// Nonce the next permit of an owner has to be signed with
+    /// selector: 38a6d67e
+    function nonces(uint64 id, address owner) external view returns (uint256);
+
+    /// EIP-712 domain separator of the permits of an asset, named after the asset, with version
+    /// 1, the chain id, and the address of the asset as verifying contract
+    /// selector: e9b8305a
+    function domainSeparator(uint64 id) external view returns (bytes32);
+
+    /// Issue a new class of fungible assets, only callable by the configured force origin
+    /// selector: a4075e04
+    function forceCreate(uint64 id, address owner, bool isSufficient, uint128 minBalance) external;
+
+    /// Force the metadata for a given asset, only callable by the configured force origin
+    /// selector: aa814f2c
+    function forceSetMetadata(
+        uint64 id,
+        bytes memory name,
+        bytes memory symbol,
+        uint8 decimal,
+        bool isFrozen
+    ) external;
+
+    /// Clear the metadata for a given asset, only callable by the configured force origin
+    /// selector: bdd2b221
+    function forceClearMetadata(uint64 id) external;
+
+    /// Alter the attributes of a given asset, only callable by the configured force origin
+    /// selector: 74293733
+    function forceAssetStatus(
+        uint64 id,
+        address owner,
+        address issuer,
+        address admin,
+        address freezer,
+        uint128 minBalance,
+        bool isSufficient,
+        bool isFrozen
+    ) external;
+
+    /// Move some assets from one account to another, only callable by the configured force origin
+    /// selector: 69098f87
+    function forceTransfer(uint64 id, address source, address dest, uint256 amount) external;
+
+    /// Move the whole balance held at the address of the asset itself, where it can only have been
+    /// sent by mistake, to a recipient. Only callable by the configured force origin
+    /// selector: 709ea72e
+    function rescueMissent(uint64 id, address to) external;
+
+    /// Clear the destroy confirmation token, destruction start block and id reservation kept for
+    /// a given asset, which does not have to exist. The asset itself is left as is. Only callable
+    /// by the configured force origin
+    /// selector: 706d9b49
+    function cancelForceOperation(uint64 id) external;
+
+    /// Emitted when an asset is created
+    event AssetCreated(uint64 indexed id, address indexed creator, address admin);
+
+    /// Emitted when the metadata of an asset is set
+    event MetadataSet(uint64 indexed id, bytes name, bytes symbol, uint8 decimals);
+
+    /// Emitted when the minimum balance of an asset is changed
+    event MinBalanceChanged(uint64 indexed id, uint128 newMin);
+
+    /// Emitted when the team of an asset is changed, including by createWithTeam, safeLaunch and
+    /// transferOwnershipAndSetTeam
+    event TeamChanged(uint64 indexed id, address issuer, address admin, address freezer);
+
+    /// Emitted when the supply cap of an asset is set, or removed with a cap of zero
+    event SupplyCapChanged(uint64 indexed id, uint256 newCap);
+
+    /// Emitted for each mint, including each of a batch
+    event Minted(uint64 indexed id, address indexed to, uint256 amount);
+
+    /// Emitted at the address of the asset for each transfer of a batch and delegated transfer
+    event Transfer(address indexed from, address indexed to, uint256 value);
+
+    /// Emitted at the address of the asset when an approval is set or cancelled
+    event Approval(address indexed owner, address indexed spender, uint256 value);
+
+    /// Emitted for each call to a mutating method, before its other events, by the runtimes
+    /// enabling the audit trail
+    event ActionPerformed(address indexed caller, bytes4 selector, uint64 indexed id);
 
-     /// Complete destroying asset and unreserve currency
-    /// selector: 99c720ff
-    function finishDestroy(uint64 id) external;
+    /// Emitted by create right before it reverts on an id it rejects, with reason 0 for an id
+    /// which is not allowed, 1 for an existing asset and 2 for an id reserved by another
+    /// account, by the runtimes enabling the audit trail. The revert drops it from the receipt,
+    /// leaving it to call tracers
+    event CreateRejected(uint64 indexed id, uint8 reason);
 }
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: GPL-3.0-only
+pragma solidity >=0.8.3;
+
+/// @author The Peaq Team
+/// @title AssetsEnumeration Interface
+/// The interface through which solidity contracts will list the assets of several assets pallet
+/// instances at once. Its address depends on the runtime wiring it.
+interface AssetsEnumeration {
+
+    /// An asset, tagged with the position of its assets pallet instance
+    struct InstanceAsset {
+        uint8 instance;
+        uint64 id;
+    }
+
+    /// Get the assets of all the enumerated instances
+    /// selector: 71a97305
+    function assets() external view returns (InstanceAsset[] memory);
+
+    /// Get up to limit assets following the cursor startKey, empty to start from the first
+    /// asset, and the cursor of the next page, empty once all the assets were returned. The
+    /// cursor is the storage key of the last asset of the page, so it stays valid when assets
+    /// are created or destroyed between pages
+    /// selector: 6146c6bb
+    function assetsPage(bytes memory startKey, uint32 limit)
+        external
+        view
+        returns (InstanceAsset[] memory assets, bytes memory nextCursor);
+}
@@ -0,0 +1,116 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Number of assets of each `pallet_assets` instance, returned by `assetCount`.
+//!
+//! `pallet_assets` keeps no count of its assets, and counting its storage would not be bounded,
+//! so the factory counts the assets it creates, through `create` and `forceCreate`, and the ones
+//! whose destruction it finishes. The assets created or destroyed outside of the factory, e.g.
+//! through the extrinsics of the pallet, are not counted: the factory keeps the ids it counted,
+//! and only uncounts those once destroyed. The counts are keyed by the index of the
+//! `pallet_assets` instance, so factories over different instances count their assets apart.
+
+use crate::{instance_index, AssetIdOf};
+use fp_evm::PrecompileHandle;
+use precompile_utils::{
+	prelude::{PrecompileHandleExt, RuntimeHelper},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+
+pub struct AssetCountNames;
+impl StorageItemNames for AssetCountNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "AssetCount";
+}
+
+/// Number of assets of a `pallet_assets` instance created through the factory and not destroyed.
+pub type AssetCount = PrecompileStorageMap<AssetCountNames, u8, u32>;
+
+pub struct CountedAssetNames;
+impl StorageItemNames for CountedAssetNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "CountedAsset";
+}
+
+/// Ids of the assets counted in [`AssetCount`], by instance.
+pub type CountedAssetOf<Runtime, Instance> =
+	PrecompileStorageMap<CountedAssetNames, (u8, AssetIdOf<Runtime, Instance>), ()>;
+
+/// Returns the number of assets of the instance.
+pub fn get<Runtime, Instance>(handle: &mut impl PrecompileHandle) -> EvmResult<u32>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: AssetCount
+	handle.record_db_read::<Runtime>(AssetCount::entry_max_encoded_len())?;
+
+	Ok(AssetCount::get(&instance_index::<Runtime, Instance>()).unwrap_or_default())
+}
+
+/// Counts `asset_id`, created in the instance, unless it is still counted: an asset counted and
+/// then destroyed outside of the factory was never uncounted.
+pub fn note_created<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	let key = (instance_index::<Runtime, Instance>(), asset_id);
+
+	// Storage item: CountedAsset
+	handle
+		.record_db_read::<Runtime>(CountedAssetOf::<Runtime, Instance>::entry_max_encoded_len())?;
+	if CountedAssetOf::<Runtime, Instance>::contains_key(&key) {
+		return Ok(())
+	}
+
+	let count = get::<Runtime, Instance>(handle)?;
+
+	// Storage item: AssetCount
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	AssetCount::insert(&instance_index::<Runtime, Instance>(), &count.saturating_add(1));
+
+	// Storage item: CountedAsset
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	CountedAssetOf::<Runtime, Instance>::insert(&key, &());
+
+	Ok(())
+}
+
+/// Uncounts `asset_id`, destroyed in the instance, if it was counted. The assets which were
+/// created outside of the factory leave the count as it is.
+pub fn note_destroyed<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	let key = (instance_index::<Runtime, Instance>(), asset_id);
+
+	// Storage item: CountedAsset
+	handle
+		.record_db_read::<Runtime>(CountedAssetOf::<Runtime, Instance>::entry_max_encoded_len())?;
+	if !CountedAssetOf::<Runtime, Instance>::contains_key(&key) {
+		return Ok(())
+	}
+
+	let count = get::<Runtime, Instance>(handle)?;
+
+	// Storage item: AssetCount
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	AssetCount::insert(&instance_index::<Runtime, Instance>(), &count.saturating_sub(1));
+
+	// Storage item: CountedAsset
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	CountedAssetOf::<Runtime, Instance>::remove(&key);
+
+	Ok(())
+}
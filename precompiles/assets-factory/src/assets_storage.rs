@@ -0,0 +1,164 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Read-only views over the `pallet_assets` storage.
+//!
+//! `pallet_assets` keeps its storage items and the fields of their values private, so the
+//! precompile decodes them through storage aliases. The layouts below must match the
+//! `pallet_assets` version used by the runtime.
+
+use crate::{AssetIdOf, BalanceOf};
+use frame_support::{
+	pallet_prelude::{NMapKey, ValueQuery},
+	storage_alias,
+	traits::Currency,
+	Blake2_128Concat, BoundedVec,
+};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_runtime::RuntimeDebug;
+
+/// Alias for the balance type of the currency used to pay the `pallet_assets` deposits.
+pub type DepositBalanceOf<Runtime, Instance = ()> =
+	<<Runtime as pallet_assets::Config<Instance>>::Currency as Currency<
+		<Runtime as frame_system::Config>::AccountId,
+	>>::Balance;
+
+/// Mirror of `pallet_assets::AssetStatus`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub enum AssetStatus {
+	/// The asset is active and able to be used.
+	Live,
+	/// Whether the asset is frozen for non-admin transfers.
+	Frozen,
+	/// The asset is currently being destroyed.
+	Destroying,
+}
+
+/// Mirror of `pallet_assets::AssetDetails`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub struct AssetDetails<Balance, AccountId, DepositBalance> {
+	pub owner: AccountId,
+	pub issuer: AccountId,
+	pub admin: AccountId,
+	pub freezer: AccountId,
+	pub supply: Balance,
+	pub deposit: DepositBalance,
+	pub min_balance: Balance,
+	pub is_sufficient: bool,
+	pub accounts: u32,
+	pub sufficients: u32,
+	pub approvals: u32,
+	pub status: AssetStatus,
+}
+
+pub type AssetDetailsOf<Runtime, Instance = ()> = AssetDetails<
+	BalanceOf<Runtime, Instance>,
+	<Runtime as frame_system::Config>::AccountId,
+	DepositBalanceOf<Runtime, Instance>,
+>;
+
+/// Mirror of `pallet_assets::AssetMetadata`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, Default, MaxEncodedLen, RuntimeDebug)]
+pub struct AssetMetadata<DepositBalance, BoundedString> {
+	pub deposit: DepositBalance,
+	pub name: BoundedString,
+	pub symbol: BoundedString,
+	pub decimals: u8,
+	pub is_frozen: bool,
+}
+
+pub type AssetMetadataOf<Runtime, Instance = ()> = AssetMetadata<
+	DepositBalanceOf<Runtime, Instance>,
+	BoundedVec<u8, <Runtime as pallet_assets::Config<Instance>>::StringLimit>,
+>;
+
+/// Mirror of `pallet_assets::AccountStatus`.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub enum AccountStatus {
+	/// Asset account can receive and transfer the assets.
+	Liquid,
+	/// Asset account cannot transfer the assets.
+	Frozen,
+	/// Asset account cannot receive and transfer the assets.
+	Blocked,
+}
+
+/// Mirror of `pallet_assets::ExistenceReason`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub enum ExistenceReason<Balance, AccountId> {
+	Consumer,
+	Sufficient,
+	DepositHeld(Balance),
+	DepositRefunded,
+	DepositFrom(AccountId, Balance),
+}
+
+/// Mirror of `pallet_assets::AssetAccount`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub struct AssetAccount<Balance, DepositBalance, Extra, AccountId> {
+	pub balance: Balance,
+	pub status: AccountStatus,
+	pub reason: ExistenceReason<DepositBalance, AccountId>,
+	pub extra: Extra,
+}
+
+pub type AssetAccountOf<Runtime, Instance = ()> = AssetAccount<
+	BalanceOf<Runtime, Instance>,
+	DepositBalanceOf<Runtime, Instance>,
+	<Runtime as pallet_assets::Config<Instance>>::Extra,
+	<Runtime as frame_system::Config>::AccountId,
+>;
+
+/// Mirror of `pallet_assets::Approval`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug)]
+pub struct Approval<Balance, DepositBalance> {
+	pub amount: Balance,
+	pub deposit: DepositBalance,
+}
+
+pub type ApprovalOf<Runtime, Instance = ()> =
+	Approval<BalanceOf<Runtime, Instance>, DepositBalanceOf<Runtime, Instance>>;
+
+/// Details of an asset.
+#[storage_alias]
+pub type Asset<Runtime: pallet_assets::Config<Instance>, Instance: 'static> = StorageMap<
+	pallet_assets::Pallet<Runtime, Instance>,
+	Blake2_128Concat,
+	AssetIdOf<Runtime, Instance>,
+	AssetDetailsOf<Runtime, Instance>,
+>;
+
+/// Metadata of an asset.
+#[storage_alias]
+pub type Metadata<Runtime: pallet_assets::Config<Instance>, Instance: 'static> = StorageMap<
+	pallet_assets::Pallet<Runtime, Instance>,
+	Blake2_128Concat,
+	AssetIdOf<Runtime, Instance>,
+	AssetMetadataOf<Runtime, Instance>,
+	ValueQuery,
+>;
+
+/// The holdings of a specific account for a specific asset.
+#[storage_alias]
+pub type Account<Runtime: pallet_assets::Config<Instance>, Instance: 'static> = StorageDoubleMap<
+	pallet_assets::Pallet<Runtime, Instance>,
+	Blake2_128Concat,
+	AssetIdOf<Runtime, Instance>,
+	Blake2_128Concat,
+	<Runtime as frame_system::Config>::AccountId,
+	AssetAccountOf<Runtime, Instance>,
+>;
+
+/// Approved balance transfers, by asset, owner and delegate.
+#[storage_alias]
+pub type Approvals<Runtime: pallet_assets::Config<Instance>, Instance: 'static> = StorageNMap<
+	pallet_assets::Pallet<Runtime, Instance>,
+	(
+		NMapKey<Blake2_128Concat, AssetIdOf<Runtime, Instance>>,
+		NMapKey<Blake2_128Concat, <Runtime as frame_system::Config>::AccountId>,
+		NMapKey<Blake2_128Concat, <Runtime as frame_system::Config>::AccountId>,
+	),
+	ApprovalOf<Runtime, Instance>,
+>;
@@ -0,0 +1,98 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-block counts of the calls to the mutating methods of the factory, returned by `callStats`.
+//!
+//! Like the create counters of the rate limit, the counts are tagged with the block they were
+//! last bumped in, so the counts of a previous block are treated as zero instead of being cleared
+//! every block. A reverted call rolls its count back along with its other changes. They are keyed
+//! by the index of the `pallet_assets` instance, so factories over different instances count
+//! their calls apart.
+
+use crate::instance_index;
+use fp_evm::PrecompileHandle;
+use frame_support::{traits::ConstU32, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use precompile_utils::{
+	prelude::{PrecompileHandleExt, RuntimeHelper},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+use sp_std::vec::Vec;
+
+/// Maximum number of methods counted in a block. The calls to other methods only count in the
+/// total.
+pub type MaxCountedMethods = ConstU32<64>;
+
+/// Calls of a block: the block, the total number of calls, and the number of calls by selector,
+/// sorted by selector.
+pub type BlockCalls<Runtime> =
+	(BlockNumberFor<Runtime>, u32, BoundedVec<(u32, u32), MaxCountedMethods>);
+
+pub struct CallCountsNames;
+impl StorageItemNames for CallCountsNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "CallCounts";
+}
+
+/// Calls to the mutating methods of a `pallet_assets` instance in the block they were last made.
+pub type CallCountsOf<Runtime> = PrecompileStorageMap<CallCountsNames, u8, BlockCalls<Runtime>>;
+
+/// Returns the total number of calls in the current block and the number of calls by selector.
+pub fn get<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+) -> EvmResult<(u32, Vec<(u32, u32)>)>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	let (total, counts) = calls_in_block::<Runtime, Instance>(handle)?;
+
+	Ok((total, counts.into_inner()))
+}
+
+/// Counts a call to the method of `selector` in the current block.
+pub fn note<Runtime, Instance>(handle: &mut impl PrecompileHandle, selector: u32) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	let (total, mut counts) = calls_in_block::<Runtime, Instance>(handle)?;
+	match counts.binary_search_by_key(&selector, |(selector, _)| *selector) {
+		Ok(index) => counts[index].1 = counts[index].1.saturating_add(1),
+		Err(index) => {
+			// Once all the slots are taken, the call only counts in the total.
+			let _ = counts.try_insert(index, (selector, 1));
+		},
+	}
+
+	// Storage item: CallCounts
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	CallCountsOf::<Runtime>::insert(
+		&instance_index::<Runtime, Instance>(),
+		&(frame_system::Pallet::<Runtime>::block_number(), total.saturating_add(1), counts),
+	);
+
+	Ok(())
+}
+
+/// Returns the calls of the current block, none if the counts are the ones of a previous block.
+fn calls_in_block<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+) -> EvmResult<(u32, BoundedVec<(u32, u32), MaxCountedMethods>)>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: CallCounts
+	handle.record_db_read::<Runtime>(CallCountsOf::<Runtime>::entry_max_encoded_len())?;
+
+	Ok(match CallCountsOf::<Runtime>::get(&instance_index::<Runtime, Instance>()) {
+		Some((block, total, counts))
+			if block == frame_system::Pallet::<Runtime>::block_number() =>
+			(total, counts),
+		_ => Default::default(),
+	})
+}
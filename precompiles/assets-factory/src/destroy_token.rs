@@ -0,0 +1,256 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Confirmation tokens of the asset destructions started through the factory.
+//!
+//! `startDestroy` returns a token derived from the asset id and the block it was issued in, which
+//! `finishDestroy` requires, so that callers acknowledge the specific destruction they finish.
+//! Tokens expire after [`AssetsFactoryConfig::DestroyTokenLifetime`] blocks.
+//!
+//! The block a destruction started in is kept as well, unlike the one of its token which a renewal
+//! replaces, so that `destroyStartedAt` tells how long the destruction has been going on, along
+//! with the account which started it, which
+//! [`AssetsFactoryConfig::OnlyInitiatorFinishesDestroy`] can require to finish it.
+
+use crate::{instance_index, AssetIdOf, AssetsFactoryConfig};
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use parity_scale_codec::Encode;
+use precompile_utils::{
+	prelude::{revert, PrecompileHandleExt, RevertReason, RuntimeHelper},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+use sp_core::H256;
+use sp_runtime::traits::Saturating;
+
+pub struct DestroyTokenNames;
+impl StorageItemNames for DestroyTokenNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "DestroyToken";
+}
+
+/// Confirmation token of the destruction of an asset, along with the block it was issued in.
+pub type DestroyTokenOf<Runtime, Instance> = PrecompileStorageMap<
+	DestroyTokenNames,
+	(u8, AssetIdOf<Runtime, Instance>),
+	(H256, BlockNumberFor<Runtime>),
+>;
+
+pub struct DestroyStartedAtNames;
+impl StorageItemNames for DestroyStartedAtNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "DestroyStartedAt";
+}
+
+/// Block the destruction of an asset started in through the factory.
+pub type DestroyStartedAtOf<Runtime, Instance> = PrecompileStorageMap<
+	DestroyStartedAtNames,
+	(u8, AssetIdOf<Runtime, Instance>),
+	BlockNumberFor<Runtime>,
+>;
+
+pub struct DestroyInitiatorNames;
+impl StorageItemNames for DestroyInitiatorNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "DestroyInitiator";
+}
+
+/// Account which started the destruction of an asset through the factory.
+pub type DestroyInitiatorOf<Runtime, Instance> = PrecompileStorageMap<
+	DestroyInitiatorNames,
+	(u8, AssetIdOf<Runtime, Instance>),
+	<Runtime as frame_system::Config>::AccountId,
+>;
+
+/// Records the current block as the one the destruction of `asset_id` started in, and `who` as
+/// the account which started it.
+pub fn note_started<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	who: Runtime::AccountId,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: DestroyStartedAt
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	DestroyStartedAtOf::<Runtime, Instance>::insert(
+		&(instance_index::<Runtime, Instance>(), asset_id.clone()),
+		&frame_system::Pallet::<Runtime>::block_number(),
+	);
+
+	// Storage item: DestroyInitiator
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	DestroyInitiatorOf::<Runtime, Instance>::insert(
+		&(instance_index::<Runtime, Instance>(), asset_id),
+		&who,
+	);
+
+	Ok(())
+}
+
+/// Checks that `who` started the destruction of `asset_id`, if the runtime requires the account
+/// which started a destruction to finish it. A destruction not started through the factory has no
+/// initiator to check against.
+pub fn check_initiator<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	who: &Runtime::AccountId,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + AssetsFactoryConfig,
+{
+	if !Runtime::OnlyInitiatorFinishesDestroy::get() {
+		return Ok(())
+	}
+
+	// Storage item: DestroyInitiator
+	handle.record_db_read::<Runtime>(
+		DestroyInitiatorOf::<Runtime, Instance>::entry_max_encoded_len(),
+	)?;
+	match DestroyInitiatorOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	)) {
+		Some(initiator) if &initiator != who =>
+			Err(revert("destroy must be finished by initiator")),
+		_ => Ok(()),
+	}
+}
+
+/// Returns the block the destruction of `asset_id` started in, if it was started through the
+/// factory.
+pub fn started_at<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<Option<BlockNumberFor<Runtime>>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: DestroyStartedAt
+	handle.record_db_read::<Runtime>(
+		DestroyStartedAtOf::<Runtime, Instance>::entry_max_encoded_len(),
+	)?;
+
+	Ok(DestroyStartedAtOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	)))
+}
+
+/// Returns the block the current token of the destruction of `asset_id` was issued in, if any,
+/// even if it expired.
+pub fn issued_at<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<Option<BlockNumberFor<Runtime>>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: DestroyToken
+	handle
+		.record_db_read::<Runtime>(DestroyTokenOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	Ok(DestroyTokenOf::<Runtime, Instance>::get(&(instance_index::<Runtime, Instance>(), asset_id))
+		.map(|(_, issued_at)| issued_at))
+}
+
+/// Issues a token for the destruction of `asset_id`, replacing any previous one.
+pub fn issue<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	id: u64,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<H256>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	let issued_at = frame_system::Pallet::<Runtime>::block_number();
+	let token = H256(sp_io::hashing::keccak_256(&(id, issued_at).encode()));
+
+	// Storage item: DestroyToken
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	DestroyTokenOf::<Runtime, Instance>::insert(
+		&(instance_index::<Runtime, Instance>(), asset_id),
+		&(token, issued_at),
+	);
+
+	Ok(token)
+}
+
+/// Checks that `token` is the unexpired token of the destruction of `asset_id`.
+pub fn check<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	token: H256,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + AssetsFactoryConfig,
+{
+	// Storage item: DestroyToken
+	handle
+		.record_db_read::<Runtime>(DestroyTokenOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	let issued_at = match DestroyTokenOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	)) {
+		Some((expected, issued_at)) if expected == token => issued_at,
+		_ =>
+			return Err(RevertReason::custom("wrong destroy confirmation token")
+				.in_field("token")
+				.into()),
+	};
+
+	let expires_at = issued_at.saturating_add(Runtime::DestroyTokenLifetime::get());
+	if frame_system::Pallet::<Runtime>::block_number() > expires_at {
+		return Err(RevertReason::custom("destroy confirmation token expired")
+			.in_field("token")
+			.into())
+	}
+
+	Ok(())
+}
+
+/// Removes the token, the start block and the initiator of the destruction of `asset_id`, once it
+/// is finished or when the force origin cancels it.
+pub fn remove<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: DestroyToken
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	DestroyTokenOf::<Runtime, Instance>::remove(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id.clone(),
+	));
+
+	// Storage item: DestroyStartedAt
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	DestroyStartedAtOf::<Runtime, Instance>::remove(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id.clone(),
+	));
+
+	// Storage item: DestroyInitiator
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	DestroyInitiatorOf::<Runtime, Instance>::remove(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	));
+
+	Ok(())
+}
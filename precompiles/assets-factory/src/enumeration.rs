@@ -0,0 +1,216 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Companion precompile enumerating the assets of several `pallet_assets` instances at once.
+//!
+//! The instances are given as a tuple, and every asset is tagged with the position of its
+//! instance in that tuple, so dapps need a single integration for e.g. local and foreign assets.
+//!
+//! The assets can also be listed by pages. The cursor of a page is the storage key of the last
+//! asset it returned, which the next page starts after, so that assets created or destroyed in
+//! between pages do not shift the others.
+
+use crate::{
+	assets_storage::{self, AssetDetailsOf},
+	AssetIdOf,
+};
+use fp_evm::PrecompileHandle;
+use frame_support::{
+	storage::StoragePrefixedMap,
+	traits::{fungibles::InspectEnumerable, ConstU32},
+};
+use parity_scale_codec::MaxEncodedLen;
+use precompile_utils::{
+	prelude::{BoundedBytes, PrecompileHandleExt, RevertReason, UnboundedBytes},
+	solidity::Codec,
+	EvmResult,
+};
+use sp_std::{convert::TryInto, marker::PhantomData, vec::Vec};
+
+/// Maximum length of the cursor of a page, longer than any storage key of an asset.
+type GetCursorLimit = ConstU32<256>;
+
+/// An asset of one of the enumerated `pallet_assets` instances.
+#[derive(Default, Debug, Codec)]
+pub struct InstanceAsset {
+	/// Position of the instance in the enumerated tuple.
+	pub instance: u8,
+	pub id: u64,
+}
+
+/// A tuple of `pallet_assets` instances enumerated together.
+pub trait AssetsInstances<Runtime> {
+	/// Returns the assets of every instance, tagged with the position of their instance.
+	fn assets(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<InstanceAsset>>;
+
+	/// Returns up to `limit` assets stored after `start_key`, or from the first one if it is
+	/// empty, and the key to continue from, empty once all the assets were returned. Reverts if
+	/// `start_key` is not the key of an asset of one of the instances.
+	fn assets_page(
+		handle: &mut impl PrecompileHandle,
+		start_key: &[u8],
+		limit: usize,
+	) -> EvmResult<(Vec<InstanceAsset>, Vec<u8>)>;
+}
+
+/// Progress of the listing of a page through the instances.
+enum PageCursor<'a> {
+	/// The instance holding the asset to start after was not reached yet.
+	Before(&'a [u8]),
+	/// The page is being filled.
+	Filling,
+	/// The page is full, and the next one starts after the given key.
+	Full(Vec<u8>),
+}
+
+/// Returns the assets of a single instance, tagged with `instance`.
+fn instance_assets<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	instance: u8,
+) -> EvmResult<Vec<InstanceAsset>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+	AssetIdOf<Runtime, Instance>: TryInto<u64>,
+{
+	let mut assets = Vec::new();
+	for asset_id in pallet_assets::Pallet::<Runtime, Instance>::asset_ids() {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		// Ids that do not fit the `uint64` used by the precompiles are skipped.
+		if let Ok(id) = asset_id.try_into() {
+			assets.push(InstanceAsset { instance, id });
+		}
+	}
+
+	Ok(assets)
+}
+
+/// Adds the assets of a single instance to the page, tagged with `instance`.
+fn instance_assets_page<'a, Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	instance: u8,
+	cursor: PageCursor<'a>,
+	limit: usize,
+	assets: &mut Vec<InstanceAsset>,
+) -> EvmResult<PageCursor<'a>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+	AssetIdOf<Runtime, Instance>: TryInto<u64>,
+{
+	let asset_ids = match cursor {
+		PageCursor::Full(_) => return Ok(cursor),
+		PageCursor::Before(start_key) => {
+			let prefix = assets_storage::Asset::<Runtime, Instance>::final_prefix();
+			if start_key.len() <= prefix.len() || !start_key.starts_with(&prefix) {
+				return Ok(cursor)
+			}
+			assets_storage::Asset::<Runtime, Instance>::iter_keys_from(start_key.to_vec())
+		},
+		PageCursor::Filling => assets_storage::Asset::<Runtime, Instance>::iter_keys(),
+	};
+
+	for asset_id in asset_ids {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let key = assets_storage::Asset::<Runtime, Instance>::hashed_key_for(&asset_id);
+		// Ids that do not fit the `uint64` used by the precompiles are skipped.
+		if let Ok(id) = asset_id.try_into() {
+			assets.push(InstanceAsset { instance, id });
+		}
+		if assets.len() >= limit {
+			return Ok(PageCursor::Full(key))
+		}
+	}
+
+	Ok(PageCursor::Filling)
+}
+
+macro_rules! impl_assets_instances {
+	($($instance:ident => $index:literal),+) => {
+		impl<Runtime, $($instance),+> AssetsInstances<Runtime> for ($($instance,)+)
+		where
+			Runtime: pallet_evm::Config $(+ pallet_assets::Config<$instance>)+,
+			$(
+				$instance: 'static,
+				AssetIdOf<Runtime, $instance>: TryInto<u64>,
+			)+
+		{
+			fn assets(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<InstanceAsset>> {
+				let mut assets = Vec::new();
+				$(assets.extend(instance_assets::<Runtime, $instance>(handle, $index)?);)+
+
+				Ok(assets)
+			}
+
+			fn assets_page(
+				handle: &mut impl PrecompileHandle,
+				start_key: &[u8],
+				limit: usize,
+			) -> EvmResult<(Vec<InstanceAsset>, Vec<u8>)> {
+				let mut assets = Vec::new();
+				let mut cursor = if start_key.is_empty() {
+					PageCursor::Filling
+				} else {
+					PageCursor::Before(start_key)
+				};
+				$(
+					cursor = instance_assets_page::<Runtime, $instance>(
+						handle, $index, cursor, limit, &mut assets,
+					)?;
+				)+
+
+				match cursor {
+					PageCursor::Before(_) =>
+						Err(RevertReason::custom("unknown cursor").in_field("startKey").into()),
+					PageCursor::Filling => Ok((assets, Vec::new())),
+					PageCursor::Full(next_key) => Ok((assets, next_key)),
+				}
+			}
+		}
+	};
+}
+
+impl_assets_instances!(I0 => 0);
+impl_assets_instances!(I0 => 0, I1 => 1);
+impl_assets_instances!(I0 => 0, I1 => 1, I2 => 2);
+impl_assets_instances!(I0 => 0, I1 => 1, I2 => 2, I3 => 3);
+
+pub struct AssetsEnumerationPrecompile<Runtime, Instances>(PhantomData<(Runtime, Instances)>);
+
+#[precompile_utils::precompile]
+impl<Runtime, Instances> AssetsEnumerationPrecompile<Runtime, Instances>
+where
+	Runtime: pallet_evm::Config,
+	Instances: AssetsInstances<Runtime>,
+{
+	#[precompile::public("assets()")]
+	#[precompile::view]
+	fn assets(handle: &mut impl PrecompileHandle) -> EvmResult<Vec<InstanceAsset>> {
+		Instances::assets(handle)
+	}
+
+	/// Returns up to `limit` assets following the cursor `startKey`, empty to start from the
+	/// first asset, and the cursor of the next page, empty once all the assets were returned.
+	#[precompile::public("assetsPage(bytes,uint32)")]
+	#[precompile::view]
+	fn assets_page(
+		handle: &mut impl PrecompileHandle,
+		start_key: BoundedBytes<GetCursorLimit>,
+		limit: u32,
+	) -> EvmResult<(Vec<InstanceAsset>, UnboundedBytes)> {
+		if limit == 0 {
+			return Err(RevertReason::custom("must be at least 1").in_field("limit").into())
+		}
+
+		let start_key: Vec<u8> = start_key.into();
+		let (assets, next_key) = Instances::assets_page(handle, &start_key, limit as usize)?;
+
+		Ok((assets, next_key.into()))
+	}
+}
@@ -0,0 +1,185 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Events emitted by the assets factory.
+//!
+//! [`EVENTS`] is the published ABI of those events, from which clients can generate bindings.
+//! The tests check every emitted log against it, keeping the on-chain topics and the ABI in
+//! lockstep.
+
+use precompile_utils::keccak256;
+use sp_std::vec::Vec;
+
+/// Event emitted when an asset is created.
+pub const SELECTOR_LOG_ASSET_CREATED: [u8; 32] = keccak256!("AssetCreated(uint64,address,address)");
+
+/// Event emitted when the metadata of an asset is set.
+pub const SELECTOR_LOG_METADATA_SET: [u8; 32] = keccak256!("MetadataSet(uint64,bytes,bytes,uint8)");
+
+/// Event emitted when the minimum balance of an asset is changed.
+pub const SELECTOR_LOG_MIN_BALANCE_CHANGED: [u8; 32] =
+	keccak256!("MinBalanceChanged(uint64,uint128)");
+
+/// Event emitted when an asset is created along with its team.
+pub const SELECTOR_LOG_TEAM_CHANGED: [u8; 32] =
+	keccak256!("TeamChanged(uint64,address,address,address)");
+
+/// Event emitted when the supply cap of an asset is set or removed.
+pub const SELECTOR_LOG_SUPPLY_CAP_CHANGED: [u8; 32] =
+	keccak256!("SupplyCapChanged(uint64,uint256)");
+
+/// Event emitted for each mint of a batch.
+pub const SELECTOR_LOG_MINTED: [u8; 32] = keccak256!("Minted(uint64,address,uint256)");
+
+/// ERC20 event emitted for each transfer of a batch and for the delegated transfers. It is logged
+/// at the address of the asset, like the transfers made through the ERC20 precompile of the asset.
+pub const SELECTOR_LOG_TRANSFER: [u8; 32] = keccak256!("Transfer(address,address,uint256)");
+
+/// ERC20 event emitted when an approval is set or cancelled. Like `Transfer`, it is logged at the
+/// address of the asset.
+pub const SELECTOR_LOG_APPROVAL: [u8; 32] = keccak256!("Approval(address,address,uint256)");
+
+/// Event emitted, with the `audit` feature, for each call to a mutating method.
+pub const SELECTOR_LOG_ACTION_PERFORMED: [u8; 32] =
+	keccak256!("ActionPerformed(address,bytes4,uint64)");
+
+/// Event emitted, with the `audit` feature, when `create` rejects an id, right before its revert.
+pub const SELECTOR_LOG_CREATE_REJECTED: [u8; 32] = keccak256!("CreateRejected(uint64,uint8)");
+
+/// `reason` of a `CreateRejected` for an id the runtime does not allow to create.
+pub const CREATE_REJECTED_NOT_ALLOWED: u8 = 0;
+/// `reason` of a `CreateRejected` for the id of an existing asset.
+pub const CREATE_REJECTED_ALREADY_EXISTS: u8 = 1;
+/// `reason` of a `CreateRejected` for an id another account reserved.
+pub const CREATE_REJECTED_RESERVED: u8 = 2;
+
+/// A parameter of an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventParam {
+	pub name: &'static str,
+	/// Canonical Solidity type of the parameter.
+	pub ty: &'static str,
+	/// Whether the parameter is a topic of the log rather than part of its data.
+	pub indexed: bool,
+}
+
+/// ABI of an event.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EventAbi {
+	pub name: &'static str,
+	pub params: &'static [EventParam],
+}
+
+impl EventAbi {
+	/// Canonical signature of the event, e.g. `MetadataSet(uint64,bytes,bytes,uint8)`.
+	pub fn signature(&self) -> Vec<u8> {
+		let mut signature = self.name.as_bytes().to_vec();
+		signature.push(b'(');
+		for (index, param) in self.params.iter().enumerate() {
+			if index > 0 {
+				signature.push(b',');
+			}
+			signature.extend_from_slice(param.ty.as_bytes());
+		}
+		signature.push(b')');
+
+		signature
+	}
+
+	/// First topic of the logs of the event.
+	pub fn selector(&self) -> [u8; 32] {
+		sp_io::hashing::keccak_256(&self.signature())
+	}
+
+	/// Number of topics of the logs of the event, including the selector.
+	pub fn topics(&self) -> usize {
+		1 + self.params.iter().filter(|param| param.indexed).count()
+	}
+}
+
+/// Events emitted by the precompile.
+pub const EVENTS: &[EventAbi] = &[
+	EventAbi {
+		name: "AssetCreated",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "creator", ty: "address", indexed: true },
+			EventParam { name: "admin", ty: "address", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "MetadataSet",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "name", ty: "bytes", indexed: false },
+			EventParam { name: "symbol", ty: "bytes", indexed: false },
+			EventParam { name: "decimals", ty: "uint8", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "MinBalanceChanged",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "newMin", ty: "uint128", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "TeamChanged",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "issuer", ty: "address", indexed: false },
+			EventParam { name: "admin", ty: "address", indexed: false },
+			EventParam { name: "freezer", ty: "address", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "SupplyCapChanged",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "newCap", ty: "uint256", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "Minted",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "to", ty: "address", indexed: true },
+			EventParam { name: "amount", ty: "uint256", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "Transfer",
+		params: &[
+			EventParam { name: "from", ty: "address", indexed: true },
+			EventParam { name: "to", ty: "address", indexed: true },
+			EventParam { name: "value", ty: "uint256", indexed: false },
+		],
+	},
+	EventAbi {
+		name: "Approval",
+		params: &[
+			EventParam { name: "owner", ty: "address", indexed: true },
+			EventParam { name: "spender", ty: "address", indexed: true },
+			EventParam { name: "value", ty: "uint256", indexed: false },
+		],
+	},
+	#[cfg(feature = "audit")]
+	EventAbi {
+		name: "ActionPerformed",
+		params: &[
+			EventParam { name: "caller", ty: "address", indexed: true },
+			EventParam { name: "selector", ty: "bytes4", indexed: false },
+			EventParam { name: "id", ty: "uint64", indexed: true },
+		],
+	},
+	#[cfg(feature = "audit")]
+	EventAbi {
+		name: "CreateRejected",
+		params: &[
+			EventParam { name: "id", ty: "uint64", indexed: true },
+			EventParam { name: "reason", ty: "uint8", indexed: false },
+		],
+	},
+];
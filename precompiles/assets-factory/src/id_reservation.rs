@@ -0,0 +1,208 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Reservations of asset ids made through `reserveId`, so that a creator is not raced to the id
+//! it announced.
+//!
+//! While a reservation lasts, only the account which made it can create the asset. Reservations
+//! expire after [`AssetsFactoryConfig::IdReservationLifetime`] blocks, an expired one being
+//! treated as none instead of being cleared. The one of an asset is removed once it is created.
+//!
+//! So that ids cannot be squatted for free, an account holds at most
+//! [`AssetsFactoryConfig::MaxIdReservations`] unexpired reservations, and cannot renew one: once
+//! its reservation of an id expired, it can only reserve the id again after another account did.
+
+use crate::{instance_index, AssetIdOf, AssetsFactoryConfig};
+use fp_evm::PrecompileHandle;
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+use precompile_utils::{
+	prelude::{revert, PrecompileHandleExt, RuntimeHelper},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+use sp_runtime::traits::Saturating;
+
+pub struct IdReservationNames;
+impl StorageItemNames for IdReservationNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "IdReservation";
+}
+
+/// Account which reserved the id of an asset, along with the block it reserved it in.
+pub type IdReservationOf<Runtime, Instance> = PrecompileStorageMap<
+	IdReservationNames,
+	(u8, AssetIdOf<Runtime, Instance>),
+	(<Runtime as frame_system::Config>::AccountId, BlockNumberFor<Runtime>),
+>;
+
+pub struct AccountIdReservationsNames;
+impl StorageItemNames for AccountIdReservationsNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "AccountIdReservations";
+}
+
+/// Reservations of an account: the ids it reserved along with the blocks it reserved them in,
+/// the expired ones being dropped whenever it reserves another id.
+pub type AccountIdReservations<Runtime, Instance> = BoundedVec<
+	(AssetIdOf<Runtime, Instance>, BlockNumberFor<Runtime>),
+	<Runtime as AssetsFactoryConfig>::MaxIdReservations,
+>;
+
+/// Reservations of the accounts, by instance and account.
+pub type AccountIdReservationsOf<Runtime, Instance> = PrecompileStorageMap<
+	AccountIdReservationsNames,
+	(u8, <Runtime as frame_system::Config>::AccountId),
+	AccountIdReservations<Runtime, Instance>,
+>;
+
+/// Reserves `asset_id` for `who` from the current block on, reverting if an account has an
+/// unexpired reservation of it, if `who` has one even expired, or if `who` holds as many
+/// unexpired reservations as it can.
+pub fn reserve<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	who: Runtime::AccountId,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + AssetsFactoryConfig,
+{
+	match get::<Runtime, Instance>(handle, asset_id.clone())? {
+		Some((holder, _)) if holder == who =>
+			return Err(revert("id reservation cannot be renewed")),
+		Some((_, reserved_at)) if !is_expired::<Runtime>(reserved_at) =>
+			return Err(revert("id reserved by another account")),
+		_ => {},
+	}
+
+	let now = frame_system::Pallet::<Runtime>::block_number();
+	let mut reservations = reservations_of::<Runtime, Instance>(handle, &who)?;
+	reservations.retain(|(_, reserved_at)| !is_expired::<Runtime>(*reserved_at));
+	reservations
+		.try_push((asset_id.clone(), now))
+		.map_err(|_| revert("too many id reservations"))?;
+
+	// Storage item: AccountIdReservations
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	AccountIdReservationsOf::<Runtime, Instance>::insert(
+		&(instance_index::<Runtime, Instance>(), who.clone()),
+		&reservations,
+	);
+
+	// Storage item: IdReservation
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	IdReservationOf::<Runtime, Instance>::insert(
+		&(instance_index::<Runtime, Instance>(), asset_id),
+		&(who, now),
+	);
+
+	Ok(())
+}
+
+/// Returns the account which reserved `asset_id` and the block it reserved it in, if any, even if
+/// the reservation expired.
+pub fn get<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<Option<(Runtime::AccountId, BlockNumberFor<Runtime>)>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: IdReservation
+	handle
+		.record_db_read::<Runtime>(IdReservationOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	Ok(IdReservationOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	)))
+}
+
+/// Checks that no account other than `who` has an unexpired reservation of `asset_id`.
+pub fn check<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	who: &Runtime::AccountId,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + AssetsFactoryConfig,
+{
+	let (holder, reserved_at) = match get::<Runtime, Instance>(handle, asset_id)? {
+		Some(reservation) => reservation,
+		None => return Ok(()),
+	};
+
+	if &holder != who && !is_expired::<Runtime>(reserved_at) {
+		return Err(revert("id reserved by another account"))
+	}
+
+	Ok(())
+}
+
+/// Removes the reservation of `asset_id`, once the asset is created or when the force origin
+/// cancels it, freeing the slot it takes among the reservations of its holder.
+pub fn remove<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + AssetsFactoryConfig,
+{
+	let holder = match get::<Runtime, Instance>(handle, asset_id.clone())? {
+		Some((holder, _)) => holder,
+		None => return Ok(()),
+	};
+
+	let mut reservations = reservations_of::<Runtime, Instance>(handle, &holder)?;
+	reservations.retain(|(reserved, _)| reserved != &asset_id);
+
+	// Storage item: AccountIdReservations
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	AccountIdReservationsOf::<Runtime, Instance>::set(
+		&(instance_index::<Runtime, Instance>(), holder),
+		(!reservations.is_empty()).then_some(&reservations),
+	);
+
+	// Storage item: IdReservation
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	IdReservationOf::<Runtime, Instance>::remove(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+	));
+
+	Ok(())
+}
+
+/// Returns the reservations of `who`, including the expired ones.
+fn reservations_of<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	who: &Runtime::AccountId,
+) -> EvmResult<AccountIdReservations<Runtime, Instance>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + AssetsFactoryConfig,
+{
+	// Storage item: AccountIdReservations
+	handle.record_db_read::<Runtime>(
+		AccountIdReservationsOf::<Runtime, Instance>::entry_max_encoded_len(),
+	)?;
+
+	Ok(AccountIdReservationsOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		who.clone(),
+	))
+	.unwrap_or_default())
+}
+
+/// Whether a reservation made in block `reserved_at` expired, reservations lasting until the end
+/// of their last block.
+fn is_expired<Runtime: AssetsFactoryConfig>(reserved_at: BlockNumberFor<Runtime>) -> bool {
+	frame_system::Pallet::<Runtime>::block_number() >
+		reserved_at.saturating_add(Runtime::IdReservationLifetime::get())
+}
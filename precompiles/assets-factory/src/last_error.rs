@@ -0,0 +1,78 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Last failure of the atomic methods of the factory, returned by `lastError`.
+//!
+//! The failures revert the storage changes of the call, so they are kept in the transient
+//! storage of the precompiles, which lasts until the end of the transaction. They are keyed by
+//! the index of the `pallet_assets` instance, so factories over different instances do not
+//! report the failures of each other.
+
+use crate::{instance_index, LAST_ERROR_EXIT, LAST_ERROR_REVERT};
+use fp_evm::PrecompileFailure;
+use frame_support::storage::TransactionOutcome;
+use precompile_utils::{
+	prelude::UnboundedBytes,
+	solidity::{self, revert::ERROR_SELECTOR},
+	storage::StorageItemNames,
+	transient::TransientStorageMap,
+	EvmResult,
+};
+use sp_std::vec::Vec;
+
+pub struct LastErrorNames;
+impl StorageItemNames for LastErrorNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "LastError";
+}
+
+/// Code and message of the last failure of a `pallet_assets` instance.
+pub type LastError = TransientStorageMap<LastErrorNames, u8, (u8, Vec<u8>)>;
+
+/// Returns the code and message of the last failure, if any.
+pub fn get<Runtime, Instance>() -> Option<(u8, Vec<u8>)>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	LastError::get(&instance_index::<Runtime, Instance>())
+}
+
+/// Commits the changes if `result` is a success, otherwise rolls them back and records the
+/// failure as the last error.
+pub fn outcome<Runtime, Instance, T>(result: EvmResult<T>) -> TransactionOutcome<EvmResult<T>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	match &result {
+		Ok(_) => TransactionOutcome::Commit(result),
+		Err(failure) => {
+			LastError::insert(&instance_index::<Runtime, Instance>(), &describe(failure));
+			TransactionOutcome::Rollback(result)
+		},
+	}
+}
+
+/// Code and message of `failure`: the reason of a revert, or the name of the EVM error.
+fn describe(failure: &PrecompileFailure) -> (u8, Vec<u8>) {
+	match failure {
+		PrecompileFailure::Revert { output, .. } => (LAST_ERROR_REVERT, revert_reason(output)),
+		PrecompileFailure::Error { exit_status } =>
+			(LAST_ERROR_EXIT, alloc::format!("{exit_status:?}").into_bytes()),
+		PrecompileFailure::Fatal { exit_status } =>
+			(LAST_ERROR_EXIT, alloc::format!("{exit_status:?}").into_bytes()),
+	}
+}
+
+/// Reason of a revert from its `output`, which is returned as is if it is not an `Error(string)`.
+pub fn revert_reason(output: &[u8]) -> Vec<u8> {
+	match solidity::codec::selector(output) {
+		Some(ERROR_SELECTOR) => solidity::decode_arguments::<UnboundedBytes>(&output[4..])
+			.map(Into::into)
+			.unwrap_or_else(|_| output.to_vec()),
+		_ => output.to_vec(),
+	}
+}
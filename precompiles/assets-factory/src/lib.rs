@@ -4,40 +4,119 @@
 // SPDX-License-Identifier: GPL-3.0-or-later
 
 #![cfg_attr(not(feature = "std"), no_std)]
+// Runtimes compiling only some groups of methods leave some of the shared helpers unused.
+#![cfg_attr(
+	not(all(
+		feature = "create",
+		feature = "team",
+		feature = "destroy",
+		feature = "freezing",
+		feature = "transfers",
+		feature = "force",
+		feature = "approvals",
+		feature = "issuance"
+	)),
+	allow(dead_code, unused_imports)
+)]
 #![cfg_attr(test, feature(assert_matches))]
 
-use fp_evm::PrecompileHandle;
+extern crate alloc;
+
+use fp_evm::{Log, PrecompileFailure, PrecompileHandle};
 use frame_support::{
 	dispatch::{Dispatchable, GetDispatchInfo, PostDispatchInfo},
 	sp_runtime::traits::StaticLookup,
-	traits::{ConstU32, OriginTrait},
+	storage::TransactionOutcome,
+	traits::{ConstU32, Contains, Currency, EnsureOrigin, Get, OriginTrait, PalletInfoAccess},
 };
+use frame_system::pallet_prelude::BlockNumberFor;
 
 use pallet_evm::AddressMapping;
+use parity_scale_codec::MaxEncodedLen;
 use peaq_primitives_xcm::EVMAddressToAssetId;
 use precompile_utils::{
+	precompile_set::{get_address_type, AddressType},
 	prelude::{
-		Address, BoundedBytes, InjectBacktrace, PrecompileHandleExt, RevertReason, RuntimeHelper,
-		SYSTEM_ACCOUNT_SIZE,
+		revert, Address, BoundedBytes, BoundedVec, Bytes4, InjectBacktrace, LogExt, LogsBuilder,
+		MayRevert, PrecompileHandleExt, RevertReason, RuntimeHelper, TryDispatchError,
+		UnboundedBytes, OUT_OF_GAS_REVERT_PREFIX, SYSTEM_ACCOUNT_SIZE,
 	},
 	solidity, EvmResult,
 };
-use sp_runtime::traits::Bounded;
+use sp_runtime::{
+	traits::{Convert, SaturatedConversion, Saturating, Zero},
+	DispatchError,
+};
 
 use peaq_primitives_xcm::AssetIdExt;
-use sp_core::{H160, U256};
+use sp_core::{H160, H256, U256};
 use sp_std::{
 	convert::{TryFrom, TryInto},
 	marker::PhantomData,
 	vec::Vec,
 };
 
+mod assets_storage;
+use assets_storage::{
+	AccountStatus, ApprovalOf, AssetAccountOf, AssetDetailsOf, AssetMetadataOf, AssetStatus,
+	DepositBalanceOf,
+};
+
+mod events;
+pub use events::{
+	EventAbi, EventParam, CREATE_REJECTED_ALREADY_EXISTS, CREATE_REJECTED_NOT_ALLOWED,
+	CREATE_REJECTED_RESERVED, EVENTS, SELECTOR_LOG_ACTION_PERFORMED, SELECTOR_LOG_APPROVAL,
+	SELECTOR_LOG_ASSET_CREATED, SELECTOR_LOG_CREATE_REJECTED, SELECTOR_LOG_METADATA_SET,
+	SELECTOR_LOG_MINTED, SELECTOR_LOG_MIN_BALANCE_CHANGED, SELECTOR_LOG_SUPPLY_CAP_CHANGED,
+	SELECTOR_LOG_TEAM_CHANGED, SELECTOR_LOG_TRANSFER,
+};
+
+mod asset_count;
+
+mod call_stats;
+
+mod destroy_token;
+
+mod id_reservation;
+
+mod last_error;
+
+mod permit;
+
+mod rate_limit;
+
+mod supply_cap;
+
+use pallet_evm_precompile_assets_erc20::{allowance_deadline, asset_pause, transfer_fee};
+
+mod enumeration;
+pub use enumeration::{
+	AssetsEnumerationPrecompile, AssetsEnumerationPrecompileCall, AssetsInstances, InstanceAsset,
+};
+
 #[cfg(test)]
 mod mock;
-#[cfg(test)]
+#[cfg(all(test, feature = "full", not(feature = "audit")))]
 mod tests;
+#[cfg(all(test, feature = "full", feature = "audit"))]
+mod tests_audit;
+#[cfg(all(
+	test,
+	feature = "create",
+	not(any(
+		feature = "approvals",
+		feature = "destroy",
+		feature = "force",
+		feature = "freezing",
+		feature = "issuance",
+		feature = "team",
+		feature = "transfers"
+	))
+))]
+mod tests_create_only;
 
 type GetBytesLimit = ConstU32<{ 2u32.pow(16) }>;
+type GetArrayLimit = ConstU32<{ 2u32.pow(9) }>;
 
 /// Alias for the Balance type for the provided Runtime and Instance.
 pub type BalanceOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<Instance>>::Balance;
@@ -49,6 +128,335 @@ pub type AssetIdOf<Runtime, Instance = ()> = <Runtime as pallet_assets::Config<I
 pub type AssetIdParameterOf<Runtime, Instance = ()> =
 	<Runtime as pallet_assets::Config<Instance>>::AssetIdParameter;
 
+/// Alias for the calls of the precompile.
+type FactoryCall<Runtime, Instance> = AssetsFactoryPrecompileCall<Runtime, Instance>;
+
+/// Alias for the dispatch origin of the runtime calls.
+type RuntimeOriginOf<Runtime> =
+	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin;
+
+/// Runtime configuration of the assets factory precompile.
+pub trait AssetsFactoryConfig: frame_system::Config {
+	/// Origin allowed to use the `force*` methods. It is checked against the signed origin of
+	/// the caller's mapped account; the forced calls are then dispatched as root.
+	type ForceOrigin: EnsureOrigin<RuntimeOriginOf<Self>>;
+
+	/// Maximum number of assets an account can create through the factory in a block, `None`
+	/// to not rate limit creates.
+	type MaxCreatesPerBlock: Get<Option<u32>>;
+
+	/// Number of blocks during which the confirmation token returned by `startDestroy` can be
+	/// used to finish the destruction.
+	type DestroyTokenLifetime: Get<BlockNumberFor<Self>>;
+
+	/// Number of blocks during which an id reserved by `reserveId` can only be created by the
+	/// account which reserved it.
+	type IdReservationLifetime: Get<BlockNumberFor<Self>>;
+
+	/// Maximum number of unexpired reservations an account can hold through `reserveId`.
+	type MaxIdReservations: Get<u32>;
+
+	/// Whether `batchTransfer` logs a `Transfer` per recipient when the caller does not choose, and
+	/// whether `batchMint` logs a `Minted` per recipient.
+	type EmitBatchEvents: Get<bool>;
+
+	/// Lowest minimum balance `create` and `setMinBalance` accept, e.g. to keep the accounts of
+	/// assets from being dust, `None` to accept any.
+	type MinBalanceFloor: Get<Option<u128>>;
+
+	/// Ids of the cross-chain assets, which `classifyAddress` tells apart from local ones.
+	type XcAssetIds: Contains<u64>;
+
+	/// Maps an account back to the address `AddressMapping` maps to it, `None` where the mapping
+	/// cannot be reversed, e.g. for the accounts derived by hashing their address. It is read by
+	/// `ownerOf`, which charges it as one storage read.
+	type AccountToAddress: Convert<Self::AccountId, Option<H160>>;
+
+	/// Maximum transfer fee, in basis points, the owner of an asset can set.
+	type MaxTransferFeeBps: Get<u16>;
+
+	/// Address the transfer fees set through the factory are sent to.
+	type TransferFeeTreasury: Get<H160>;
+
+	/// Decimals of the metadata set by `setMetadataDefaults`.
+	type DefaultDecimals: Get<u8>;
+
+	/// Placeholder name and symbol `create` gives every asset created without metadata, along
+	/// with the default decimals, so that no asset is without metadata, `None` to create assets
+	/// without metadata. The methods which set the metadata of the asset they create, e.g.
+	/// `createWithMetadata`, set it once, without the placeholder.
+	type DefaultMetadata: Get<Option<(Vec<u8>, Vec<u8>)>>;
+
+	/// Whether `setTeam` rejects a team in which an account has several roles, to separate the
+	/// duties of the issuer, the admin and the freezer.
+	type RolesMustBeDistinct: Get<bool>;
+
+	/// Whether only the account which started the destruction of an asset through `startDestroy`
+	/// can finish it through `finishDestroy` or `forceFinishDestroy`.
+	type OnlyInitiatorFinishesDestroy: Get<bool>;
+
+	/// Prefix of the addresses of the ERC20 precompile set the runtime wires for the assets, which
+	/// `erc20Address` derives from the id of an asset, `None` if the runtime wires none. Runtimes
+	/// should take it from the prefix they give the precompile set, not restate it.
+	type Erc20FacadePrefix: Get<Option<[u8; 4]>>;
+
+	/// Whether the storage of `pallet_assets` is being migrated, e.g. by a multi-block migration.
+	/// Meanwhile, the mutating methods revert with "pallet migrating, try later" instead of
+	/// failing on storage in an intermediate state, and the views remain available.
+	type AssetsMigrating: Get<bool>;
+}
+
+/// Index of the `pallet_assets` instance, keying the state the precompile keeps per instance.
+fn instance_index<Runtime, Instance>() -> u8
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	pallet_assets::Pallet::<Runtime, Instance>::index() as u8
+}
+
+/// Largest id the `u64` ids of the precompile can be converted to an `AssetId` up to.
+///
+/// The ids converting are expected to be the ones up to a bound, like with a `u32` asset id, and
+/// the bound is searched for by conversion. It is only used to describe the ids which are too
+/// large.
+fn max_asset_id<AssetId: TryFrom<u64>>() -> u64 {
+	let (mut low, mut high) = (0u64, u64::MAX);
+	while low < high {
+		let middle = low + (high - low) / 2 + 1;
+		if AssetId::try_from(middle).is_ok() {
+			low = middle;
+		} else {
+			high = middle - 1;
+		}
+	}
+
+	low
+}
+
+/// Converts `id` to an `AssetId`, reverting with the largest id of the runtime if it does not
+/// fit.
+fn asset_id_of<AssetId: TryFrom<u64>>(id: u64) -> MayRevert<AssetId> {
+	id.try_into().map_err(|_| {
+		RevertReason::value_is_too_large(alloc::format!(
+			"asset id (max {})",
+			max_asset_id::<AssetId>()
+		))
+		.into()
+	})
+}
+
+/// Converts `min_balance` to the balance type, reverting if it does not fit.
+fn min_balance_of<Balance: TryFrom<u128>>(min_balance: u128) -> MayRevert<Balance> {
+	min_balance
+		.try_into()
+		.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("minBalance"))
+}
+
+/// Checks that `min_balance` meets the `MinBalanceFloor` of the runtime, if it has one.
+fn check_min_balance_floor<Runtime: AssetsFactoryConfig>(min_balance: u128) -> EvmResult {
+	match Runtime::MinBalanceFloor::get() {
+		Some(floor) if min_balance < floor => Err(revert("min_balance below floor")),
+		_ => Ok(()),
+	}
+}
+
+/// Checks that `bytes` fit the `StringLimit` of the pallet, so that oversized input is rejected
+/// before being copied, or paid for as the data of a log.
+fn check_string_limit<Runtime, Instance>(bytes: &BoundedBytes<GetBytesLimit>) -> MayRevert
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	let limit = <Runtime as pallet_assets::Config<Instance>>::StringLimit::get();
+	if bytes.as_bytes().len() > limit as usize {
+		return Err(RevertReason::custom(alloc::format!("exceeds the string limit of {limit}")))
+	}
+
+	Ok(())
+}
+
+/// Revert of the methods on an existing asset called with the id of one which does not exist.
+fn asset_not_found() -> PrecompileFailure {
+	RevertReason::custom("asset not found").in_field("id").into()
+}
+
+/// Reference counts of an asset, as returned by `accountCounts`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct AccountCounts {
+	/// Accounts holding the asset, whatever the reason they exist for.
+	pub accounts: u32,
+	/// Accounts existing because the asset is sufficient, a subset of `accounts`.
+	pub sufficients: u32,
+	pub approvals: u32,
+}
+
+/// `kind` of an address which is not the one of an existing asset.
+pub const ADDRESS_KIND_NONE: u8 = 0;
+/// `kind` of the address of a local asset.
+pub const ADDRESS_KIND_LOCAL_ASSET: u8 = 1;
+/// `kind` of the address of a cross-chain asset.
+pub const ADDRESS_KIND_XC_ASSET: u8 = 2;
+
+/// Kind of an address, as returned by `classifyAddress`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct AddressClass {
+	/// One of the `ADDRESS_KIND_*` constants.
+	pub kind: u8,
+	/// Id of the asset, zero if the address is not the one of an asset.
+	pub id: u64,
+}
+
+/// `status` of a live asset.
+pub const ASSET_STATUS_LIVE: u8 = 0;
+/// `status` of a frozen asset.
+pub const ASSET_STATUS_FROZEN: u8 = 1;
+/// `status` of an asset being destroyed.
+pub const ASSET_STATUS_DESTROYING: u8 = 2;
+
+/// Bit of `permissions` set if the asset can be created, as it does not exist.
+pub const PERMISSION_CREATE: u8 = 1 << 0;
+/// Bit of `permissions` set if the caller is the issuer of the live asset.
+pub const PERMISSION_MINT: u8 = 1 << 1;
+/// Bit of `permissions` set if the caller is the admin of the live asset.
+pub const PERMISSION_BURN: u8 = 1 << 2;
+/// Bit of `permissions` set if the caller holds some of the live asset and is not frozen.
+pub const PERMISSION_TRANSFER: u8 = 1 << 3;
+/// Bit of `permissions` set if the caller is the owner of the live asset.
+pub const PERMISSION_SET_METADATA: u8 = 1 << 4;
+/// Bit of `permissions` set if the caller is the owner of the asset, which is not being
+/// destroyed already.
+pub const PERMISSION_DESTROY: u8 = 1 << 5;
+
+/// Details and metadata of an asset, as returned by `tokenInfoBatch`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct TokenInfo {
+	/// Whether the asset exists, the other fields being zeroed if it does not.
+	pub exists: bool,
+	pub name: UnboundedBytes,
+	pub symbol: UnboundedBytes,
+	pub decimals: u8,
+	/// Never truncated, the balance type converting to a `U256`.
+	pub total_supply: U256,
+	pub min_balance: U256,
+	/// One of the `ASSET_STATUS_*` constants.
+	pub status: u8,
+}
+
+/// Parameters of `safeLaunch`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct LaunchParams {
+	pub id: u64,
+	pub admin: Address,
+	pub min_balance: u128,
+	pub name: BoundedBytes<GetBytesLimit>,
+	pub symbol: BoundedBytes<GetBytesLimit>,
+	pub decimals: u8,
+	pub issuer: Address,
+	pub freezer: Address,
+	/// Supply minted by the caller, which must then be the issuer. Nothing is minted if zero.
+	pub initial_supply: U256,
+	pub initial_holder: Address,
+}
+
+/// Prefixes the reason of `failure` with `step`, the step of a method it occurred in. Failures
+/// which are not reverts, and the reverts of dispatches running out of gas, are returned as they
+/// are, so that the latter still start with `OUT_OF_GAS_REVERT_PREFIX`.
+fn failed_step(step: &str, failure: PrecompileFailure) -> PrecompileFailure {
+	match failure {
+		PrecompileFailure::Revert { output, .. } => {
+			let cause = last_error::revert_reason(&output);
+			if cause.starts_with(OUT_OF_GAS_REVERT_PREFIX.as_bytes()) {
+				return revert(cause)
+			}
+
+			let mut reason = alloc::format!("{step}: ").into_bytes();
+			reason.extend(cause);
+
+			revert(reason)
+		},
+		failure => failure,
+	}
+}
+
+/// Log of the factory whose cost is recorded when it is built, like the other costs of a method
+/// before the work it reports, and which is emitted once the work is done. The factory emits its
+/// logs through it only, so that none is emitted without being paid for.
+#[must_use]
+struct PaidLog(Log);
+
+impl PaidLog {
+	/// Records the cost of `log`.
+	fn new(handle: &mut impl PrecompileHandle, log: Log) -> EvmResult<Self> {
+		handle.record_log_costs(&[&log])?;
+
+		Ok(Self(log))
+	}
+
+	fn emit(self, handle: &mut impl PrecompileHandle) -> EvmResult {
+		self.0.record(handle)
+	}
+}
+
+/// `code` of the last error when no atomic method failed in the transaction.
+pub const LAST_ERROR_NONE: u8 = 0;
+/// `code` of the last error when it is a revert, the message being its reason.
+pub const LAST_ERROR_REVERT: u8 = 1;
+/// `code` of the last error when it is an EVM error, like running out of gas, the message
+/// being its name.
+pub const LAST_ERROR_EXIT: u8 = 2;
+
+/// `amountConversionMode` when the amounts not fitting the balance type are saturated.
+pub const AMOUNT_CONVERSION_SATURATING: u8 = 0;
+/// `amountConversionMode` when the amounts not fitting the balance type are rejected.
+pub const AMOUNT_CONVERSION_CHECKED: u8 = 1;
+
+/// Last failure of an atomic method in the transaction, as returned by `lastError`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct LastError {
+	/// One of the `LAST_ERROR_*` constants.
+	pub code: u8,
+	pub message: UnboundedBytes,
+}
+
+/// Number of calls to a method.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct MethodCalls {
+	pub selector: u32,
+	pub calls: u32,
+}
+
+/// Calls to the mutating methods of the factory in the current block, as returned by `callStats`.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct CallStats {
+	pub block: u64,
+	/// Calls to all the mutating methods.
+	pub total: u32,
+	/// Calls by method, sorted by selector. The methods not called in the block are left out.
+	pub methods: Vec<MethodCalls>,
+}
+
+/// State the precompiles keep about an asset besides the one of `pallet_assets`, as returned by
+/// `extendedConfig`. The fields of which nothing is kept are zero.
+#[derive(Default, Debug, PartialEq, Eq, solidity::Codec)]
+pub struct ExtendedConfig {
+	/// Set by `setSupplyCap`, zero if the asset is not capped.
+	pub supply_cap: U256,
+	/// Set by `setTransferFeeBps`, along with the treasury the fees are sent to.
+	pub transfer_fee_bps: u16,
+	pub transfer_fee_treasury: Address,
+	/// Account which reserved the id through `reserveId` and the block it did, whether the
+	/// reservation expired or not.
+	pub reserved_by: H256,
+	pub reserved_at: u64,
+	/// Blocks the current destroy confirmation token was issued in and the destruction started
+	/// in, through `startDestroy`.
+	pub destroy_token_issued_at: u64,
+	pub destroy_started_at: u64,
+	/// Set by `setAssetPaused`.
+	pub paused: bool,
+}
+
 pub struct AssetsFactoryPrecompile<Runtime, Instance: 'static = ()>(
 	PhantomData<(Runtime, Instance)>,
 );
@@ -58,28 +466,249 @@ impl<Runtime, Instance> AssetsFactoryPrecompile<Runtime, Instance>
 where
 	Instance: 'static,
 	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + frame_system::Config,
+	Runtime: pallet_timestamp::Config,
 	Runtime::RuntimeCall: Dispatchable<PostInfo = PostDispatchInfo> + GetDispatchInfo,
 	Runtime::RuntimeCall: From<pallet_assets::Call<Runtime, Instance>>,
 	<Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin: From<Option<Runtime::AccountId>>,
 	BalanceOf<Runtime, Instance>: TryFrom<U256> + Into<U256> + solidity::Codec,
-	AssetIdOf<Runtime, Instance>: TryFrom<u64> + AssetIdExt,
+	AssetIdOf<Runtime, Instance>: TryFrom<u64> + TryInto<u64> + AssetIdExt,
 	AssetIdParameterOf<Runtime, Instance>: TryFrom<u64> + AssetIdExt,
 	Runtime: EVMAddressToAssetId<AssetIdOf<Runtime, Instance>>,
+	Runtime: AssetsFactoryConfig,
+	Runtime::AccountId: Into<[u8; 32]>,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin: OriginTrait,
 {
+	/// Rejects the calls to the mutating methods while `pallet_assets` is migrating, otherwise
+	/// counts them and, with the `audit` feature, logs them.
+	#[precompile::pre_check]
+	fn pre_check(handle: &mut impl PrecompileHandle) -> EvmResult {
+		let selector = match solidity::codec::selector(handle.input()) {
+			Some(selector) => selector,
+			None => return Ok(()),
+		};
+		if !FactoryCall::<Runtime, Instance>::supports_selector(selector) || Self::is_view(selector)
+		{
+			return Ok(())
+		}
+		if Runtime::AssetsMigrating::get() {
+			return Err(revert("pallet migrating, try later"))
+		}
+
+		call_stats::note::<Runtime, Instance>(handle, selector)?;
+
+		#[cfg(feature = "audit")]
+		Self::log_action(handle, selector)?;
+
+		Ok(())
+	}
+
+	/// Logs the call of the caller to the mutating method of `selector` for the audit trail. All
+	/// the mutating methods take the id of the asset first, `safeLaunch` as the first field of its
+	/// parameters, except `createFromTemplate` whose call is logged once for each of its ids. The
+	/// logs are recorded before the ones of the method, and a revert of the method discards them
+	/// along with them.
+	#[cfg(feature = "audit")]
+	fn log_action(handle: &mut impl PrecompileHandle, selector: u32) -> EvmResult {
+		let arguments = &handle.input()[4..];
+		#[cfg(feature = "create")]
+		if FactoryCall::<Runtime, Instance>::create_from_template_selectors().contains(&selector) {
+			let ids =
+				match solidity::decode_arguments::<(BoundedVec<u64, GetArrayLimit>,)>(arguments) {
+					Ok((ids,)) => Vec::from(ids),
+					Err(_) => return Ok(()),
+				};
+
+			return ids.into_iter().try_for_each(|id| Self::log_action_of(handle, selector, id))
+		}
+
+		#[cfg(all(feature = "create", feature = "team", feature = "issuance"))]
+		let id = if FactoryCall::<Runtime, Instance>::safe_launch_selectors().contains(&selector) {
+			solidity::decode_arguments::<LaunchParams>(arguments).map(|params| params.id)
+		} else {
+			solidity::decode_arguments::<u64>(arguments)
+		};
+		#[cfg(not(all(feature = "create", feature = "team", feature = "issuance")))]
+		let id = solidity::decode_arguments::<u64>(arguments);
+		// The method cannot decode its arguments either, and reverts with the reason.
+		let id = match id {
+			Ok(id) => id,
+			Err(_) => return Ok(()),
+		};
+
+		Self::log_action_of(handle, selector, id)
+	}
+
+	/// Logs the call of the caller to the mutating method of `selector` on the asset `id`.
+	#[cfg(feature = "audit")]
+	fn log_action_of(handle: &mut impl PrecompileHandle, selector: u32, id: u64) -> EvmResult {
+		// A `bytes4` is padded on the right.
+		let mut event_data = [0u8; 32];
+		event_data[..4].copy_from_slice(&selector.to_be_bytes());
+
+		let log = LogsBuilder::new(handle.context().address).log3(
+			SELECTOR_LOG_ACTION_PERFORMED,
+			handle.context().caller,
+			H256::from_low_u64_be(id),
+			event_data.to_vec(),
+		);
+		PaidLog::new(handle, log)?.emit(handle)
+	}
+
+	/// Tells whether `selector` is the one of a view, whose calls are not counted. The views are
+	/// the methods marked `#[precompile::view]`, so that a new one cannot be left out.
+	fn is_view(selector: u32) -> bool {
+		FactoryCall::<Runtime, Instance>::view_selectors().contains(&selector)
+	}
+
 	#[precompile::public("convertAssetIdToAddress(uint64)")]
 	#[precompile::view]
 	fn convert_asset_id_to_address(
-		_handle: &mut impl PrecompileHandle,
+		handle: &mut impl PrecompileHandle,
 		id: u64,
 	) -> EvmResult<Address> {
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		Ok(Self::asset_address(handle, asset_id)?.into())
+	}
+
+	/// Returns the address of the ERC20 precompile of the runtime for the asset, the prefix of the
+	/// facade followed by the id over 16 bytes, for the integrations expecting a plain ERC20. It
+	/// is only derived, whether the asset exists or not.
+	#[precompile::public("erc20Address(uint64)")]
+	#[precompile::view]
+	fn erc20_address(_handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<Address> {
+		asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let prefix = Runtime::Erc20FacadePrefix::get().ok_or_else(|| revert("no erc20 facade"))?;
+
+		let mut address = [0u8; 20];
+		address[0..4].copy_from_slice(&prefix);
+		address[4..20].copy_from_slice(&u128::from(id).to_be_bytes());
+
+		Ok(Address(address.into()))
+	}
+
+	/// Returns the number of assets of the instance created through the factory, by `create` or
+	/// `forceCreate`, and not destroyed through it. `pallet_assets` keeps no count of its assets,
+	/// so the ones created outside of the factory are not counted.
+	#[precompile::public("assetCount()")]
+	#[precompile::view]
+	fn asset_count(handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		asset_count::get::<Runtime, Instance>(handle)
+	}
+
+	/// Tells whether `address` is the one of an existing local or cross-chain asset, along with
+	/// the id of the asset.
+	#[precompile::public("classifyAddress(address)")]
+	#[precompile::view]
+	fn classify_address(
+		handle: &mut impl PrecompileHandle,
+		address: Address,
+	) -> EvmResult<AddressClass> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = match Runtime::address_to_asset_id(address.into()) {
+			Some(asset_id) => asset_id,
+			None => return Ok(AddressClass::default()),
+		};
+		if !assets_storage::Asset::<Runtime, Instance>::contains_key(asset_id.clone()) {
+			return Ok(AddressClass::default())
+		}
+		let id: u64 = match asset_id.try_into() {
+			Ok(id) => id,
+			Err(_) => return Ok(AddressClass::default()),
+		};
+
+		let kind = if Runtime::XcAssetIds::contains(&id) {
+			ADDRESS_KIND_XC_ASSET
+		} else {
+			ADDRESS_KIND_LOCAL_ASSET
+		};
+
+		Ok(AddressClass { kind, id })
+	}
+
+	/// Tells whether `address` is the one of an existing asset which is live, neither frozen nor
+	/// being destroyed.
+	#[precompile::public("addressIsLiveAsset(address)")]
+	#[precompile::view]
+	fn address_is_live_asset(
+		handle: &mut impl PrecompileHandle,
+		address: Address,
+	) -> EvmResult<bool> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		Ok(Runtime::address_to_asset_id(address.into())
+			.and_then(assets_storage::Asset::<Runtime, Instance>::get)
+			.map_or(false, |details| details.status == AssetStatus::Live))
+	}
+
+	/// Returns the account the caller's address is mapped to, which is the origin of the calls
+	/// the precompile dispatches on its behalf.
+	#[precompile::public("callerAccount()")]
+	#[precompile::view]
+	fn caller_account(handle: &mut impl PrecompileHandle) -> EvmResult<H256> {
+		let account = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		Ok(H256(account.into()))
+	}
+
+	/// Returns the chain id of the EVM, e.g. for the domain of EIP-712 signatures.
+	#[precompile::public("chainId()")]
+	#[precompile::view]
+	fn chain_id(_handle: &mut impl PrecompileHandle) -> EvmResult<u64> {
+		Ok(<Runtime as pallet_evm::Config>::ChainId::get())
+	}
+
+	/// Returns the decimals `setMetadataDefaults` sets.
+	#[precompile::public("defaultDecimals()")]
+	#[precompile::view]
+	fn default_decimals(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		Ok(<Runtime as AssetsFactoryConfig>::DefaultDecimals::get())
+	}
+
+	/// Returns whether `setTeam` rejects a team in which an account has several roles.
+	#[precompile::public("rolesMustBeDistinct()")]
+	#[precompile::view]
+	fn roles_must_be_distinct(_handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		Ok(<Runtime as AssetsFactoryConfig>::RolesMustBeDistinct::get())
+	}
+
+	/// Returns how the amounts given to the factory are converted to the balance type, which is
+	/// always checked: an amount which does not fit reverts instead of being saturated, so clients
+	/// know not to rely on the factory clamping them.
+	#[precompile::public("amountConversionMode()")]
+	#[precompile::view]
+	fn amount_conversion_mode(_handle: &mut impl PrecompileHandle) -> EvmResult<u8> {
+		Ok(AMOUNT_CONVERSION_CHECKED)
+	}
+
+	/// Returns the deposit reserved for an account of an asset when it is touched, e.g. by
+	/// `transferAndTouch`.
+	#[precompile::public("accountDeposit()")]
+	#[precompile::view]
+	fn account_deposit(_handle: &mut impl PrecompileHandle) -> EvmResult<U256> {
+		Ok(<Runtime as pallet_assets::Config<Instance>>::AssetAccountDeposit::get()
+			.saturated_into::<u128>()
+			.into())
+	}
 
-		Ok(Runtime::asset_id_to_address(asset_id).into())
+	/// Returns the lowest minimum balance `create` and `setMinBalance` accept, zero if there is no
+	/// floor.
+	#[precompile::public("minBalanceFloor()")]
+	#[precompile::view]
+	fn min_balance_floor(_handle: &mut impl PrecompileHandle) -> EvmResult<u128> {
+		Ok(<Runtime as AssetsFactoryConfig>::MinBalanceFloor::get().unwrap_or_default())
 	}
 
+	/// Creates an asset with the caller as its owner. Creating an existing asset reverts with
+	/// "asset already exists", before anything is noted, so that the second of two creates of an
+	/// id in the same block fails without side effects.
+	///
+	/// If the runtime has `DefaultMetadata`, the asset is given it along with the default
+	/// decimals, atomically: if setting the metadata fails, the asset is not created either.
+	#[cfg(feature = "create")]
 	#[precompile::public("create(uint64,address,uint128)")]
 	fn create(
 		handle: &mut impl PrecompileHandle,
@@ -87,27 +716,89 @@ where
 		admin: Address,
 		min_balance: u128,
 	) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
+		let (name, symbol) = match <Runtime as AssetsFactoryConfig>::DefaultMetadata::get() {
+			Some(metadata) => metadata,
+			None => return Self::create_asset(handle, id, admin, min_balance),
+		};
+		let decimals = <Runtime as AssetsFactoryConfig>::DefaultDecimals::get();
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = Self::create_asset(handle, id, admin, min_balance).and_then(|()| {
+				Self::set_metadata(handle, id, name.into(), symbol.into(), decimals)
+			});
 
+			match result {
+				Ok(()) => TransactionOutcome::Commit(result),
+				Err(_) => TransactionOutcome::Rollback(result),
+			}
+		})
+	}
+
+	/// Creates the asset of `create`, without its default metadata, for the methods which set the
+	/// metadata of the asset themselves.
+	#[cfg(feature = "create")]
+	fn create_asset(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		admin: Address,
+		min_balance: u128,
+	) -> EvmResult {
 		let admin: H160 = admin.into();
-		let asset_id: AssetIdParameterOf<Runtime, Instance> = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(handle.context().address).log3(
+				SELECTOR_LOG_ASSET_CREATED,
+				H256::from_low_u64_be(id),
+				handle.context().caller,
+				solidity::encode_event_data(Address(admin)),
+			),
+		)?;
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
 
 		if !asset_id.is_allow_to_create() {
+			Self::log_create_rejected(handle, id, CREATE_REJECTED_NOT_ALLOWED)?;
 			return Err(RevertReason::Custom("Invalid asset id".into()).into())
 		}
+		check_min_balance_floor::<Runtime>(min_balance)?;
 
-		let min_balance: BalanceOf<Runtime, Instance> =
-			min_balance.try_into().unwrap_or_else(|_| Bounded::max_value());
+		let min_balance: BalanceOf<Runtime, Instance> = min_balance_of(min_balance)?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+		if assets_storage::Asset::<Runtime, Instance>::contains_key(storage_id.clone()) {
+			Self::log_create_rejected(handle, id, CREATE_REJECTED_ALREADY_EXISTS)?;
+			return Err(RevertReason::custom("asset already exists").in_field("id").into())
+		}
 
 		// Build call with origin.
 		{
 			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 			let admin = Runtime::AddressMapping::into_account_id(admin);
 
+			match id_reservation::check::<Runtime, Instance>(handle, storage_id.clone(), &origin) {
+				Err(failure @ PrecompileFailure::Revert { .. }) => {
+					Self::log_create_rejected(handle, id, CREATE_REJECTED_RESERVED)?;
+					return Err(failure)
+				},
+				result => result?,
+			}
+
+			// The deposit is reserved from the caller, not from the admin. Checking it here
+			// reverts with a clear reason instead of the pallet's balance error.
+			// Storage item: System Account
+			handle.record_db_read::<Runtime>(SYSTEM_ACCOUNT_SIZE as usize)?;
+			let deposit = <Runtime as pallet_assets::Config<Instance>>::AssetDeposit::get();
+			if <Runtime as pallet_assets::Config<Instance>>::Currency::free_balance(&origin) <
+				deposit
+			{
+				return Err(revert("insufficient balance for deposit"))
+			}
+
+			rate_limit::note_create::<Runtime>(handle, &origin)?;
+
 			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
 				handle,
 				Some(origin).into(),
 				pallet_assets::Call::<Runtime, Instance>::create {
@@ -119,9 +810,101 @@ where
 			)?;
 		}
 
+		asset_count::note_created::<Runtime, Instance>(handle, storage_id.clone())?;
+		id_reservation::remove::<Runtime, Instance>(handle, storage_id)?;
+
+		log.emit(handle)
+	}
+
+	/// Logs, with the `audit` feature, that `create` rejects `id` for `reason`, one of the
+	/// `CREATE_REJECTED_*` constants. The revert which follows discards the log from the
+	/// receipt, so it is only seen by the tracers of the call, like the test harness.
+	#[cfg(all(feature = "create", feature = "audit"))]
+	fn log_create_rejected(handle: &mut impl PrecompileHandle, id: u64, reason: u8) -> EvmResult {
+		let log = LogsBuilder::new(handle.context().address).log2(
+			SELECTOR_LOG_CREATE_REJECTED,
+			H256::from_low_u64_be(id),
+			solidity::encode_event_data(reason),
+		);
+		PaidLog::new(handle, log)?.emit(handle)
+	}
+
+	#[cfg(all(feature = "create", not(feature = "audit")))]
+	fn log_create_rejected(
+		_handle: &mut impl PrecompileHandle,
+		_id: u64,
+		_reason: u8,
+	) -> EvmResult {
+		Ok(())
+	}
+
+	/// Like `create`, with the caller as the admin of the asset.
+	#[cfg(feature = "create")]
+	#[precompile::public("createSelfAdmin(uint64,uint128)")]
+	fn create_self_admin(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		min_balance: u128,
+	) -> EvmResult {
+		let admin = Address(handle.context().caller);
+
+		Self::create(handle, id, admin, min_balance)
+	}
+
+	/// Like `create`, but succeeds without creating anything if the asset already exists with the
+	/// same admin and minimum balance, so that deployment scripts can be re-run.
+	#[cfg(feature = "create")]
+	#[precompile::public("createIdempotent(uint64,address,uint128)")]
+	fn create_idempotent(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		admin: Address,
+		min_balance: u128,
+	) -> EvmResult {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		let details = match assets_storage::Asset::<Runtime, Instance>::get(asset_id) {
+			Some(details) => details,
+			None => return Self::create(handle, id, admin, min_balance),
+		};
+
+		let admin = Runtime::AddressMapping::into_account_id(admin.into());
+		let min_balance: BalanceOf<Runtime, Instance> = min_balance_of(min_balance)?;
+		if details.admin != admin || details.min_balance != min_balance {
+			return Err(revert("asset exists with different parameters"))
+		}
+
 		Ok(())
 	}
 
+	/// Reserves the id of an asset which does not exist yet, so that only the caller can create
+	/// it during the next `IdReservationLifetime` blocks. The reservation is removed once the
+	/// asset is created, and cannot be renewed: reverts with "id reservation cannot be renewed"
+	/// if the caller reserved the id before, and with "too many id reservations" if it holds
+	/// `MaxIdReservations` unexpired reservations already.
+	#[cfg(feature = "create")]
+	#[precompile::public("reserveId(uint64)")]
+	fn reserve_id(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		if !asset_id.is_allow_to_create() {
+			return Err(RevertReason::Custom("Invalid asset id".into()).into())
+		}
+		let asset_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+		if assets_storage::Asset::<Runtime, Instance>::contains_key(asset_id.clone()) {
+			return Err(RevertReason::custom("asset already exists").in_field("id").into())
+		}
+
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		id_reservation::reserve::<Runtime, Instance>(handle, asset_id, caller)
+	}
+
+	#[cfg(feature = "create")]
 	#[precompile::public("setMetadata(uint64,bytes,bytes,uint8)")]
 	fn set_metadata(
 		handle: &mut impl PrecompileHandle,
@@ -130,20 +913,38 @@ where
 		symbol: BoundedBytes<GetBytesLimit>,
 		decimals: u8,
 	) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
+		check_string_limit::<Runtime, Instance>(&name).in_field("name")?;
+		check_string_limit::<Runtime, Instance>(&symbol).in_field("symbol")?;
+		Self::ensure_asset_exists(handle, id)?;
 
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(handle.context().address).log2(
+				SELECTOR_LOG_METADATA_SET,
+				H256::from_low_u64_be(id),
+				solidity::encode_event_data((name.clone(), symbol.clone(), decimals)),
+			),
+		)?;
+		// Storage item: Metadata
+		handle.record_db_read::<Runtime>(AssetMetadataOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
 		let name: Vec<_> = name.into();
 		let symbol: Vec<_> = symbol.into();
 
+		// Only the force origin can change frozen metadata, report it rather than the pallet's
+		// generic `NoPermission`.
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		if assets_storage::Metadata::<Runtime, Instance>::get(storage_id).is_frozen {
+			return Err(revert("metadata is frozen"))
+		}
+
 		// Build call with origin.
 		{
 			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
 
 			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
 				handle,
 				Some(origin).into(),
 				pallet_assets::Call::<Runtime, Instance>::set_metadata {
@@ -156,69 +957,400 @@ where
 			)?;
 		}
 
-		Ok(())
+		log.emit(handle)
 	}
 
-	#[precompile::public("setMinBalance(uint64,uint128)")]
-	fn set_min_balance(
+	/// Like `setMetadata`, with the default decimals of the runtime.
+	#[cfg(feature = "create")]
+	#[precompile::public("setMetadataDefaults(uint64,bytes,bytes)")]
+	fn set_metadata_defaults(
 		handle: &mut impl PrecompileHandle,
 		id: u64,
-		min_balance: u128,
+		name: BoundedBytes<GetBytesLimit>,
+		symbol: BoundedBytes<GetBytesLimit>,
 	) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
+		let decimals = <Runtime as AssetsFactoryConfig>::DefaultDecimals::get();
 
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		Self::set_metadata(handle, id, name, symbol, decimals)
+	}
 
-		let min_balance: BalanceOf<Runtime, Instance> =
-			min_balance.try_into().unwrap_or_else(|_| Bounded::max_value());
+	/// Like `setMetadata`, keeping the decimals the asset already has so that a rename cannot
+	/// change them by mistake. Reverts if the asset has no metadata yet, as it has no decimals to
+	/// keep either.
+	#[cfg(feature = "create")]
+	#[precompile::public("rename(uint64,bytes,bytes)")]
+	fn rename(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		name: BoundedBytes<GetBytesLimit>,
+		symbol: BoundedBytes<GetBytesLimit>,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		// Storage item: Metadata
+		handle.record_db_read::<Runtime>(AssetMetadataOf::<Runtime, Instance>::max_encoded_len())?;
 
-		// Build call with origin.
-		{
-			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let decimals = match assets_storage::Metadata::<Runtime, Instance>::try_get(asset_id) {
+			Ok(metadata) => metadata.decimals,
+			Err(()) => return Err(revert("asset has no metadata to rename")),
+		};
 
-			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
-				handle,
-				Some(origin).into(),
-				pallet_assets::Call::<Runtime, Instance>::set_min_balance {
-					id: asset_id,
-					min_balance,
-				},
-				SYSTEM_ACCOUNT_SIZE,
-			)?;
+		Self::set_metadata(handle, id, name, symbol, decimals)
+	}
+
+	/// Sets the name, symbol and decimals of asset `from` as the metadata of asset `to`, e.g. when
+	/// an asset is migrated to a new id. The caller must own both assets. Reverts if `from` has no
+	/// metadata to copy.
+	#[cfg(feature = "create")]
+	#[precompile::public("copyMetadata(uint64,uint64)")]
+	fn copy_metadata(handle: &mut impl PrecompileHandle, from: u64, to: u64) -> EvmResult {
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		for (id, field) in [(from, "from"), (to, "to")] {
+			// Storage item: Asset
+			handle
+				.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+			let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field(field)?;
+			let details = assets_storage::Asset::<Runtime, Instance>::get(asset_id)
+				.ok_or_else(|| RevertReason::custom("asset not found").in_field(field))?;
+			if details.owner != caller {
+				return Err(RevertReason::custom("caller is not the asset owner")
+					.in_field(field)
+					.into())
+			}
 		}
 
-		Ok(())
+		// Storage item: Metadata
+		handle.record_db_read::<Runtime>(AssetMetadataOf::<Runtime, Instance>::max_encoded_len())?;
+		let from_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(from).in_field("from")?;
+		let metadata = assets_storage::Metadata::<Runtime, Instance>::try_get(from_id)
+			.map_err(|()| revert("asset has no metadata to copy"))?;
+
+		Self::set_metadata(
+			handle,
+			to,
+			metadata.name.into_inner().into(),
+			metadata.symbol.into_inner().into(),
+			metadata.decimals,
+		)
 	}
 
-	#[precompile::public("setTeam(uint64,address,address,address)")]
-	fn set_team(
+	/// Creates an asset and sets its metadata atomically, so that it never exists without
+	/// metadata. If setting the metadata fails, the asset is not created either.
+	#[cfg(feature = "create")]
+	#[precompile::public("createWithMetadata(uint64,address,uint128,bytes,bytes,uint8)")]
+	fn create_with_metadata(
 		handle: &mut impl PrecompileHandle,
 		id: u64,
-		issuer: Address,
 		admin: Address,
-		freezer: Address,
+		min_balance: u128,
+		name: BoundedBytes<GetBytesLimit>,
+		symbol: BoundedBytes<GetBytesLimit>,
+		decimals: u8,
 	) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = Self::create_asset(handle, id, admin, min_balance)
+				.and_then(|()| Self::set_metadata(handle, id, name, symbol, decimals));
 
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
-		let issuer: H160 = issuer.into();
-		let admin: H160 = admin.into();
-		let freezer: H160 = freezer.into();
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Creates an asset for each of `ids` with the shared `admin`, `minBalance` and `decimals`,
+	/// named after the name and symbol of the same index. The whole batch is atomic: if any entry
+	/// fails, none of the assets is created, and the reason is prefixed with the index of the
+	/// entry.
+	#[cfg(feature = "create")]
+	#[precompile::public("createFromTemplate(uint64[],bytes[],bytes[],address,uint128,uint8)")]
+	fn create_from_template(
+		handle: &mut impl PrecompileHandle,
+		ids: BoundedVec<u64, GetArrayLimit>,
+		names: BoundedVec<BoundedBytes<GetBytesLimit>, GetArrayLimit>,
+		symbols: BoundedVec<BoundedBytes<GetBytesLimit>, GetArrayLimit>,
+		admin: Address,
+		min_balance: u128,
+		decimals: u8,
+	) -> EvmResult {
+		let ids: Vec<_> = ids.into();
+		let names: Vec<_> = names.into();
+		let symbols: Vec<_> = symbols.into();
+		if ids.len() != names.len() {
+			return Err(RevertReason::custom("ids and names lengths differ")
+				.in_field("names")
+				.into())
+		}
+		if ids.len() != symbols.len() {
+			return Err(RevertReason::custom("ids and symbols lengths differ")
+				.in_field("symbols")
+				.into())
+		}
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = ids
+				.into_iter()
+				.zip(names.into_iter().zip(symbols))
+				.enumerate()
+				.try_for_each(|(index, (id, (name, symbol)))| {
+					Self::create_asset(handle, id, admin, min_balance)
+						.and_then(|()| Self::set_metadata(handle, id, name, symbol, decimals))
+						.map_err(|failure| failed_step(&alloc::format!("ids[{index}]"), failure))
+				});
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Creates an asset and sets its whole team atomically, so that it never exists with only an
+	/// admin. If setting the team fails, the asset is not created either.
+	#[cfg(all(feature = "create", feature = "team"))]
+	#[precompile::public("createWithTeam(uint64,address,uint128,address,address)")]
+	fn create_with_team(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		admin: Address,
+		min_balance: u128,
+		issuer: Address,
+		freezer: Address,
+	) -> EvmResult {
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = Self::create(handle, id, admin, min_balance)
+				.and_then(|()| Self::set_team(handle, id, issuer, admin, freezer));
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Launches an asset atomically: creates it, sets its metadata and team, and mints its
+	/// initial supply if any. The parameters are validated first, the caller having to afford the
+	/// deposits of the asset and its metadata. If any step fails, nothing is launched and the
+	/// revert reason is prefixed with the step, one of `validate`, `create`, `setMetadata`,
+	/// `setTeam` and `mint`.
+	#[cfg(all(feature = "create", feature = "team", feature = "issuance"))]
+	#[precompile::public(
+		"safeLaunch((uint64,address,uint128,bytes,bytes,uint8,address,address,uint256,address))"
+	)]
+	fn safe_launch(handle: &mut impl PrecompileHandle, params: LaunchParams) -> EvmResult {
+		let LaunchParams {
+			id,
+			admin,
+			min_balance,
+			name,
+			symbol,
+			decimals,
+			issuer,
+			freezer,
+			initial_supply,
+			initial_holder,
+		} = params;
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = Self::validate_launch(handle, id, admin, min_balance, &name, &symbol)
+				.map_err(|failure| failed_step("validate", failure))
+				.and_then(|()| {
+					Self::create_asset(handle, id, admin, min_balance)
+						.map_err(|failure| failed_step("create", failure))
+				})
+				.and_then(|()| {
+					Self::set_metadata(handle, id, name, symbol, decimals)
+						.map_err(|failure| failed_step("setMetadata", failure))
+				})
+				.and_then(|()| {
+					Self::set_team(handle, id, issuer, admin, freezer)
+						.map_err(|failure| failed_step("setTeam", failure))
+				})
+				.and_then(|()| {
+					if initial_supply.is_zero() {
+						return Ok(())
+					}
+
+					Self::mint(handle, id, initial_holder, initial_supply)
+						.map_err(|failure| failed_step("mint", failure))
+				});
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Checks the parameters of `safeLaunch` which the pallet would otherwise only reject after
+	/// some of the steps, or not at all.
+	#[cfg(all(feature = "create", feature = "team", feature = "issuance"))]
+	fn validate_launch(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		admin: Address,
+		min_balance: u128,
+		name: &BoundedBytes<GetBytesLimit>,
+		symbol: &BoundedBytes<GetBytesLimit>,
+	) -> EvmResult {
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		if !asset_id.is_allow_to_create() {
+			return Err(revert("asset id not allowed"))
+		}
+		if min_balance.is_zero() {
+			return Err(revert("min balance is zero"))
+		}
+		if H160::from(admin).is_zero() {
+			return Err(revert("admin is the zero address"))
+		}
+
+		// Storage item: System Account
+		handle.record_db_read::<Runtime>(SYSTEM_ACCOUNT_SIZE as usize)?;
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let metadata_len = name.as_bytes().len().saturating_add(symbol.as_bytes().len());
+		let deposits = <Runtime as pallet_assets::Config<Instance>>::AssetDeposit::get()
+			.saturating_add(<Runtime as pallet_assets::Config<Instance>>::MetadataDepositBase::get())
+			.saturating_add(
+				<Runtime as pallet_assets::Config<Instance>>::MetadataDepositPerByte::get()
+					.saturating_mul((metadata_len as u32).into()),
+			);
+		if <Runtime as pallet_assets::Config<Instance>>::Currency::free_balance(&caller) < deposits
+		{
+			return Err(revert("insufficient balance for deposits"))
+		}
+
+		Ok(())
+	}
+
+	/// Sets the minimum balance of an asset. Unlike `create`, a minimum balance that does not fit
+	/// the balance type is rejected instead of being saturated.
+	#[cfg(feature = "create")]
+	#[precompile::public("setMinBalance(uint64,uint128)")]
+	fn set_min_balance(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		min_balance: u128,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		check_min_balance_floor::<Runtime>(min_balance)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
+
+		let new_min_balance: BalanceOf<Runtime, Instance> = min_balance_of(min_balance)?;
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(handle.context().address).log2(
+				SELECTOR_LOG_MIN_BALANCE_CHANGED,
+				H256::from_low_u64_be(id),
+				solidity::encode_event_data(min_balance),
+			),
+		)?;
 
 		// Build call with origin.
 		{
 			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
-			let issuer = Runtime::AddressMapping::into_account_id(issuer);
-			let admin = Runtime::AddressMapping::into_account_id(admin);
-			let freezer = Runtime::AddressMapping::into_account_id(freezer);
 
 			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::set_min_balance {
+					id: asset_id,
+					min_balance: new_min_balance,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		log.emit(handle)
+	}
+
+	/// Sets the minimum balance of each of `ids` to the value of the same index, as
+	/// `setMinBalance` does. The whole batch is atomic: if any entry fails, including a value
+	/// that does not fit the balance type, no minimum balance is changed, and the reason is
+	/// prefixed with the index of the entry.
+	#[cfg(feature = "create")]
+	#[precompile::public("setMinBalanceBatch(uint64[],uint128[])")]
+	fn set_min_balance_batch(
+		handle: &mut impl PrecompileHandle,
+		ids: BoundedVec<u64, GetArrayLimit>,
+		min_balances: BoundedVec<u128, GetArrayLimit>,
+	) -> EvmResult {
+		let ids: Vec<_> = ids.into();
+		let min_balances: Vec<_> = min_balances.into();
+		if ids.len() != min_balances.len() {
+			return Err(RevertReason::custom("ids and minBalances lengths differ")
+				.in_field("minBalances")
+				.into())
+		}
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = ids.into_iter().zip(min_balances).enumerate().try_for_each(
+				|(index, (id, min_balance))| {
+					Self::set_min_balance(handle, id, min_balance)
+						.map_err(|failure| failed_step(&alloc::format!("ids[{index}]"), failure))
+				},
+			);
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	#[cfg(feature = "create")]
+	#[precompile::public("createsRemaining(address)")]
+	#[precompile::view]
+	fn creates_remaining(handle: &mut impl PrecompileHandle, account: Address) -> EvmResult<u32> {
+		let account = Runtime::AddressMapping::into_account_id(account.into());
+
+		rate_limit::creates_remaining::<Runtime>(handle, &account)
+	}
+
+	/// Sets the issuer, the admin and the freezer of an asset, logging a `TeamChanged` unless the
+	/// team is unchanged. If the runtime requires the roles to be distinct, no account can hold
+	/// two of them.
+	#[cfg(feature = "team")]
+	#[precompile::public("setTeam(uint64,address,address,address)")]
+	fn set_team(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		issuer: Address,
+		admin: Address,
+		freezer: Address,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let team_changed = LogsBuilder::new(handle.context().address).log2(
+			SELECTOR_LOG_TEAM_CHANGED,
+			H256::from_low_u64_be(id),
+			solidity::encode_event_data((issuer, admin, freezer)),
+		);
+
+		// Build call with origin.
+		let log = {
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let issuer = Runtime::AddressMapping::into_account_id(issuer.into());
+			let admin = Runtime::AddressMapping::into_account_id(admin.into());
+			let freezer = Runtime::AddressMapping::into_account_id(freezer.into());
+
+			if <Runtime as AssetsFactoryConfig>::RolesMustBeDistinct::get() &&
+				(issuer == admin || issuer == freezer || admin == freezer)
+			{
+				return Err(revert("roles must be distinct"))
+			}
+
+			// Storage item: Asset
+			handle
+				.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+			// Setting the current team is a no-op, which the pallet would still dispatch and
+			// deposit a `TeamChanged` event for. Only the calls the pallet would accept are
+			// skipped, the others are dispatched to fail as usual.
+			let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+			let unchanged = assets_storage::Asset::<Runtime, Instance>::get(storage_id).map_or(
+				false,
+				|details| {
+					details.status == AssetStatus::Live &&
+						details.owner == origin &&
+						details.issuer == issuer &&
+						details.admin == admin &&
+						details.freezer == freezer
+				},
+			);
+			if unchanged {
+				return Ok(())
+			}
+			let log = PaidLog::new(handle, team_changed)?;
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
 				handle,
 				Some(origin).into(),
 				pallet_assets::Call::<Runtime, Instance>::set_team {
@@ -229,22 +1361,22 @@ where
 				},
 				SYSTEM_ACCOUNT_SIZE,
 			)?;
-		}
 
-		Ok(())
+			log
+		};
+
+		log.emit(handle)
 	}
 
+	#[cfg(feature = "team")]
 	#[precompile::public("transferOwnership(uint64,address)")]
 	fn transfer_ownership(
 		handle: &mut impl PrecompileHandle,
 		id: u64,
 		owner: Address,
 	) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
-
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
 		let owner: H160 = owner.into();
 
 		// Build call with origin.
@@ -253,7 +1385,7 @@ where
 			let owner = Runtime::AddressMapping::into_account_id(owner);
 
 			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
 				handle,
 				Some(origin).into(),
 				pallet_assets::Call::<Runtime, Instance>::transfer_ownership {
@@ -266,23 +1398,211 @@ where
 
 		Ok(())
 	}
+
+	/// Like `transferOwnership`, but reverts unless the new owner has code, so that an asset meant
+	/// to be governed by a contract is not handed to an EOA by mistake.
+	#[cfg(feature = "team")]
+	#[precompile::public("transferOwnershipToContract(uint64,address)")]
+	fn transfer_ownership_to_contract(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		contract_addr: Address,
+	) -> EvmResult {
+		if get_address_type::<Runtime>(handle, contract_addr.into())? == AddressType::EOA {
+			return Err(revert("target is not a contract"))
+		}
+
+		Self::transfer_ownership(handle, id, contract_addr)
+	}
+
+	/// Sets the team of an asset and transfers its ownership atomically, so that the previous team
+	/// never controls an asset the new owner holds. The team is set first, while the caller is
+	/// still the owner. If either step fails, neither is done.
+	#[cfg(feature = "team")]
+	#[precompile::public("transferOwnershipAndSetTeam(uint64,address,address,address,address)")]
+	fn transfer_ownership_and_set_team(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		new_owner: Address,
+		issuer: Address,
+		admin: Address,
+		freezer: Address,
+	) -> EvmResult {
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = Self::set_team(handle, id, issuer, admin, freezer)
+				.and_then(|()| Self::transfer_ownership(handle, id, new_owner));
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Starts the destruction of an asset, returning the confirmation token `finishDestroy`
+	/// requires. If the owner calls it on an asset already being destroyed, a fresh token is
+	/// issued instead, so that an expired one can be replaced.
+	#[cfg(feature = "destroy")]
 	#[precompile::public("startDestroy(uint64)")]
-	fn start_destroy(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
+	fn start_destroy(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<H256> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
 
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		let renewing = match assets_storage::Asset::<Runtime, Instance>::get(storage_id.clone()) {
+			Some(details) => details.status == AssetStatus::Destroying && details.owner == origin,
+			None => return Err(asset_not_found()),
+		};
+
+		if !renewing {
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin.clone()).into(),
+				pallet_assets::Call::<Runtime, Instance>::start_destroy { id: asset_id },
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+			destroy_token::note_started::<Runtime, Instance>(handle, storage_id.clone(), origin)?;
+		}
+
+		destroy_token::issue::<Runtime, Instance>(handle, id, storage_id)
+	}
+
+	/// Finishes the destruction of an asset, which requires the unexpired confirmation token
+	/// returned by `startDestroy`. If the runtime has `OnlyInitiatorFinishesDestroy`, reverts
+	/// with "destroy must be finished by initiator" unless the caller started the destruction.
+	#[cfg(feature = "destroy")]
+	#[precompile::public("finishDestroy(uint64,bytes32)")]
+	fn finish_destroy(handle: &mut impl PrecompileHandle, id: u64, token: H256) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+
+		destroy_token::check::<Runtime, Instance>(handle, storage_id.clone(), token)?;
 
 		// Build call with origin.
 		{
 			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			destroy_token::check_initiator::<Runtime, Instance>(
+				handle,
+				storage_id.clone(),
+				&origin,
+			)?;
 
 			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
 				handle,
 				Some(origin).into(),
-				pallet_assets::Call::<Runtime, Instance>::start_destroy { id: asset_id },
+				pallet_assets::Call::<Runtime, Instance>::finish_destroy { id: asset_id },
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		asset_count::note_destroyed::<Runtime, Instance>(handle, storage_id.clone())?;
+		destroy_token::remove::<Runtime, Instance>(handle, storage_id)
+	}
+
+	/// Returns the block the destruction of an asset started in, zero if it is not being destroyed
+	/// or if its destruction was not started through the factory.
+	#[precompile::public("destroyStartedAt(uint64)")]
+	#[precompile::view]
+	fn destroy_started_at(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<u64> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		// A destruction finished outside of the factory leaves its start block behind.
+		let destroying = assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone())
+			.map_or(false, |details| details.status == AssetStatus::Destroying);
+		if !destroying {
+			return Ok(0)
+		}
+
+		Ok(destroy_token::started_at::<Runtime, Instance>(handle, asset_id)?
+			.map_or(0, |block| block.saturated_into()))
+	}
+
+	/// Runs the `destroyAccounts` and `destroyApprovals` steps of the destruction of the asset
+	/// until nothing is left, then finishes it. At most `max_iterations` calls are dispatched to
+	/// bound the gas used; `false` is returned if the destruction is not finished yet, in which
+	/// case it should be called again. Only callable by the configured force origin, with the
+	/// unexpired confirmation token returned by `startDestroy` like `finishDestroy`, and by the
+	/// account which started the destruction if the runtime has `OnlyInitiatorFinishesDestroy`.
+	#[cfg(feature = "destroy")]
+	#[precompile::public("forceFinishDestroy(uint64,bytes32,uint32)")]
+	fn force_finish_destroy(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		token: H256,
+		max_iterations: u32,
+	) -> EvmResult<bool> {
+		Self::ensure_force_origin(handle)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		destroy_token::check::<Runtime, Instance>(handle, storage_id.clone(), token)?;
+
+		// The steps are dispatched as the caller, `pallet_assets` requiring a signed origin.
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		destroy_token::check_initiator::<Runtime, Instance>(handle, storage_id.clone(), &origin)?;
+
+		for _ in 0..max_iterations {
+			// Storage item: Asset
+			handle
+				.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+			let details = assets_storage::Asset::<Runtime, Instance>::get(storage_id.clone())
+				.ok_or_else(asset_not_found)?;
+
+			let (call, finished) = if details.accounts > 0 {
+				(pallet_assets::Call::<Runtime, Instance>::destroy_accounts { id: asset_id }, false)
+			} else if details.approvals > 0 {
+				(
+					pallet_assets::Call::<Runtime, Instance>::destroy_approvals { id: asset_id },
+					false,
+				)
+			} else {
+				(pallet_assets::Call::<Runtime, Instance>::finish_destroy { id: asset_id }, true)
+			};
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin.clone()).into(),
+				call,
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+
+			if finished {
+				asset_count::note_destroyed::<Runtime, Instance>(handle, storage_id.clone())?;
+				destroy_token::remove::<Runtime, Instance>(handle, storage_id)?;
+				return Ok(true)
+			}
+		}
+
+		Ok(false)
+	}
+
+	#[cfg(feature = "freezing")]
+	#[precompile::public("block(uint64,address)")]
+	fn block(handle: &mut impl PrecompileHandle, id: u64, who: Address) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
+		let who: H160 = who.into();
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let who = Runtime::AddressMapping::into_account_id(who);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::block {
+					id: asset_id,
+					who: Runtime::Lookup::unlookup(who),
+				},
 				SYSTEM_ACCOUNT_SIZE,
 			)?;
 		}
@@ -290,27 +1610,1655 @@ where
 		Ok(())
 	}
 
-	#[precompile::public("finishDestroy(uint64)")]
-	fn finish_destroy(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
-		handle.record_log_costs_manual(3, 32)?;
+	/// Prevents `who` from transferring the asset, only callable by its freezer.
+	#[cfg(feature = "freezing")]
+	#[precompile::public("freeze(uint64,address)")]
+	fn freeze(handle: &mut impl PrecompileHandle, id: u64, who: Address) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
+		let who: H160 = who.into();
 
-		let asset_id = id
-			.try_into()
-			.map_err(|_| RevertReason::value_is_too_large("asset id").in_field("id"))?;
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let who = Runtime::AddressMapping::into_account_id(who);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::freeze {
+					id: asset_id,
+					who: Runtime::Lookup::unlookup(who),
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Allows a frozen `who` to transfer the asset again, only callable by its admin.
+	#[cfg(feature = "freezing")]
+	#[precompile::public("thaw(uint64,address)")]
+	fn thaw(handle: &mut impl PrecompileHandle, id: u64, who: Address) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
+		let who: H160 = who.into();
 
 		// Build call with origin.
 		{
 			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let who = Runtime::AddressMapping::into_account_id(who);
 
 			// Dispatch call (if enough gas).
-			RuntimeHelper::<Runtime>::try_dispatch(
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
 				handle,
 				Some(origin).into(),
-				pallet_assets::Call::<Runtime, Instance>::finish_destroy { id: asset_id },
+				pallet_assets::Call::<Runtime, Instance>::thaw {
+					id: asset_id,
+					who: Runtime::Lookup::unlookup(who),
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Prevents all non-admin transfers of the asset, only callable by its freezer.
+	#[cfg(feature = "freezing")]
+	#[precompile::public("freezeAsset(uint64)")]
+	fn freeze_asset(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::freeze_asset { id: asset_id },
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Allows non-admin transfers of a frozen asset again, only callable by its admin.
+	#[cfg(feature = "freezing")]
+	#[precompile::public("thawAsset(uint64)")]
+	fn thaw_asset(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		let asset_id = asset_id_of(id).in_field("id")?;
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::thaw_asset { id: asset_id },
 				SYSTEM_ACCOUNT_SIZE,
 			)?;
 		}
 
 		Ok(())
 	}
+
+	/// Pauses the asset or unpauses it, only callable by its owner. While paused, its mints,
+	/// burns and transfers through the precompiles revert with "asset paused", whoever makes them,
+	/// while the reads are unaffected. Unlike `freezeAsset`, it is kept by the precompiles rather
+	/// than by `pallet_assets`.
+	#[cfg(feature = "freezing")]
+	#[precompile::public("setAssetPaused(uint64,bool)")]
+	fn set_asset_paused(handle: &mut impl PrecompileHandle, id: u64, paused: bool) -> EvmResult {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		let details = assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone())
+			.ok_or_else(asset_not_found)?;
+		if details.owner != caller {
+			return Err(revert("caller is not the asset owner"))
+		}
+
+		// Storage item: AssetPaused
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		asset_pause::set::<Runtime, Instance>(asset_id, paused);
+
+		Ok(())
+	}
+
+	/// Returns whether the owner of the asset paused it.
+	#[precompile::public("assetPaused(uint64)")]
+	#[precompile::view]
+	fn asset_paused(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<bool> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		asset_pause::is_paused::<Runtime, Instance>(handle, asset_id)
+	}
+
+	/// Mints `amount` of the asset to `beneficiary`, logging a `Minted` like `batchMint` does for
+	/// each of its recipients.
+	#[cfg(feature = "issuance")]
+	#[precompile::public("mint(uint64,address,uint256)")]
+	fn mint(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		beneficiary: Address,
+		amount: U256,
+	) -> EvmResult {
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(handle.context().address).log3(
+				SELECTOR_LOG_MINTED,
+				H256::from_low_u64_be(id),
+				H160::from(beneficiary),
+				solidity::encode_event_data(amount),
+			),
+		)?;
+		Self::ensure_asset_exists(handle, id)?;
+		Self::ensure_not_paused(handle, id)?;
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let amount: BalanceOf<Runtime, Instance> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		if let Some(cap) = supply_cap::get::<Runtime, Instance>(handle, storage_id.clone())? {
+			// Storage item: Asset
+			handle
+				.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+			let supply = assets_storage::Asset::<Runtime, Instance>::get(storage_id)
+				.map_or_else(Zero::zero, |details| details.supply);
+			if supply.saturating_add(amount) > cap {
+				return Err(revert("supply cap exceeded"))
+			}
+		}
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let beneficiary = Runtime::AddressMapping::into_account_id(beneficiary.into());
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::mint {
+					id: asset_id,
+					beneficiary: Runtime::Lookup::unlookup(beneficiary),
+					amount,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		log.emit(handle)
+	}
+
+	/// Burns `amount` of the asset held by `who`, only callable by the admin of the asset.
+	/// Burning from a frozen asset or a frozen account is reported before dispatching it.
+	#[cfg(feature = "issuance")]
+	#[precompile::public("burn(uint64,address,uint256)")]
+	fn burn(handle: &mut impl PrecompileHandle, id: u64, who: Address, amount: U256) -> EvmResult {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+		// Storage item: Account
+		handle.record_db_read::<Runtime>(AssetAccountOf::<Runtime, Instance>::max_encoded_len())?;
+		Self::ensure_not_paused(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let amount: BalanceOf<Runtime, Instance> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let asset_status = assets_storage::Asset::<Runtime, Instance>::get(storage_id.clone())
+			.ok_or_else(asset_not_found)?
+			.status;
+		if asset_status == AssetStatus::Frozen {
+			return Err(revert("asset frozen"))
+		}
+		// Like `pallet_assets`, blocked accounts count as frozen.
+		let account_status = assets_storage::Account::<Runtime, Instance>::get(storage_id, &who)
+			.map(|account| account.status);
+		if matches!(account_status, Some(AccountStatus::Frozen | AccountStatus::Blocked)) {
+			return Err(revert("account frozen"))
+		}
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin).into(),
+				pallet_assets::Call::<Runtime, Instance>::burn {
+					id: asset_id,
+					who: Runtime::Lookup::unlookup(who),
+					amount,
+				},
+				0,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Mints `amounts[i]` to `recipients[i]` for every `i`, atomically. If the asset has a
+	/// supply cap, the total of the amounts is checked against it once, before any mint. An empty
+	/// batch succeeds without checking anything, whatever the asset. Unless the runtime opts out
+	/// through `EmitBatchEvents`, the `Minted` events are logged in the order of the inputs, which
+	/// indexers rely on.
+	#[cfg(feature = "issuance")]
+	#[precompile::public("batchMint(uint64,address[],uint256[])")]
+	fn batch_mint(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		recipients: BoundedVec<Address, GetArrayLimit>,
+		amounts: BoundedVec<U256, GetArrayLimit>,
+	) -> EvmResult {
+		let recipients: Vec<_> = recipients.into();
+		let amounts: Vec<_> = amounts.into();
+		if recipients.len() != amounts.len() {
+			return Err(RevertReason::custom("recipients and amounts lengths differ")
+				.in_field("amounts")
+				.into())
+		}
+		if recipients.is_empty() {
+			return Ok(())
+		}
+		Self::ensure_asset_exists(handle, id)?;
+		Self::ensure_not_paused(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let balances = amounts
+			.iter()
+			.enumerate()
+			.map(|(index, value)| {
+				BalanceOf::<Runtime, Instance>::try_from(*value).map_err(|_| {
+					RevertReason::value_is_too_large("balance type")
+						.in_array(index)
+						.in_field("amounts")
+						.into()
+				})
+			})
+			.collect::<EvmResult<Vec<_>>>()?;
+
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		if let Some(cap) = supply_cap::get::<Runtime, Instance>(handle, storage_id.clone())? {
+			// Storage item: Asset
+			handle
+				.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+			let supply = assets_storage::Asset::<Runtime, Instance>::get(storage_id)
+				.map_or_else(Zero::zero, |details| details.supply);
+			let total = balances.iter().fold(supply, |total, amount| total.saturating_add(*amount));
+			if total > cap {
+				return Err(revert("supply cap exceeded"))
+			}
+		}
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let emit_events = Runtime::EmitBatchEvents::get();
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = (|| -> EvmResult {
+				// Each item is logged right after it is dispatched, never grouped nor sorted, so
+				// that the logs follow the order of the inputs.
+				for (index, ((to, value), amount)) in
+					recipients.into_iter().zip(amounts).zip(balances).enumerate()
+				{
+					let to: H160 = to.into();
+					let log = if emit_events {
+						Some(PaidLog::new(
+							handle,
+							LogsBuilder::new(handle.context().address).log3(
+								SELECTOR_LOG_MINTED,
+								H256::from_low_u64_be(id),
+								to,
+								solidity::encode_event_data(value),
+							),
+						)?)
+					} else {
+						None
+					};
+
+					// Dispatch call (if enough gas).
+					RuntimeHelper::<Runtime>::try_dispatch_tagged(
+						handle,
+						Some(origin.clone()).into(),
+						pallet_assets::Call::<Runtime, Instance>::mint {
+							id: asset_id,
+							beneficiary: Runtime::Lookup::unlookup(
+								Runtime::AddressMapping::into_account_id(to),
+							),
+							amount,
+						},
+						SYSTEM_ACCOUNT_SIZE,
+					)
+					.map_err(|e| match e {
+						TryDispatchError::Substrate(e) => RevertReason::custom(alloc::format!(
+							"Dispatched call failed with error: {e:?}"
+						))
+						.in_array(index)
+						.in_field("recipients")
+						.into(),
+						e => e.into(),
+					})?;
+
+					if let Some(log) = log {
+						log.emit(handle)?;
+					}
+				}
+
+				Ok(())
+			})();
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Sets the share, in basis points, of the transfers of the asset sent to the treasury as fee,
+	/// only callable by the owner of the asset. A fee of zero removes it. The fee is taken on the
+	/// ERC20 `transfer` and `transferFrom`, and on `transferApproved`, `transferAndTouch` and
+	/// `batchTransfer`, but not on the force transfers.
+	#[cfg(feature = "transfers")]
+	#[precompile::public("setTransferFeeBps(uint64,uint16)")]
+	fn set_transfer_fee_bps(handle: &mut impl PrecompileHandle, id: u64, bps: u16) -> EvmResult {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		let details = assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone())
+			.ok_or_else(asset_not_found)?;
+		if details.owner != caller {
+			return Err(revert("caller is not the asset owner"))
+		}
+		if bps > Runtime::MaxTransferFeeBps::get() {
+			return Err(RevertReason::custom("transfer fee above maximum").in_field("bps").into())
+		}
+
+		// Storage item: TransferFee
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		transfer_fee::set::<Runtime, Instance>(
+			asset_id,
+			Some(transfer_fee::TransferFee { bps, treasury: Runtime::TransferFeeTreasury::get() })
+				.filter(|fee| fee.bps > 0),
+		);
+
+		Ok(())
+	}
+
+	/// Returns the transfer fee of the asset in basis points, zero if it has none.
+	#[precompile::public("transferFeeBps(uint64)")]
+	#[precompile::view]
+	fn transfer_fee_bps(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<u16> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		Ok(transfer_fee::get::<Runtime, Instance>(handle, asset_id)?.map_or(0, |fee| fee.bps))
+	}
+
+	/// Returns all the state the precompiles keep about an asset besides the one of
+	/// `pallet_assets`, to inspect it in one call. The asset does not have to exist, e.g. to
+	/// inspect the reservation of its id.
+	#[precompile::public("extendedConfig(uint64)")]
+	#[precompile::view]
+	fn extended_config(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<ExtendedConfig> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		let supply_cap = supply_cap::get::<Runtime, Instance>(handle, asset_id.clone())?;
+		let transfer_fee = transfer_fee::get::<Runtime, Instance>(handle, asset_id.clone())?;
+		let reservation = id_reservation::get::<Runtime, Instance>(handle, asset_id.clone())?;
+		let destroy_token_issued_at =
+			destroy_token::issued_at::<Runtime, Instance>(handle, asset_id.clone())?;
+		let destroy_started_at =
+			destroy_token::started_at::<Runtime, Instance>(handle, asset_id.clone())?;
+		let paused = asset_pause::is_paused::<Runtime, Instance>(handle, asset_id)?;
+
+		let (reserved_by, reserved_at) = reservation
+			.map(|(holder, reserved_at)| (H256(holder.into()), reserved_at.saturated_into()))
+			.unwrap_or_default();
+
+		Ok(ExtendedConfig {
+			supply_cap: supply_cap.map_or_else(U256::zero, Into::into),
+			transfer_fee_bps: transfer_fee.map_or(0, |fee| fee.bps),
+			transfer_fee_treasury: Address(
+				transfer_fee.map_or_else(H160::zero, |fee| fee.treasury),
+			),
+			reserved_by,
+			reserved_at,
+			destroy_token_issued_at: destroy_token_issued_at
+				.map_or(0, |block| block.saturated_into()),
+			destroy_started_at: destroy_started_at.map_or(0, |block| block.saturated_into()),
+			paused,
+		})
+	}
+
+	/// Caps the supply of the asset the factory mints can reach, only callable by the owner of
+	/// the asset. A cap of zero removes it, any other cap cannot be below the current supply.
+	#[cfg(feature = "issuance")]
+	#[precompile::public("setSupplyCap(uint64,uint256)")]
+	fn set_supply_cap(handle: &mut impl PrecompileHandle, id: u64, cap: U256) -> EvmResult {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let cap: BalanceOf<Runtime, Instance> = cap
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("cap"))?;
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		let details = assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone())
+			.ok_or_else(asset_not_found)?;
+		if details.owner != caller {
+			return Err(revert("caller is not the asset owner"))
+		}
+		if !cap.is_zero() && cap < details.supply {
+			return Err(revert("cap below current supply"))
+		}
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(handle.context().address).log2(
+				SELECTOR_LOG_SUPPLY_CAP_CHANGED,
+				H256::from_low_u64_be(id),
+				solidity::encode_event_data::<U256>(cap.into()),
+			),
+		)?;
+
+		// Storage item: SupplyCap
+		handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+		supply_cap::set::<Runtime, Instance>(asset_id, Some(cap).filter(|cap| !cap.is_zero()));
+
+		log.emit(handle)
+	}
+
+	/// Returns the supply cap of the asset, zero if it is not capped.
+	#[precompile::public("supplyCap(uint64)")]
+	#[precompile::view]
+	fn supply_cap(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<U256> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		Ok(supply_cap::get::<Runtime, Instance>(handle, asset_id)?
+			.map_or_else(U256::zero, Into::into))
+	}
+
+	/// Returns the numbers of accounts, sufficient accounts and approvals of an asset, which are
+	/// the items destroying it has to remove.
+	#[precompile::public("accountCounts(uint64)")]
+	#[precompile::view]
+	fn account_counts(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<AccountCounts> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		let details = assets_storage::Asset::<Runtime, Instance>::get(asset_id)
+			.ok_or_else(asset_not_found)?;
+
+		Ok(AccountCounts {
+			accounts: details.accounts,
+			sufficients: details.sufficients,
+			approvals: details.approvals,
+		})
+	}
+
+	/// Returns the name of an asset, empty if it has no metadata.
+	#[precompile::public("name(uint64)")]
+	#[precompile::view]
+	fn name(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<UnboundedBytes> {
+		Ok(Self::metadata(handle, id)?.name.into_inner().into())
+	}
+
+	/// Returns the symbol of an asset, empty if it has no metadata.
+	#[precompile::public("symbol(uint64)")]
+	#[precompile::view]
+	fn symbol(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<UnboundedBytes> {
+		Ok(Self::metadata(handle, id)?.symbol.into_inner().into())
+	}
+
+	/// Returns the decimals of an asset, zero if it has no metadata.
+	#[precompile::public("decimals(uint64)")]
+	#[precompile::view]
+	fn decimals(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<u8> {
+		Ok(Self::metadata(handle, id)?.decimals)
+	}
+
+	/// Metadata of an asset, the default one if the asset has none, as `pallet_assets` does not
+	/// require an asset to have metadata.
+	fn metadata(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+	) -> EvmResult<AssetMetadataOf<Runtime, Instance>> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		// Storage item: Metadata
+		handle.record_db_read::<Runtime>(AssetMetadataOf::<Runtime, Instance>::max_encoded_len())?;
+
+		Ok(assets_storage::Metadata::<Runtime, Instance>::get(asset_id))
+	}
+
+	/// Returns the details and metadata of each of the given assets, with `exists` set to false
+	/// for the ones which do not exist instead of reverting.
+	#[precompile::public("tokenInfoBatch(uint64[])")]
+	#[precompile::view]
+	fn token_info_batch(
+		handle: &mut impl PrecompileHandle,
+		ids: BoundedVec<u64, GetArrayLimit>,
+	) -> EvmResult<Vec<TokenInfo>> {
+		let ids: Vec<u64> = ids.into();
+		let mut infos = Vec::with_capacity(ids.len());
+
+		for id in ids {
+			// Storage item: Asset
+			handle
+				.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+			// Storage item: Metadata
+			handle.record_db_read::<Runtime>(
+				AssetMetadataOf::<Runtime, Instance>::max_encoded_len(),
+			)?;
+
+			// An id too large for the asset id type cannot be the one of an existing asset.
+			let details = AssetIdOf::<Runtime, Instance>::try_from(id).ok().and_then(|asset_id| {
+				assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone())
+					.map(|details| (asset_id, details))
+			});
+			let (asset_id, details) = match details {
+				Some(details) => details,
+				None => {
+					infos.push(TokenInfo::default());
+					continue
+				},
+			};
+
+			let metadata = assets_storage::Metadata::<Runtime, Instance>::get(asset_id);
+			let status = match details.status {
+				AssetStatus::Live => ASSET_STATUS_LIVE,
+				AssetStatus::Frozen => ASSET_STATUS_FROZEN,
+				AssetStatus::Destroying => ASSET_STATUS_DESTROYING,
+			};
+
+			infos.push(TokenInfo {
+				exists: true,
+				name: metadata.name.into_inner().into(),
+				symbol: metadata.symbol.into_inner().into(),
+				decimals: metadata.decimals,
+				total_supply: details.supply.into(),
+				min_balance: details.min_balance.into(),
+				status,
+			});
+		}
+
+		Ok(infos)
+	}
+
+	/// Returns the last failure of the atomic methods (`batchMint`, `batchTransfer`,
+	/// `createFromTemplate`, `createWithMetadata`, `createWithTeam`, `safeLaunch`,
+	/// `setMinBalanceBatch`, `transferAndTouch` and `transferOwnershipAndSetTeam`) in the current
+	/// transaction.
+	/// The reverts of these methods are kept until the end of the transaction, so a contract
+	/// catching one can read its underlying reason.
+	#[precompile::public("lastError()")]
+	#[precompile::view]
+	fn last_error(_handle: &mut impl PrecompileHandle) -> EvmResult<LastError> {
+		Ok(match last_error::get::<Runtime, Instance>() {
+			Some((code, message)) => LastError { code, message: message.into() },
+			None => LastError { code: LAST_ERROR_NONE, message: UnboundedBytes::default() },
+		})
+	}
+
+	/// Returns how many times each mutating method was called in the current block. The counts
+	/// start over every block.
+	#[precompile::public("callStats()")]
+	#[precompile::view]
+	fn call_stats(handle: &mut impl PrecompileHandle) -> EvmResult<CallStats> {
+		let (total, counts) = call_stats::get::<Runtime, Instance>(handle)?;
+
+		Ok(CallStats {
+			block: frame_system::Pallet::<Runtime>::block_number().saturated_into(),
+			total,
+			methods: counts
+				.into_iter()
+				.map(|(selector, calls)| MethodCalls { selector, calls })
+				.collect(),
+		})
+	}
+
+	/// Returns the selectors of the methods of the factory, sorted, so that proxies and routers
+	/// can find out which ones it supports. They depend on the features it is compiled with.
+	#[precompile::public("supportedSelectors()")]
+	#[precompile::view]
+	fn supported_selectors(_handle: &mut impl PrecompileHandle) -> EvmResult<Vec<Bytes4>> {
+		Ok(FactoryCall::<Runtime, Instance>::selectors()
+			.iter()
+			.map(|&selector| Bytes4::from(selector))
+			.collect())
+	}
+
+	/// Returns whether the runtime is configured soundly for the factory, which is whether:
+	/// - the default metadata, if any, fits the string limit of the pallet, or every `create` would
+	///   revert;
+	/// - the minimum balance floor, if any, fits the balance type, or no minimum balance would
+	///   reach it;
+	/// - the maximum transfer fee is at most the whole of the amount.
+	#[precompile::public("selfCheck()")]
+	#[precompile::view]
+	fn self_check(_handle: &mut impl PrecompileHandle) -> EvmResult<bool> {
+		let limit = <Runtime as pallet_assets::Config<Instance>>::StringLimit::get() as usize;
+		let default_metadata_fits = <Runtime as AssetsFactoryConfig>::DefaultMetadata::get()
+			.map_or(true, |(name, symbol)| name.len() <= limit && symbol.len() <= limit);
+		let min_balance_floor_fits = <Runtime as AssetsFactoryConfig>::MinBalanceFloor::get()
+			.map_or(true, |floor| min_balance_of::<BalanceOf<Runtime, Instance>>(floor).is_ok());
+		let max_transfer_fee_fits = <Runtime as AssetsFactoryConfig>::MaxTransferFeeBps::get() <=
+			transfer_fee::BPS_DENOMINATOR;
+
+		Ok(default_metadata_fits && min_balance_floor_fits && max_transfer_fee_fits)
+	}
+
+	/// Returns the address owning the asset, along with its raw account. The address is zero
+	/// where the account cannot be mapped back to an address, which the address mapping of the
+	/// runtime does not allow for every account; the raw account is then the only way to tell
+	/// the owner.
+	#[precompile::public("ownerOf(uint64)")]
+	#[precompile::view]
+	fn owner_of(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<(Address, H256)> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+		// Storage item: the address the account maps back to
+		handle.record_db_read::<Runtime>(H160::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let details = assets_storage::Asset::<Runtime, Instance>::get(asset_id)
+			.ok_or_else(asset_not_found)?;
+		let address =
+			<Runtime as AssetsFactoryConfig>::AccountToAddress::convert(details.owner.clone())
+				.unwrap_or_default();
+
+		Ok((Address(address), H256(details.owner.into())))
+	}
+
+	/// Returns the `PERMISSION_*` bits of the operations `caller` is allowed to perform on an
+	/// asset, derived from its team, its status, whether it is paused and the account of
+	/// `caller`. The balances needed to pay the deposits are not checked.
+	#[precompile::public("permissions(uint64,address)")]
+	#[precompile::view]
+	fn permissions(handle: &mut impl PrecompileHandle, id: u64, caller: Address) -> EvmResult<u8> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+		// Storage item: Account
+		handle.record_db_read::<Runtime>(AssetAccountOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let caller = Runtime::AddressMapping::into_account_id(caller.into());
+
+		let details = match assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone()) {
+			Some(details) => details,
+			None if asset_id.is_allow_to_create() => return Ok(PERMISSION_CREATE),
+			None => return Ok(0),
+		};
+
+		let live = details.status == AssetStatus::Live;
+		// Pausing an asset blocks the moves of its balances only.
+		let movable =
+			live && !asset_pause::is_paused::<Runtime, Instance>(handle, asset_id.clone())?;
+		let mut permissions = 0;
+		if movable && details.issuer == caller {
+			permissions |= PERMISSION_MINT;
+		}
+		if movable && details.admin == caller {
+			permissions |= PERMISSION_BURN;
+		}
+		if live && details.owner == caller {
+			permissions |= PERMISSION_SET_METADATA;
+		}
+		if details.status != AssetStatus::Destroying && details.owner == caller {
+			permissions |= PERMISSION_DESTROY;
+		}
+		let holds_liquid_balance =
+			assets_storage::Account::<Runtime, Instance>::get(asset_id, caller)
+				.map_or(false, |account| {
+					account.status == AccountStatus::Liquid && !account.balance.is_zero()
+				});
+		if movable && holds_liquid_balance {
+			permissions |= PERMISSION_TRANSFER;
+		}
+
+		Ok(permissions)
+	}
+
+	#[precompile::public("isBlocked(uint64,address)")]
+	#[precompile::view]
+	fn is_blocked(handle: &mut impl PrecompileHandle, id: u64, who: Address) -> EvmResult<bool> {
+		// Storage item: Account
+		handle.record_db_read::<Runtime>(AssetAccountOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let who = Runtime::AddressMapping::into_account_id(who.into());
+
+		// A nonexistent account cannot be blocked.
+		let is_blocked = assets_storage::Account::<Runtime, Instance>::get(asset_id, who)
+			.map_or(false, |account| account.status == AccountStatus::Blocked);
+
+		Ok(is_blocked)
+	}
+
+	#[precompile::public("reservedDepositOf(uint64,address)")]
+	#[precompile::view]
+	fn reserved_deposit_of(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		account: Address,
+	) -> EvmResult<U256> {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+		// Storage item: Metadata
+		handle.record_db_read::<Runtime>(AssetMetadataOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let account = Runtime::AddressMapping::into_account_id(account.into());
+
+		// Both deposits are held by the owner, and move with the ownership of the asset.
+		let deposit = match assets_storage::Asset::<Runtime, Instance>::get(asset_id.clone()) {
+			Some(details) if details.owner == account => details.deposit.saturating_add(
+				assets_storage::Metadata::<Runtime, Instance>::get(asset_id).deposit,
+			),
+			_ => Zero::zero(),
+		};
+
+		Ok(deposit.saturated_into::<u128>().into())
+	}
+
+	/// Returns the total deposit reserved from `owner` for its approvals of the asset, each
+	/// delegate holding one until its approval is cancelled or used up.
+	#[precompile::public("approvalDepositOf(uint64,address)")]
+	#[precompile::view]
+	fn approval_deposit_of(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		owner: Address,
+	) -> EvmResult<U256> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+
+		let mut deposit = DepositBalanceOf::<Runtime, Instance>::zero();
+		for approval in
+			assets_storage::Approvals::<Runtime, Instance>::iter_prefix_values((asset_id, owner))
+		{
+			// Storage item: Approvals
+			handle.record_db_read::<Runtime>(ApprovalOf::<Runtime, Instance>::max_encoded_len())?;
+			deposit = deposit.saturating_add(approval.deposit);
+		}
+
+		Ok(deposit.saturated_into::<u128>().into())
+	}
+
+	/// Transfers `amount` to `to`, first touching its account at the caller's expense if it does
+	/// not exist yet. Touching another account requires the caller to be the asset's admin or
+	/// freezer. Like in the ERC20 `transfer`, the transfer fee of the asset, if any, is transferred
+	/// to its treasury out of `amount`. All the steps are applied atomically.
+	#[cfg(feature = "transfers")]
+	#[precompile::public("transferAndTouch(uint64,address,uint256)")]
+	fn transfer_and_touch(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		to: Address,
+		amount: U256,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		Self::ensure_not_paused(handle, id)?;
+		// Storage item: Account
+		handle.record_db_read::<Runtime>(AssetAccountOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let amount: BalanceOf<Runtime, Instance> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+
+		let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let to = Runtime::AddressMapping::into_account_id(to.into());
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let needs_touch =
+			!assets_storage::Account::<Runtime, Instance>::contains_key(storage_id.clone(), &to);
+		let (amount, fee) = transfer_fee::split_amount(
+			transfer_fee::get::<Runtime, Instance>(handle, storage_id)?,
+			amount,
+		);
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = (|| -> EvmResult {
+				if needs_touch {
+					// Dispatch call (if enough gas).
+					RuntimeHelper::<Runtime>::try_dispatch_tagged(
+						handle,
+						Some(origin.clone()).into(),
+						pallet_assets::Call::<Runtime, Instance>::touch_other {
+							id: asset_id,
+							who: Runtime::Lookup::unlookup(to.clone()),
+						},
+						SYSTEM_ACCOUNT_SIZE,
+					)?;
+				}
+
+				// Dispatch call (if enough gas).
+				RuntimeHelper::<Runtime>::try_dispatch_tagged(
+					handle,
+					Some(origin.clone()).into(),
+					pallet_assets::Call::<Runtime, Instance>::transfer {
+						id: asset_id,
+						target: Runtime::Lookup::unlookup(to),
+						amount,
+					},
+					SYSTEM_ACCOUNT_SIZE,
+				)?;
+
+				if let Some((treasury, fee)) = fee {
+					// Dispatch call (if enough gas).
+					RuntimeHelper::<Runtime>::try_dispatch_tagged(
+						handle,
+						Some(origin).into(),
+						pallet_assets::Call::<Runtime, Instance>::transfer {
+							id: asset_id,
+							target: Runtime::Lookup::unlookup(
+								Runtime::AddressMapping::into_account_id(treasury),
+							),
+							amount: fee,
+						},
+						SYSTEM_ACCOUNT_SIZE,
+					)?;
+				}
+
+				Ok(())
+			})();
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Transfers `amounts[i]` to `recipients[i]` for every index, logging an ERC20 `Transfer` at
+	/// the address of the asset for each of them unless the runtime disables it by default. The
+	/// transfers are applied atomically, and a failing one reverts the whole batch with its index.
+	/// An empty batch succeeds without checking anything, whatever the asset. The `Transfer`
+	/// events are logged in the order of the inputs, which indexers rely on. Like in the ERC20
+	/// `transfer`, the transfer fee of the asset, if any, is transferred to its treasury out of
+	/// each amount, and logged as a `Transfer` right after the one of the amount.
+	#[cfg(feature = "transfers")]
+	#[precompile::public("batchTransfer(uint64,address[],uint256[])")]
+	fn batch_transfer(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		recipients: BoundedVec<Address, GetArrayLimit>,
+		amounts: BoundedVec<U256, GetArrayLimit>,
+	) -> EvmResult {
+		Self::do_batch_transfer(handle, id, recipients, amounts, Runtime::EmitBatchEvents::get())
+	}
+
+	/// Like `batchTransfer`, but the caller chooses whether the `Transfer` events are logged.
+	/// Skipping them saves their gas, at the expense of indexers not seeing the transfers.
+	#[cfg(feature = "transfers")]
+	#[precompile::public("batchTransfer(uint64,address[],uint256[],bool)")]
+	fn batch_transfer_with_events(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		recipients: BoundedVec<Address, GetArrayLimit>,
+		amounts: BoundedVec<U256, GetArrayLimit>,
+		emit_events: bool,
+	) -> EvmResult {
+		Self::do_batch_transfer(handle, id, recipients, amounts, emit_events)
+	}
+
+	#[cfg(feature = "transfers")]
+	fn do_batch_transfer(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		recipients: BoundedVec<Address, GetArrayLimit>,
+		amounts: BoundedVec<U256, GetArrayLimit>,
+		emit_events: bool,
+	) -> EvmResult {
+		let recipients: Vec<_> = recipients.into();
+		let amounts: Vec<_> = amounts.into();
+		if recipients.len() != amounts.len() {
+			return Err(RevertReason::custom("recipients and amounts lengths differ")
+				.in_field("amounts")
+				.into())
+		}
+		if recipients.is_empty() {
+			return Ok(())
+		}
+		Self::ensure_asset_exists(handle, id)?;
+		Self::ensure_not_paused(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let asset_address = Self::asset_address(handle, storage_id.clone())?;
+		let transfer_fee = transfer_fee::get::<Runtime, Instance>(handle, storage_id)?;
+		let caller = handle.context().caller;
+		let origin = Runtime::AddressMapping::into_account_id(caller);
+
+		frame_support::storage::with_transaction_unchecked(|| {
+			let result = (|| -> EvmResult {
+				// Like in `batchMint`, the logs follow the order of the inputs.
+				for (index, (to, value)) in recipients.into_iter().zip(amounts).enumerate() {
+					let to: H160 = to.into();
+					let amount: BalanceOf<Runtime, Instance> = value.try_into().map_err(|_| {
+						RevertReason::value_is_too_large("balance type")
+							.in_array(index)
+							.in_field("amounts")
+					})?;
+					let (amount, fee) = transfer_fee::split_amount(transfer_fee, amount);
+
+					for (target, amount) in sp_std::iter::once((to, amount)).chain(fee) {
+						let log = if emit_events {
+							Some(PaidLog::new(
+								handle,
+								LogsBuilder::new(asset_address).log3(
+									SELECTOR_LOG_TRANSFER,
+									caller,
+									target,
+									solidity::encode_event_data(amount),
+								),
+							)?)
+						} else {
+							None
+						};
+
+						// Dispatch call (if enough gas).
+						RuntimeHelper::<Runtime>::try_dispatch_tagged(
+							handle,
+							Some(origin.clone()).into(),
+							pallet_assets::Call::<Runtime, Instance>::transfer {
+								id: asset_id,
+								target: Runtime::Lookup::unlookup(
+									Runtime::AddressMapping::into_account_id(target),
+								),
+								amount,
+							},
+							SYSTEM_ACCOUNT_SIZE,
+						)
+						.map_err(|e| match e {
+							TryDispatchError::Substrate(e) => RevertReason::custom(alloc::format!(
+								"Dispatched call failed with error: {e:?}"
+							))
+							.in_array(index)
+							.in_field("recipients")
+							.into(),
+							e => e.into(),
+						})?;
+
+						if let Some(log) = log {
+							log.emit(handle)?;
+						}
+					}
+				}
+
+				Ok(())
+			})();
+
+			last_error::outcome::<Runtime, Instance, _>(result)
+		})
+	}
+
+	/// Approves `spender` to transfer up to `amount` of the caller's balance of the asset until the
+	/// block `deadlineBlock` included. After it, `transferApproved` and the ERC20 `transferFrom` of
+	/// the asset revert. Like the ERC20 `approve`, it replaces the previous approval of `spender`.
+	#[cfg(feature = "approvals")]
+	#[precompile::public("approveWithDeadline(uint64,address,uint256,uint64)")]
+	fn approve_with_deadline(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		spender: Address,
+		amount: U256,
+		deadline_block: u64,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let balance: BalanceOf<Runtime, Instance> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+		if deadline_block < allowance_deadline::current_block::<Runtime>() {
+			return Err(RevertReason::custom("deadline already passed")
+				.in_field("deadlineBlock")
+				.into())
+		}
+
+		let caller = handle.context().caller;
+
+		Self::replace_approval(
+			handle,
+			asset_id,
+			caller,
+			spender.into(),
+			balance,
+			amount,
+			Some(deadline_block),
+		)
+	}
+
+	/// Sets the approval of `owner` to `spender`, cancelling its previous one, and the deadline
+	/// of the new one.
+	#[cfg(feature = "approvals")]
+	fn replace_approval(
+		handle: &mut impl PrecompileHandle,
+		asset_id: AssetIdParameterOf<Runtime, Instance>,
+		owner: H160,
+		spender: H160,
+		balance: BalanceOf<Runtime, Instance>,
+		amount: U256,
+		deadline_block: Option<u64>,
+	) -> EvmResult {
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let asset_address = Self::asset_address(handle, storage_id.clone())?;
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(asset_address).log3(
+				SELECTOR_LOG_APPROVAL,
+				owner,
+				spender,
+				solidity::encode_event_data(amount),
+			),
+		)?;
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(owner);
+			let spender = Runtime::AddressMapping::into_account_id(spender);
+
+			// Storage item: Approvals
+			handle.record_db_read::<Runtime>(ApprovalOf::<Runtime, Instance>::max_encoded_len())?;
+
+			// The pallet adds to an existing approval, which has to be cancelled to be replaced.
+			if assets_storage::Approvals::<Runtime, Instance>::contains_key((
+				storage_id.clone(),
+				origin.clone(),
+				spender.clone(),
+			)) {
+				// Dispatch call (if enough gas).
+				RuntimeHelper::<Runtime>::try_dispatch_tagged(
+					handle,
+					Some(origin.clone()).into(),
+					pallet_assets::Call::<Runtime, Instance>::cancel_approval {
+						id: asset_id,
+						delegate: Runtime::Lookup::unlookup(spender.clone()),
+					},
+					SYSTEM_ACCOUNT_SIZE,
+				)?;
+			}
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin.clone()).into(),
+				pallet_assets::Call::<Runtime, Instance>::approve_transfer {
+					id: asset_id,
+					delegate: Runtime::Lookup::unlookup(spender.clone()),
+					amount: balance,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+
+			// Storage item: AllowanceDeadline
+			handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+			allowance_deadline::set::<Runtime, Instance>(
+				storage_id.clone(),
+				origin,
+				spender,
+				deadline_block,
+			);
+		}
+
+		log.emit(handle)
+	}
+
+	/// Approves `spender` to transfer up to `value` of the balance of `owner`, who signed the
+	/// approval off-chain as an EIP-2612 permit with the nonce `nonces` returns, before the
+	/// timestamp `deadline`, in seconds. Anyone can submit the permit, which can only be used
+	/// once. Like the ERC20 `approve`, it replaces the previous approval of `spender`, along with
+	/// its deadline.
+	#[cfg(feature = "approvals")]
+	#[precompile::public("permit(uint64,address,address,uint256,uint64,uint8,bytes32,bytes32)")]
+	#[allow(clippy::too_many_arguments)]
+	fn permit(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		owner: Address,
+		spender: Address,
+		value: U256,
+		deadline: u64,
+		v: u8,
+		r: H256,
+		s: H256,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let balance: BalanceOf<Runtime, Instance> = value
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("value"))?;
+		let owner: H160 = owner.into();
+		let spender: H160 = spender.into();
+
+		permit::verify::<Runtime, Instance>(
+			handle,
+			asset_id.into(),
+			owner,
+			spender,
+			value,
+			deadline,
+			v,
+			r,
+			s,
+		)?;
+
+		Self::replace_approval(handle, asset_id, owner, spender, balance, value, None)
+	}
+
+	/// Returns the nonce the next permit of `owner` has to be signed with.
+	#[precompile::public("nonces(uint64,address)")]
+	#[precompile::view]
+	fn nonces(handle: &mut impl PrecompileHandle, id: u64, owner: Address) -> EvmResult<U256> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		permit::nonce::<Runtime, Instance>(handle, asset_id, owner.into())
+	}
+
+	/// Returns the EIP-712 domain separator of the permits of an asset, named after the asset and
+	/// verified by its address.
+	#[precompile::public("domainSeparator(uint64)")]
+	#[precompile::view]
+	fn domain_separator(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult<H256> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		permit::domain_separator::<Runtime, Instance>(handle, asset_id)
+	}
+
+	/// Transfers `amount` of the balance of `owner` approved to the caller to `destination`,
+	/// logging an ERC20 `Transfer` at the address of the asset. Like in the ERC20 `transferFrom`,
+	/// the transfer fee of the asset, if any, is transferred to its treasury out of `amount` and
+	/// logged as a second `Transfer`. Reverts once the deadline of the approval, if any, has
+	/// passed, with "no approval" if there is none, and with "allowance overflow" if `amount` is
+	/// above it.
+	#[cfg(feature = "approvals")]
+	#[precompile::public("transferApproved(uint64,address,address,uint256)")]
+	fn transfer_approved(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		owner: Address,
+		destination: Address,
+		amount: U256,
+	) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+		Self::ensure_not_paused(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let balance: BalanceOf<Runtime, Instance> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let owner: H160 = owner.into();
+		let destination: H160 = destination.into();
+		let (balance, fee) = transfer_fee::split_amount(
+			transfer_fee::get::<Runtime, Instance>(handle, storage_id.clone())?,
+			balance,
+		);
+		let asset_address = Self::asset_address(handle, storage_id.clone())?;
+		let logs = sp_std::iter::once((destination, balance))
+			.chain(fee)
+			.map(|(target, amount)| {
+				PaidLog::new(
+					handle,
+					LogsBuilder::new(asset_address).log3(
+						SELECTOR_LOG_TRANSFER,
+						owner,
+						target,
+						solidity::encode_event_data(amount),
+					),
+				)
+			})
+			.collect::<EvmResult<Vec<_>>>()?;
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(handle.context().caller);
+			let owner = Runtime::AddressMapping::into_account_id(owner);
+
+			allowance_deadline::ensure_not_expired::<Runtime, Instance>(
+				handle,
+				storage_id.clone(),
+				owner.clone(),
+				origin.clone(),
+			)?;
+
+			let dispatched = frame_support::storage::with_transaction_unchecked(|| {
+				let result = (|| -> Result<(), TryDispatchError> {
+					for (target, amount) in sp_std::iter::once((destination, balance)).chain(fee) {
+						// Dispatch call (if enough gas).
+						RuntimeHelper::<Runtime>::try_dispatch_tagged(
+							handle,
+							Some(origin.clone()).into(),
+							pallet_assets::Call::<Runtime, Instance>::transfer_approved {
+								id: asset_id,
+								owner: Runtime::Lookup::unlookup(owner.clone()),
+								destination: Runtime::Lookup::unlookup(
+									Runtime::AddressMapping::into_account_id(target),
+								),
+								amount,
+							},
+							SYSTEM_ACCOUNT_SIZE,
+						)?;
+					}
+
+					Ok(())
+				})();
+
+				if result.is_ok() {
+					TransactionOutcome::Commit(result)
+				} else {
+					TransactionOutcome::Rollback(result)
+				}
+			});
+
+			// The pallet reports a missing approval and an amount above the approval alike.
+			let unapproved: DispatchError =
+				pallet_assets::Error::<Runtime, Instance>::Unapproved.into();
+			match dispatched {
+				Err(TryDispatchError::Substrate(error)) if error == unapproved => {
+					// Storage item: Approvals
+					handle.record_db_read::<Runtime>(
+						ApprovalOf::<Runtime, Instance>::max_encoded_len(),
+					)?;
+
+					let approved = assets_storage::Approvals::<Runtime, Instance>::contains_key((
+						storage_id.clone(),
+						owner,
+						origin,
+					));
+					return Err(revert(if approved { "allowance overflow" } else { "no approval" }))
+				},
+				dispatched => {
+					dispatched?;
+				},
+			}
+		}
+
+		logs.into_iter().try_for_each(|log| log.emit(handle))
+	}
+
+	/// Cancels the approval of the caller to `spender` along with its deadline, releasing its
+	/// deposit. Expired approvals are not removed by the pallet, and are cleaned up this way.
+	#[cfg(feature = "approvals")]
+	#[precompile::public("cancelApproval(uint64,address)")]
+	fn cancel_approval(handle: &mut impl PrecompileHandle, id: u64, spender: Address) -> EvmResult {
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+		let caller = handle.context().caller;
+		let spender: H160 = spender.into();
+		let asset_address = Self::asset_address(handle, storage_id.clone())?;
+		let log = PaidLog::new(
+			handle,
+			LogsBuilder::new(asset_address).log3(
+				SELECTOR_LOG_APPROVAL,
+				caller,
+				spender,
+				solidity::encode_event_data(U256::zero()),
+			),
+		)?;
+
+		// Build call with origin.
+		{
+			let origin = Runtime::AddressMapping::into_account_id(caller);
+			let spender = Runtime::AddressMapping::into_account_id(spender);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				Some(origin.clone()).into(),
+				pallet_assets::Call::<Runtime, Instance>::cancel_approval {
+					id: asset_id,
+					delegate: Runtime::Lookup::unlookup(spender.clone()),
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+
+			// Storage item: AllowanceDeadline
+			handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+			allowance_deadline::set::<Runtime, Instance>(storage_id.clone(), origin, spender, None);
+		}
+
+		log.emit(handle)
+	}
+
+	/// Returns the last block at which `spender` can transfer the balance of `owner` approved to
+	/// it, zero if the approval has no deadline.
+	#[precompile::public("allowanceDeadline(uint64,address,address)")]
+	#[precompile::view]
+	fn allowance_deadline(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		owner: Address,
+		spender: Address,
+	) -> EvmResult<u64> {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let owner = Runtime::AddressMapping::into_account_id(owner.into());
+		let spender = Runtime::AddressMapping::into_account_id(spender.into());
+
+		Ok(allowance_deadline::get::<Runtime, Instance>(handle, asset_id, owner, spender)?
+			.unwrap_or(0))
+	}
+
+	#[cfg(feature = "force")]
+	#[precompile::public("forceCreate(uint64,address,bool,uint128)")]
+	fn force_create(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		owner: Address,
+		is_sufficient: bool,
+		min_balance: u128,
+	) -> EvmResult {
+		let origin = Self::ensure_force_origin(handle)?;
+
+		let owner: H160 = owner.into();
+		let asset_id = asset_id_of::<AssetIdParameterOf<Runtime, Instance>>(id).in_field("id")?;
+
+		if !asset_id.is_allow_to_create() {
+			return Err(RevertReason::Custom("Invalid asset id".into()).into())
+		}
+
+		let min_balance: BalanceOf<Runtime, Instance> = min_balance_of(min_balance)?;
+		let storage_id: AssetIdOf<Runtime, Instance> = asset_id.into();
+
+		// Build call with origin.
+		{
+			let owner = Runtime::AddressMapping::into_account_id(owner);
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				origin,
+				pallet_assets::Call::<Runtime, Instance>::force_create {
+					id: asset_id,
+					owner: Runtime::Lookup::unlookup(owner),
+					is_sufficient,
+					min_balance,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		asset_count::note_created::<Runtime, Instance>(handle, storage_id)
+	}
+
+	#[cfg(feature = "force")]
+	#[precompile::public("forceSetMetadata(uint64,bytes,bytes,uint8,bool)")]
+	fn force_set_metadata(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		name: BoundedBytes<GetBytesLimit>,
+		symbol: BoundedBytes<GetBytesLimit>,
+		decimals: u8,
+		is_frozen: bool,
+	) -> EvmResult {
+		let origin = Self::ensure_force_origin(handle)?;
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of(id).in_field("id")?;
+		let name: Vec<_> = name.into();
+		let symbol: Vec<_> = symbol.into();
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch_tagged(
+			handle,
+			origin,
+			pallet_assets::Call::<Runtime, Instance>::force_set_metadata {
+				id: asset_id,
+				name,
+				symbol,
+				decimals,
+				is_frozen,
+			},
+			SYSTEM_ACCOUNT_SIZE,
+		)?;
+
+		Ok(())
+	}
+
+	#[cfg(feature = "force")]
+	#[precompile::public("forceClearMetadata(uint64)")]
+	fn force_clear_metadata(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		let origin = Self::ensure_force_origin(handle)?;
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of(id).in_field("id")?;
+
+		// Dispatch call (if enough gas).
+		RuntimeHelper::<Runtime>::try_dispatch_tagged(
+			handle,
+			origin,
+			pallet_assets::Call::<Runtime, Instance>::force_clear_metadata { id: asset_id },
+			SYSTEM_ACCOUNT_SIZE,
+		)?;
+
+		Ok(())
+	}
+
+	#[cfg(feature = "force")]
+	#[precompile::public(
+		"forceAssetStatus(uint64,address,address,address,address,uint128,bool,bool)"
+	)]
+	fn force_asset_status(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		owner: Address,
+		issuer: Address,
+		admin: Address,
+		freezer: Address,
+		min_balance: u128,
+		is_sufficient: bool,
+		is_frozen: bool,
+	) -> EvmResult {
+		let origin = Self::ensure_force_origin(handle)?;
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of(id).in_field("id")?;
+		let min_balance: BalanceOf<Runtime, Instance> = min_balance_of(min_balance)?;
+
+		// Build call with origin.
+		{
+			let owner = Runtime::AddressMapping::into_account_id(owner.into());
+			let issuer = Runtime::AddressMapping::into_account_id(issuer.into());
+			let admin = Runtime::AddressMapping::into_account_id(admin.into());
+			let freezer = Runtime::AddressMapping::into_account_id(freezer.into());
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				origin,
+				pallet_assets::Call::<Runtime, Instance>::force_asset_status {
+					id: asset_id,
+					owner: Runtime::Lookup::unlookup(owner),
+					issuer: Runtime::Lookup::unlookup(issuer),
+					admin: Runtime::Lookup::unlookup(admin),
+					freezer: Runtime::Lookup::unlookup(freezer),
+					min_balance,
+					is_sufficient,
+					is_frozen,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	#[cfg(feature = "force")]
+	#[precompile::public("forceTransfer(uint64,address,address,uint256)")]
+	fn force_transfer(
+		handle: &mut impl PrecompileHandle,
+		id: u64,
+		source: Address,
+		dest: Address,
+		amount: U256,
+	) -> EvmResult {
+		let origin = Self::ensure_force_origin(handle)?;
+		Self::ensure_asset_exists(handle, id)?;
+
+		let asset_id = asset_id_of(id).in_field("id")?;
+		let amount: BalanceOf<Runtime, Instance> = amount
+			.try_into()
+			.map_err(|_| RevertReason::value_is_too_large("balance type").in_field("amount"))?;
+
+		// Build call with origin.
+		{
+			let source = Runtime::AddressMapping::into_account_id(source.into());
+			let dest = Runtime::AddressMapping::into_account_id(dest.into());
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				origin,
+				pallet_assets::Call::<Runtime, Instance>::force_transfer {
+					id: asset_id,
+					source: Runtime::Lookup::unlookup(source),
+					dest: Runtime::Lookup::unlookup(dest),
+					amount,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Moves the whole balance held by the account of the address of the asset itself, where it
+	/// can only have been sent by mistake, to `to`. Only callable by the configured force origin.
+	#[cfg(feature = "force")]
+	#[precompile::public("rescueMissent(uint64,address)")]
+	fn rescue_missent(handle: &mut impl PrecompileHandle, id: u64, to: Address) -> EvmResult {
+		let origin = Self::ensure_force_origin(handle)?;
+		Self::ensure_asset_exists(handle, id)?;
+
+		// Storage item: Account
+		handle.record_db_read::<Runtime>(AssetAccountOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		let holder = Runtime::AddressMapping::into_account_id(Self::asset_address(
+			handle,
+			asset_id.clone(),
+		)?);
+
+		let amount = pallet_assets::Pallet::<Runtime, Instance>::balance(asset_id.clone(), &holder);
+		if amount.is_zero() {
+			return Err(revert("nothing to rescue"))
+		}
+
+		// Build call with origin.
+		{
+			let to = Runtime::AddressMapping::into_account_id(to.into());
+
+			// Dispatch call (if enough gas).
+			RuntimeHelper::<Runtime>::try_dispatch_tagged(
+				handle,
+				origin,
+				pallet_assets::Call::<Runtime, Instance>::force_transfer {
+					id: asset_id.into(),
+					source: Runtime::Lookup::unlookup(holder),
+					dest: Runtime::Lookup::unlookup(to),
+					amount,
+				},
+				SYSTEM_ACCOUNT_SIZE,
+			)?;
+		}
+
+		Ok(())
+	}
+
+	/// Clears the state the precompiles keep about the multi-step operations on an asset: the
+	/// confirmation token and start block of its destruction, and the reservation of its id.
+	/// Only callable by the configured force origin, so that governance can abort an operation
+	/// instead of leaving it half-finished. The asset does not have to exist.
+	///
+	/// The asset itself is left as is, `pallet_assets` having no way to stop a destruction: its
+	/// owner has to get a new token through `startDestroy` before it can be finished with
+	/// `finishDestroy` or `forceFinishDestroy`.
+	#[cfg(feature = "force")]
+	#[precompile::public("cancelForceOperation(uint64)")]
+	fn cancel_force_operation(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		Self::ensure_force_origin(handle)?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		destroy_token::remove::<Runtime, Instance>(handle, asset_id.clone())?;
+		id_reservation::remove::<Runtime, Instance>(handle, asset_id)?;
+
+		Ok(())
+	}
+
+	/// Returns the address of asset `asset_id`, charging the storage reads the runtime makes to
+	/// derive it, if any, so that deriving addresses in bulk is not free.
+	fn asset_address(
+		handle: &mut impl PrecompileHandle,
+		asset_id: AssetIdOf<Runtime, Instance>,
+	) -> EvmResult<H160> {
+		let weight = Runtime::asset_id_to_address_weight();
+		if !weight.is_zero() {
+			handle.record_cost_checked(RuntimeHelper::<Runtime>::weight_to_gas(weight))?;
+			handle.record_external_cost(None, Some(weight.proof_size()), None)?;
+		}
+
+		Ok(Runtime::asset_id_to_address(asset_id))
+	}
+
+	/// Reverts with "asset not found" unless asset `id` exists, so that the methods on existing
+	/// assets report unknown ids uniformly instead of with the generic error of the pallet.
+	fn ensure_asset_exists(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		// Storage item: Asset
+		handle.record_db_read::<Runtime>(AssetDetailsOf::<Runtime, Instance>::max_encoded_len())?;
+
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+		if !assets_storage::Asset::<Runtime, Instance>::contains_key(asset_id) {
+			return Err(asset_not_found())
+		}
+
+		Ok(())
+	}
+
+	/// Reverts with "asset paused" if the owner of asset `id` paused it, for the methods moving
+	/// its balances.
+	#[cfg(any(feature = "issuance", feature = "transfers", feature = "approvals"))]
+	fn ensure_not_paused(handle: &mut impl PrecompileHandle, id: u64) -> EvmResult {
+		let asset_id = asset_id_of::<AssetIdOf<Runtime, Instance>>(id).in_field("id")?;
+
+		asset_pause::ensure_not_paused::<Runtime, Instance>(handle, asset_id)
+	}
+
+	#[cfg(any(feature = "force", feature = "destroy"))]
+	/// Ensures the caller's mapped account satisfies the runtime's `ForceOrigin`, and returns
+	/// the origin the forced `pallet_assets` calls are dispatched with.
+	fn ensure_force_origin(
+		handle: &mut impl PrecompileHandle,
+	) -> EvmResult<RuntimeOriginOf<Runtime>> {
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+
+		<Runtime as AssetsFactoryConfig>::ForceOrigin::ensure_origin(Some(caller).into())
+			.map_err(|_| revert("caller is not allowed to force"))?;
+
+		Ok(<RuntimeOriginOf<Runtime> as OriginTrait>::root())
+	}
 }
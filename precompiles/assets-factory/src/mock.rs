@@ -38,19 +38,19 @@
 use super::*;
 
 use frame_support::{
-	construct_runtime, parameter_types,
-	traits::{AsEnsureOriginWithArg, Everything},
+	construct_runtime, ord_parameter_types, parameter_types,
+	traits::{AsEnsureOriginWithArg, Contains, Everything},
 	weights::Weight,
 };
 use precompile_utils::{precompile_set::*, testing::*};
 
-use frame_system::EnsureRoot;
+use frame_system::{EnsureRoot, EnsureSignedBy};
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot};
 
 use sp_core::{ConstU32, H160, H256};
 use sp_runtime::{
 	testing::Header,
-	traits::{BlakeTwo256, IdentityLookup},
+	traits::{BlakeTwo256, Convert, IdentityLookup},
 };
 
 pub type AccountId = MockPeaqAccount;
@@ -64,16 +64,61 @@ pub type Block = frame_system::mocking::MockBlock<Runtime>;
 impl EVMAddressToAssetId<AssetId> for Runtime {
 	/// The way to convert an account to assetId is by ensuring that the prefix is 0XFFFFFFFF
 	/// and by taking the lowest 128 bits as the assetId
-	fn address_to_asset_id(_address: H160) -> Option<AssetId> {
-		None
+	fn address_to_asset_id(address: H160) -> Option<AssetId> {
+		if address == MockPeaqAccount::EVMu2Account.into() {
+			return Some(MockAssetId(3))
+		}
+
+		let bytes = address.as_fixed_bytes();
+		if bytes[0..4] != ASSET_ADDRESS_PREFIX {
+			return None
+		}
+		let mut id = [0u8; 16];
+		id.copy_from_slice(&bytes[4..20]);
+		Some(MockAssetId(u128::from_be_bytes(id)))
 	}
 
 	fn asset_id_to_address(asset_id: AssetId) -> H160 {
 		match asset_id {
 			MockAssetId(3) => MockPeaqAccount::EVMu2Account.into(),
-			_ => H160::from([0u8; 20]),
+			MockAssetId(id) => {
+				let mut address = [0u8; 20];
+				address[0..4].copy_from_slice(&ASSET_ADDRESS_PREFIX);
+				address[4..20].copy_from_slice(&id.to_be_bytes());
+				H160(address)
+			},
 		}
 	}
+
+	/// The derivation only computes the address, unless a test sets `AddressDerivationReads` to
+	/// stand for a runtime looking the addresses of remapped ids up in storage.
+	fn asset_id_to_address_weight() -> Weight {
+		AddressDerivationReadWeight::get().saturating_mul(AddressDerivationReads::get())
+	}
+}
+
+const ASSET_ADDRESS_PREFIX: [u8; 4] = [0xff; 4];
+
+parameter_types! {
+	pub static AddressDerivationReads: u64 = 0;
+	pub const AddressDerivationReadWeight: Weight = Weight::from_parts(1_000, 64);
+}
+
+/// In the tests, the account of David is taken for one derived by hashing its address, which is
+/// not mapped back to it.
+pub struct AccountToAddress;
+impl Convert<AccountId, Option<H160>> for AccountToAddress {
+	fn convert(account: AccountId) -> Option<H160> {
+		(account != MockPeaqAccount::David).then(|| account.into())
+	}
+}
+
+/// In the tests, the assets with an id of at least 1000 are cross-chain assets.
+pub struct XcAssetIds;
+impl Contains<u64> for XcAssetIds {
+	fn contains(id: &u64) -> bool {
+		*id >= 1000
+	}
 }
 
 parameter_types! {
@@ -143,15 +188,24 @@ const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 /// Block Storage Limit in bytes. Set to 40KB.
 const BLOCK_STORAGE_LIMIT: u64 = 40 * 1024;
 pub type PCall = AssetsFactoryPrecompileCall<Runtime, ()>;
-
-pub type Precompiles<R> =
-	PrecompileSetBuilder<R, (PrecompileAt<AddressU64<1>, AssetsFactoryPrecompile<R>>,)>;
+pub type ForeignPCall = AssetsFactoryPrecompileCall<Runtime, pallet_assets::Instance1>;
+pub type ECall = AssetsEnumerationPrecompileCall<Runtime, ((), pallet_assets::Instance1)>;
+
+pub type Precompiles<R> = PrecompileSetBuilder<
+	R,
+	(
+		PrecompileAt<AddressU64<1>, AssetsFactoryPrecompile<R>>,
+		PrecompileAt<AddressU64<2>, AssetsEnumerationPrecompile<R, ((), pallet_assets::Instance1)>>,
+		PrecompileAt<AddressU64<3>, AssetsFactoryPrecompile<R, pallet_assets::Instance1>>,
+	),
+>;
 
 parameter_types! {
 	pub BlockGasLimit: U256 = U256::from(u64::MAX);
 	pub PrecompilesValue: Precompiles<Runtime> = Precompiles::new();
 
 	pub WeightPerGas: Weight = Weight::from_parts(1, 0);
+	pub const ChainId: u64 = 9990;
 	pub GasLimitPovSizeRatio: u64 = {
 		let block_gas_limit = BlockGasLimit::get().min(u64::MAX.into()).low_u64();
 		block_gas_limit.saturating_div(MAX_POV_SIZE)
@@ -174,7 +228,7 @@ impl pallet_evm::Config for Runtime {
 	type Runner = pallet_evm::runner::stack::Runner<Self>;
 	type PrecompilesType = Precompiles<Self>;
 	type PrecompilesValue = PrecompilesValue;
-	type ChainId = ();
+	type ChainId = ChainId;
 	type OnChargeTransaction = ();
 	type BlockGasLimit = BlockGasLimit;
 	type BlockHashMapping = pallet_evm::SubstrateBlockHashMapping<Self>;
@@ -187,14 +241,15 @@ impl pallet_evm::Config for Runtime {
 }
 
 // These parameters dont matter much as this will only be called by root with the forced arguments
-// No deposit is substracted with those methods
+// No deposit is substracted with those methods, unless a test sets the asset, account, approval
+// and metadata ones
 parameter_types! {
-	pub const AssetDeposit: Balance = 0;
-	pub const AssetAccountDeposit: Balance = 0;
-	pub const ApprovalDeposit: Balance = 0;
+	pub static AssetDeposit: Balance = 0;
+	pub static AssetAccountDeposit: Balance = 0;
+	pub static ApprovalDeposit: Balance = 0;
 	pub const AssetsStringLimit: u32 = 50;
-	pub const MetadataDepositBase: Balance = 0;
-	pub const MetadataDepositPerByte: Balance = 0;
+	pub static MetadataDepositBase: Balance = 0;
+	pub static MetadataDepositPerByte: Balance = 0;
 }
 
 impl pallet_assets::Config for Runtime {
@@ -213,11 +268,75 @@ impl pallet_assets::Config for Runtime {
 	type Extra = ();
 	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
 	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
+	// Small, so that destroying an asset with a few accounts takes several calls.
+	type RemoveItemsLimit = ConstU32<2>;
+	type AssetIdParameter = AssetId;
+	type CallbackHandle = ();
+}
+
+// Narrower balance type, to check how the factory handles values that do not fit it
+impl pallet_assets::Config<pallet_assets::Instance1> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = u64;
+	type AssetId = AssetId;
+	type Currency = Balances;
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type AssetDeposit = AssetDeposit;
+	type AssetAccountDeposit = AssetAccountDeposit;
+	type MetadataDepositBase = MetadataDepositBase;
+	type MetadataDepositPerByte = MetadataDepositPerByte;
+	type ApprovalDeposit = ApprovalDeposit;
+	type StringLimit = AssetsStringLimit;
+	type Freezer = ();
+	type Extra = ();
+	type CreateOrigin = AsEnsureOriginWithArg<frame_system::EnsureSigned<AccountId>>;
+	type WeightInfo = pallet_assets::weights::SubstrateWeight<Runtime>;
 	type RemoveItemsLimit = ConstU32<0>;
 	type AssetIdParameter = AssetId;
 	type CallbackHandle = ();
 }
 
+ord_parameter_types! {
+	pub const ForceAccount: AccountId = MockPeaqAccount::David;
+}
+
+parameter_types! {
+	pub static MaxCreatesPerBlock: Option<u32> = None;
+	pub const DestroyTokenLifetime: BlockNumber = 10;
+	pub const IdReservationLifetime: BlockNumber = 10;
+	pub const MaxIdReservations: u32 = 2;
+	pub static EmitBatchEvents: bool = true;
+	pub const MaxTransferFeeBps: u16 = 500;
+	pub TransferFeeTreasury: H160 = MockPeaqAccount::Charlie.into();
+	pub const DefaultDecimals: u8 = 18;
+	pub static RolesMustBeDistinct: bool = false;
+	pub static OnlyInitiatorFinishesDestroy: bool = false;
+	pub static MinBalanceFloor: Option<u128> = None;
+	pub static DefaultMetadata: Option<(Vec<u8>, Vec<u8>)> = None;
+	pub static Erc20FacadePrefix: Option<[u8; 4]> = None;
+	pub static AssetsMigrating: bool = false;
+}
+
+impl AssetsFactoryConfig for Runtime {
+	type ForceOrigin = EnsureSignedBy<ForceAccount, AccountId>;
+	type MaxCreatesPerBlock = MaxCreatesPerBlock;
+	type DestroyTokenLifetime = DestroyTokenLifetime;
+	type IdReservationLifetime = IdReservationLifetime;
+	type MaxIdReservations = MaxIdReservations;
+	type EmitBatchEvents = EmitBatchEvents;
+	type MinBalanceFloor = MinBalanceFloor;
+	type XcAssetIds = XcAssetIds;
+	type AccountToAddress = AccountToAddress;
+	type MaxTransferFeeBps = MaxTransferFeeBps;
+	type TransferFeeTreasury = TransferFeeTreasury;
+	type DefaultDecimals = DefaultDecimals;
+	type DefaultMetadata = DefaultMetadata;
+	type RolesMustBeDistinct = RolesMustBeDistinct;
+	type OnlyInitiatorFinishesDestroy = OnlyInitiatorFinishesDestroy;
+	type Erc20FacadePrefix = Erc20FacadePrefix;
+	type AssetsMigrating = AssetsMigrating;
+}
+
 // Configure a mock runtime to test the pallet.
 construct_runtime!(
 	pub enum Runtime where
@@ -228,6 +347,7 @@ construct_runtime!(
 		System: frame_system,
 		Balances: pallet_balances,
 		Assets: pallet_assets,
+		ForeignAssets: pallet_assets::<Instance1>,
 		Evm: pallet_evm,
 		Timestamp: pallet_timestamp,
 	}
@@ -0,0 +1,175 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! EIP-2612 permits: approvals signed by the owner off-chain, set by anyone through `permit`.
+//!
+//! The signature is an EIP-712 one over a `Permit` of the asset, whose domain is named after the
+//! asset and verified by its address, on the chain id of the EVM. Each owner has a nonce by asset,
+//! bumped by every permit, so a signature can only be used once. As in the `ECDSA` library of
+//! OpenZeppelin, only the signatures with a low `s` are accepted, so that a signature has no
+//! second, malleated form. The nonces are keyed by the index of the `pallet_assets` instance as
+//! well, so factories over different instances do not share the nonces of assets with the same id.
+
+use crate::{assets_storage, instance_index, AssetIdOf, AssetMetadataOf};
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use parity_scale_codec::MaxEncodedLen;
+use peaq_primitives_xcm::EVMAddressToAssetId;
+use precompile_utils::{
+	keccak256,
+	prelude::{revert, Address, PrecompileHandleExt, RuntimeHelper},
+	solidity,
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+use sp_core::{H160, H256, U256};
+use sp_io::hashing::keccak_256;
+use sp_runtime::SaturatedConversion;
+
+/// Cost of recovering the signer of a permit, as the `ecrecover` precompile.
+pub const ECRECOVER_COST: u64 = 3000;
+
+/// EIP-712 type hash of the domain of the permits.
+pub const DOMAIN_TYPEHASH: [u8; 32] = keccak256!(
+	"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+);
+
+/// EIP-712 type hash of a permit.
+pub const PERMIT_TYPEHASH: [u8; 32] = keccak256!(
+	"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)"
+);
+
+/// Version of the domain of the permits.
+pub const DOMAIN_VERSION: &[u8] = b"1";
+
+/// Half of the order of the secp256k1 curve, the largest `s` of the signatures accepted.
+pub const SECP256K1_HALF_ORDER: U256 =
+	U256([0xdfe92f46681b20a0, 0x5d576e7357a4501d, 0xffffffffffffffff, 0x7fffffffffffffff]);
+
+pub struct PermitNoncesNames;
+impl StorageItemNames for PermitNoncesNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "PermitNonces";
+}
+
+/// Number of permits of an owner, by instance, asset and owner.
+pub type PermitNoncesOf<Runtime, Instance> =
+	PrecompileStorageMap<PermitNoncesNames, (u8, AssetIdOf<Runtime, Instance>, H160), U256>;
+
+/// Returns the nonce the next permit of `owner` has to be signed with.
+pub fn nonce<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	owner: H160,
+) -> EvmResult<U256>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: PermitNonces
+	handle
+		.record_db_read::<Runtime>(PermitNoncesOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	Ok(PermitNoncesOf::<Runtime, Instance>::get(&(
+		instance_index::<Runtime, Instance>(),
+		asset_id,
+		owner,
+	))
+	.unwrap_or_default())
+}
+
+/// Returns the EIP-712 domain separator of the permits of `asset_id`.
+pub fn domain_separator<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<H256>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+	Runtime: EVMAddressToAssetId<AssetIdOf<Runtime, Instance>>,
+{
+	// Storage item: Metadata
+	handle.record_db_read::<Runtime>(AssetMetadataOf::<Runtime, Instance>::max_encoded_len())?;
+	let name = assets_storage::Metadata::<Runtime, Instance>::get(asset_id.clone()).name;
+
+	Ok(keccak_256(&solidity::encode_arguments((
+		H256(DOMAIN_TYPEHASH),
+		H256(keccak_256(&name)),
+		H256(keccak_256(DOMAIN_VERSION)),
+		U256::from(<Runtime as pallet_evm::Config>::ChainId::get()),
+		Address(Runtime::asset_id_to_address(asset_id)),
+	)))
+	.into())
+}
+
+/// Checks that `owner` signed the permit and that its deadline, in seconds, has not passed, then
+/// consumes the nonce it was signed with.
+#[allow(clippy::too_many_arguments)]
+pub fn verify<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+	owner: H160,
+	spender: H160,
+	value: U256,
+	deadline: u64,
+	v: u8,
+	r: H256,
+	s: H256,
+) -> EvmResult
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config + pallet_timestamp::Config,
+	Runtime: EVMAddressToAssetId<AssetIdOf<Runtime, Instance>>,
+{
+	// Storage item: Timestamp Now
+	handle.record_db_read::<Runtime>(16)?;
+	let now: u64 = pallet_timestamp::Pallet::<Runtime>::get().saturated_into();
+	// The timestamps are in milliseconds.
+	if deadline < now / 1000 {
+		return Err(revert("permit expired"))
+	}
+
+	let nonce = nonce::<Runtime, Instance>(handle, asset_id.clone(), owner)?;
+	let domain_separator = domain_separator::<Runtime, Instance>(handle, asset_id.clone())?;
+	let permit_hash = keccak_256(&solidity::encode_arguments((
+		H256(PERMIT_TYPEHASH),
+		Address(owner),
+		Address(spender),
+		value,
+		nonce,
+		U256::from(deadline),
+	)));
+
+	let mut message = b"\x19\x01".to_vec();
+	message.extend_from_slice(domain_separator.as_bytes());
+	message.extend_from_slice(&permit_hash);
+
+	// The signature with the `s` of the other half of the order is as valid, and is rejected.
+	if U256::from_big_endian(s.as_bytes()) > SECP256K1_HALF_ORDER {
+		return Err(revert("invalid permit signature"))
+	}
+
+	let mut signature = [0u8; 65];
+	signature[0..32].copy_from_slice(r.as_bytes());
+	signature[32..64].copy_from_slice(s.as_bytes());
+	signature[64] = v;
+
+	handle.record_cost(ECRECOVER_COST)?;
+	let signer = sp_io::crypto::secp256k1_ecdsa_recover(&signature, &keccak_256(&message))
+		.map(|public| H160::from(H256(keccak_256(&public))))
+		.map_err(|_| revert("invalid permit signature"))?;
+	if signer.is_zero() || signer != owner {
+		return Err(revert("invalid permit signature"))
+	}
+
+	// Storage item: PermitNonces
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	PermitNoncesOf::<Runtime, Instance>::insert(
+		&(instance_index::<Runtime, Instance>(), asset_id, owner),
+		&nonce.saturating_add(U256::one()),
+	);
+
+	Ok(())
+}
@@ -0,0 +1,96 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Per-account, per-block limit on the assets created through the factory.
+//!
+//! The counters are kept in the storage of the precompile and tagged with the block they were
+//! last bumped in, so a counter from a previous block is simply treated as zero instead of being
+//! cleared every block.
+
+use crate::AssetsFactoryConfig;
+use fp_evm::PrecompileHandle;
+use frame_support::traits::Get;
+use frame_system::pallet_prelude::BlockNumberFor;
+use precompile_utils::{
+	prelude::{revert, PrecompileHandleExt, RuntimeHelper},
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+
+pub struct CreateCounterNames;
+impl StorageItemNames for CreateCounterNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "CreateCounter";
+}
+
+/// Number of assets created by an account, along with the block they were created in.
+pub type CreateCounterOf<Runtime> = PrecompileStorageMap<
+	CreateCounterNames,
+	<Runtime as frame_system::Config>::AccountId,
+	(BlockNumberFor<Runtime>, u32),
+>;
+
+/// Returns how many assets `who` can still create in the current block, `u32::MAX` if creates
+/// are not rate limited.
+pub fn creates_remaining<Runtime>(
+	handle: &mut impl PrecompileHandle,
+	who: &Runtime::AccountId,
+) -> EvmResult<u32>
+where
+	Runtime: AssetsFactoryConfig + pallet_evm::Config,
+{
+	let limit = match Runtime::MaxCreatesPerBlock::get() {
+		Some(limit) => limit,
+		None => return Ok(u32::MAX),
+	};
+
+	Ok(limit.saturating_sub(creates_in_block::<Runtime>(handle, who)?))
+}
+
+/// Counts a create of `who` in the current block, reverting if its quota is exhausted.
+pub fn note_create<Runtime>(
+	handle: &mut impl PrecompileHandle,
+	who: &Runtime::AccountId,
+) -> EvmResult
+where
+	Runtime: AssetsFactoryConfig + pallet_evm::Config,
+{
+	let limit = match Runtime::MaxCreatesPerBlock::get() {
+		Some(limit) => limit,
+		None => return Ok(()),
+	};
+
+	let creates = creates_in_block::<Runtime>(handle, who)?;
+	if creates >= limit {
+		return Err(revert("create rate limit exceeded"))
+	}
+
+	// Storage item: CreateCounter
+	handle.record_cost(RuntimeHelper::<Runtime>::db_write_gas_cost())?;
+	CreateCounterOf::<Runtime>::insert(
+		who,
+		&(frame_system::Pallet::<Runtime>::block_number(), creates.saturating_add(1)),
+	);
+
+	Ok(())
+}
+
+/// Returns the number of assets created by `who` in the current block.
+fn creates_in_block<Runtime>(
+	handle: &mut impl PrecompileHandle,
+	who: &Runtime::AccountId,
+) -> EvmResult<u32>
+where
+	Runtime: AssetsFactoryConfig + pallet_evm::Config,
+{
+	// Storage item: CreateCounter
+	handle.record_db_read::<Runtime>(CreateCounterOf::<Runtime>::entry_max_encoded_len())?;
+
+	Ok(match CreateCounterOf::<Runtime>::get(who) {
+		Some((block, creates)) if block == frame_system::Pallet::<Runtime>::block_number() =>
+			creates,
+		_ => 0,
+	})
+}
@@ -0,0 +1,60 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Optional supply caps of the assets, enforced by the factory on the mints it dispatches.
+//!
+//! `pallet_assets` has no notion of a maximum supply, so the caps are kept in the storage of the
+//! precompile. They are keyed by the index of the `pallet_assets` instance as well, so factories
+//! over different instances do not share the caps of assets with the same id.
+
+use crate::{instance_index, AssetIdOf, BalanceOf};
+use fp_evm::PrecompileHandle;
+use precompile_utils::{
+	prelude::PrecompileHandleExt,
+	storage::{PrecompileStorageMap, StorageItemNames},
+	EvmResult,
+};
+
+pub struct SupplyCapNames;
+impl StorageItemNames for SupplyCapNames {
+	const PRECOMPILE: &'static str = "AssetsFactoryPrecompile";
+	const ITEM: &'static str = "SupplyCap";
+}
+
+/// Maximum supply of an asset of a `pallet_assets` instance.
+pub type SupplyCapOf<Runtime, Instance> = PrecompileStorageMap<
+	SupplyCapNames,
+	(u8, AssetIdOf<Runtime, Instance>),
+	BalanceOf<Runtime, Instance>,
+>;
+
+/// Returns the supply cap of `asset_id`, if any.
+pub fn get<Runtime, Instance>(
+	handle: &mut impl PrecompileHandle,
+	asset_id: AssetIdOf<Runtime, Instance>,
+) -> EvmResult<Option<BalanceOf<Runtime, Instance>>>
+where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance> + pallet_evm::Config,
+{
+	// Storage item: SupplyCap
+	handle.record_db_read::<Runtime>(SupplyCapOf::<Runtime, Instance>::entry_max_encoded_len())?;
+
+	Ok(SupplyCapOf::<Runtime, Instance>::get(&(instance_index::<Runtime, Instance>(), asset_id)))
+}
+
+/// Sets the supply cap of `asset_id`, removing it if `cap` is `None`.
+pub fn set<Runtime, Instance>(
+	asset_id: AssetIdOf<Runtime, Instance>,
+	cap: Option<BalanceOf<Runtime, Instance>>,
+) where
+	Instance: 'static,
+	Runtime: pallet_assets::Config<Instance>,
+{
+	SupplyCapOf::<Runtime, Instance>::set(
+		&(instance_index::<Runtime, Instance>(), asset_id),
+		cap.as_ref(),
+	);
+}
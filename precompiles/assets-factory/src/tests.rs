@@ -36,12 +36,124 @@
 
 use crate::{mock::*, *};
 
-use precompile_utils::testing::*;
+use frame_support::{
+	assert_ok,
+	traits::{
+		fungibles::{
+			approvals::Inspect as ApprovalInspect, metadata::Inspect as MetadataInspect,
+			roles::Inspect as RolesInspect,
+		},
+		ReservableCurrency,
+	},
+};
+use pallet_evm::{GasWeightMapping, Log};
+use parity_scale_codec::Encode;
+use precompile_utils::{
+	prelude::{log2, log3, UnboundedBytes},
+	testing::*,
+};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
 }
 
+fn asset_created_log(id: u64, creator: MockPeaqAccount, admin: MockPeaqAccount) -> Log {
+	log3(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_ASSET_CREATED,
+		H256::from_low_u64_be(id),
+		H160::from(creator),
+		solidity::encode_event_data(Address(admin.into())),
+	)
+}
+
+fn metadata_set_log(id: u64, name: Vec<u8>, symbol: Vec<u8>, decimals: u8) -> Log {
+	log2(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_METADATA_SET,
+		H256::from_low_u64_be(id),
+		solidity::encode_event_data((
+			UnboundedBytes::from(name),
+			UnboundedBytes::from(symbol),
+			decimals,
+		)),
+	)
+}
+
+fn team_changed_log(
+	id: u64,
+	issuer: MockPeaqAccount,
+	admin: MockPeaqAccount,
+	freezer: MockPeaqAccount,
+) -> Log {
+	log2(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_TEAM_CHANGED,
+		H256::from_low_u64_be(id),
+		solidity::encode_event_data((
+			Address(issuer.into()),
+			Address(admin.into()),
+			Address(freezer.into()),
+		)),
+	)
+}
+
+fn min_balance_changed_log(id: u64, new_min: u128) -> Log {
+	log2(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_MIN_BALANCE_CHANGED,
+		H256::from_low_u64_be(id),
+		solidity::encode_event_data(new_min),
+	)
+}
+
+fn supply_cap_changed_log(id: u64, new_cap: u128) -> Log {
+	log2(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_SUPPLY_CAP_CHANGED,
+		H256::from_low_u64_be(id),
+		solidity::encode_event_data(U256::from(new_cap)),
+	)
+}
+
+fn minted_log(id: u64, to: MockPeaqAccount, amount: u128) -> Log {
+	log3(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_MINTED,
+		H256::from_low_u64_be(id),
+		H160::from(to),
+		solidity::encode_event_data(U256::from(amount)),
+	)
+}
+
+fn transfer_log(id: u64, from: MockPeaqAccount, to: MockPeaqAccount, amount: u128) -> Log {
+	log3(
+		Runtime::asset_id_to_address(MockAssetId(id.into())),
+		SELECTOR_LOG_TRANSFER,
+		H160::from(from),
+		H160::from(to),
+		solidity::encode_event_data(U256::from(amount)),
+	)
+}
+
+fn approval_log(id: u64, owner: MockPeaqAccount, spender: MockPeaqAccount, amount: u128) -> Log {
+	log3(
+		Runtime::asset_id_to_address(MockAssetId(id.into())),
+		SELECTOR_LOG_APPROVAL,
+		H160::from(owner),
+		H160::from(spender),
+		solidity::encode_event_data(U256::from(amount)),
+	)
+}
+
+fn destroy_token(id: u64, issued_at: BlockNumber) -> H256 {
+	H256(sp_io::hashing::keccak_256(&(id, issued_at).encode()))
+}
+
+fn log_cost(log: &Log) -> u64 {
+	log.compute_cost().expect("log cost fits in u64")
+}
+
 #[test]
 fn selector_less_than_four_bytes() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -72,13 +184,105 @@ fn no_selector_exists_but_length_is_right() {
 #[test]
 fn selectors() {
 	assert!(PCall::convert_asset_id_to_address_selectors().contains(&0xa70174cb));
+	assert!(PCall::erc20_address_selectors().contains(&0x1a296d66));
+	assert!(PCall::asset_count_selectors().contains(&0xeafe7a74));
+	assert!(PCall::classify_address_selectors().contains(&0x5b046ea2));
+	assert!(PCall::address_is_live_asset_selectors().contains(&0x17fef253));
+	assert!(PCall::caller_account_selectors().contains(&0x49e71f1d));
+	assert!(PCall::chain_id_selectors().contains(&0x9a8a0592));
+	assert!(PCall::default_decimals_selectors().contains(&0x30f08abd));
+	assert!(PCall::roles_must_be_distinct_selectors().contains(&0xa9a85140));
+	assert!(PCall::amount_conversion_mode_selectors().contains(&0x394b6c5a));
+	assert!(PCall::account_deposit_selectors().contains(&0xbb384740));
+	assert!(PCall::min_balance_floor_selectors().contains(&0x29801d91));
 	assert!(PCall::create_selectors().contains(&0x9c28547e));
+	assert!(PCall::create_self_admin_selectors().contains(&0x1afcff92));
+	assert!(PCall::create_idempotent_selectors().contains(&0x5c2597b7));
+	assert!(PCall::reserve_id_selectors().contains(&0x8ff49ee6));
+	assert!(PCall::create_with_metadata_selectors().contains(&0x206bfee8));
+	assert!(PCall::create_from_template_selectors().contains(&0xb9be431f));
+	assert!(PCall::create_with_team_selectors().contains(&0xfb589a2d));
+	assert!(PCall::safe_launch_selectors().contains(&0xf2445571));
 	assert!(PCall::set_metadata_selectors().contains(&0xf96ee86d));
+	assert!(PCall::set_metadata_defaults_selectors().contains(&0x87e3eed5));
+	assert!(PCall::rename_selectors().contains(&0x9f41d4a2));
 	assert!(PCall::set_min_balance_selectors().contains(&0x28bfefa1));
+	assert!(PCall::set_min_balance_batch_selectors().contains(&0x91b14ce2));
+	assert!(PCall::creates_remaining_selectors().contains(&0xdfc3dab8));
 	assert!(PCall::set_team_selectors().contains(&0xb6e6b7d4));
 	assert!(PCall::transfer_ownership_selectors().contains(&0x0a94864e));
+	assert!(PCall::transfer_ownership_to_contract_selectors().contains(&0xd17e790a));
+	assert!(PCall::transfer_ownership_and_set_team_selectors().contains(&0xc20ea395));
 	assert!(PCall::start_destroy_selectors().contains(&0x13f946af));
-	assert!(PCall::finish_destroy_selectors().contains(&0x99c720ff));
+	assert!(PCall::finish_destroy_selectors().contains(&0xacd19595));
+	assert!(PCall::force_finish_destroy_selectors().contains(&0x4430b8fa));
+	assert!(PCall::block_selectors().contains(&0x63422462));
+	assert!(PCall::freeze_selectors().contains(&0x4d1a1635));
+	assert!(PCall::thaw_selectors().contains(&0xef33e57a));
+	assert!(PCall::freeze_asset_selectors().contains(&0xfdade1d5));
+	assert!(PCall::thaw_asset_selectors().contains(&0xc8ae38b6));
+	assert!(PCall::set_asset_paused_selectors().contains(&0x13a30b0d));
+	assert!(PCall::asset_paused_selectors().contains(&0xb1213295));
+	assert!(PCall::owner_of_selectors().contains(&0x7903a617));
+	assert!(PCall::mint_selectors().contains(&0xf2be4599));
+	assert!(PCall::burn_selectors().contains(&0xa4fa3313));
+	assert!(PCall::batch_mint_selectors().contains(&0x3c2dcdcf));
+	assert!(PCall::set_supply_cap_selectors().contains(&0xab1c3510));
+	assert!(PCall::supply_cap_selectors().contains(&0x84e0e2ad));
+	assert!(PCall::set_transfer_fee_bps_selectors().contains(&0x6947288f));
+	assert!(PCall::transfer_fee_bps_selectors().contains(&0x49b7940b));
+	assert!(PCall::account_counts_selectors().contains(&0x39d54988));
+	assert!(PCall::name_selectors().contains(&0x20fc811e));
+	assert!(PCall::symbol_selectors().contains(&0x7904fe8c));
+	assert!(PCall::decimals_selectors().contains(&0xff01a6f7));
+	assert!(PCall::token_info_batch_selectors().contains(&0x6120b296));
+	assert!(PCall::last_error_selectors().contains(&0x29f0de3f));
+	assert!(PCall::call_stats_selectors().contains(&0x9e5de11e));
+	assert!(PCall::supported_selectors_selectors().contains(&0x8d057a25));
+	assert!(PCall::permissions_selectors().contains(&0xfcb1d7ae));
+	assert!(PCall::is_blocked_selectors().contains(&0xd1dae163));
+	assert!(PCall::reserved_deposit_of_selectors().contains(&0x51c6b1e2));
+	assert!(PCall::approval_deposit_of_selectors().contains(&0xe45eeaae));
+	assert!(PCall::self_check_selectors().contains(&0x1785e185));
+	assert!(PCall::copy_metadata_selectors().contains(&0xca14fa7c));
+	assert!(PCall::transfer_and_touch_selectors().contains(&0xa58745dd));
+	assert!(PCall::batch_transfer_selectors().contains(&0xadf58a37));
+	assert!(PCall::batch_transfer_with_events_selectors().contains(&0x1191da99));
+	assert!(PCall::approve_with_deadline_selectors().contains(&0xd86d35ae));
+	assert!(PCall::permit_selectors().contains(&0x49448b04));
+	assert!(PCall::nonces_selectors().contains(&0x38a6d67e));
+	assert!(PCall::domain_separator_selectors().contains(&0xe9b8305a));
+	assert!(PCall::transfer_approved_selectors().contains(&0x81051e8a));
+	assert!(PCall::cancel_approval_selectors().contains(&0x1074f6ea));
+	assert!(PCall::allowance_deadline_selectors().contains(&0xdb398b0a));
+	assert!(PCall::destroy_started_at_selectors().contains(&0x8bbe21c7));
+	assert!(PCall::extended_config_selectors().contains(&0x1b48dd4d));
+	assert!(PCall::force_create_selectors().contains(&0xa4075e04));
+	assert!(PCall::force_set_metadata_selectors().contains(&0xaa814f2c));
+	assert!(PCall::force_clear_metadata_selectors().contains(&0xbdd2b221));
+	assert!(PCall::force_asset_status_selectors().contains(&0x74293733));
+	assert!(PCall::force_transfer_selectors().contains(&0x69098f87));
+	assert!(PCall::rescue_missent_selectors().contains(&0x709ea72e));
+	assert!(PCall::cancel_force_operation_selectors().contains(&0x706d9b49));
+	assert!(ECall::assets_selectors().contains(&0x71a97305));
+	assert!(ECall::assets_page_selectors().contains(&0x6146c6bb));
+}
+
+#[test]
+fn view_selectors_are_the_ones_of_the_views() {
+	let views = PCall::view_selectors();
+	for selectors in [
+		PCall::asset_count_selectors(),
+		PCall::supply_cap_selectors(),
+		PCall::call_stats_selectors(),
+		PCall::self_check_selectors(),
+	] {
+		assert!(selectors.iter().all(|selector| views.contains(selector)));
+	}
+	for selectors in [PCall::create_selectors(), PCall::mint_selectors(), PCall::permit_selectors()]
+	{
+		assert!(selectors.iter().all(|selector| !views.contains(selector)));
+	}
 }
 
 #[test]
@@ -94,13 +298,86 @@ fn modifiers() {
 			);
 
 			tester.test_view_modifier(PCall::convert_asset_id_to_address_selectors());
+			tester.test_view_modifier(PCall::erc20_address_selectors());
+			tester.test_view_modifier(PCall::asset_count_selectors());
+			tester.test_view_modifier(PCall::classify_address_selectors());
+			tester.test_view_modifier(PCall::address_is_live_asset_selectors());
+			tester.test_view_modifier(PCall::caller_account_selectors());
+			tester.test_view_modifier(PCall::chain_id_selectors());
+			tester.test_view_modifier(PCall::default_decimals_selectors());
+			tester.test_view_modifier(PCall::roles_must_be_distinct_selectors());
+			tester.test_view_modifier(PCall::amount_conversion_mode_selectors());
+			tester.test_view_modifier(PCall::account_deposit_selectors());
+			tester.test_view_modifier(PCall::min_balance_floor_selectors());
 			tester.test_default_modifier(PCall::create_selectors());
+			tester.test_default_modifier(PCall::create_self_admin_selectors());
+			tester.test_default_modifier(PCall::create_idempotent_selectors());
+			tester.test_default_modifier(PCall::reserve_id_selectors());
+			tester.test_default_modifier(PCall::create_with_metadata_selectors());
+			tester.test_default_modifier(PCall::create_from_template_selectors());
+			tester.test_default_modifier(PCall::copy_metadata_selectors());
+			tester.test_default_modifier(PCall::create_with_team_selectors());
+			tester.test_default_modifier(PCall::safe_launch_selectors());
 			tester.test_default_modifier(PCall::set_metadata_selectors());
+			tester.test_default_modifier(PCall::set_metadata_defaults_selectors());
+			tester.test_default_modifier(PCall::rename_selectors());
 			tester.test_default_modifier(PCall::set_min_balance_selectors());
+			tester.test_default_modifier(PCall::set_min_balance_batch_selectors());
+			tester.test_view_modifier(PCall::creates_remaining_selectors());
 			tester.test_default_modifier(PCall::set_team_selectors());
 			tester.test_default_modifier(PCall::transfer_ownership_selectors());
+			tester.test_default_modifier(PCall::transfer_ownership_to_contract_selectors());
+			tester.test_default_modifier(PCall::transfer_ownership_and_set_team_selectors());
 			tester.test_default_modifier(PCall::start_destroy_selectors());
 			tester.test_default_modifier(PCall::finish_destroy_selectors());
+			tester.test_default_modifier(PCall::force_finish_destroy_selectors());
+			tester.test_default_modifier(PCall::block_selectors());
+			tester.test_default_modifier(PCall::freeze_selectors());
+			tester.test_default_modifier(PCall::thaw_selectors());
+			tester.test_default_modifier(PCall::freeze_asset_selectors());
+			tester.test_default_modifier(PCall::thaw_asset_selectors());
+			tester.test_default_modifier(PCall::set_asset_paused_selectors());
+			tester.test_view_modifier(PCall::asset_paused_selectors());
+			tester.test_view_modifier(PCall::owner_of_selectors());
+			tester.test_default_modifier(PCall::mint_selectors());
+			tester.test_default_modifier(PCall::burn_selectors());
+			tester.test_default_modifier(PCall::batch_mint_selectors());
+			tester.test_default_modifier(PCall::set_supply_cap_selectors());
+			tester.test_view_modifier(PCall::supply_cap_selectors());
+			tester.test_default_modifier(PCall::set_transfer_fee_bps_selectors());
+			tester.test_view_modifier(PCall::transfer_fee_bps_selectors());
+			tester.test_view_modifier(PCall::account_counts_selectors());
+			tester.test_view_modifier(PCall::name_selectors());
+			tester.test_view_modifier(PCall::symbol_selectors());
+			tester.test_view_modifier(PCall::decimals_selectors());
+			tester.test_view_modifier(PCall::token_info_batch_selectors());
+			tester.test_view_modifier(PCall::last_error_selectors());
+			tester.test_view_modifier(PCall::call_stats_selectors());
+			tester.test_view_modifier(PCall::supported_selectors_selectors());
+			tester.test_view_modifier(PCall::permissions_selectors());
+			tester.test_view_modifier(PCall::is_blocked_selectors());
+			tester.test_view_modifier(PCall::reserved_deposit_of_selectors());
+			tester.test_view_modifier(PCall::approval_deposit_of_selectors());
+			tester.test_view_modifier(PCall::self_check_selectors());
+			tester.test_default_modifier(PCall::transfer_and_touch_selectors());
+			tester.test_default_modifier(PCall::batch_transfer_selectors());
+			tester.test_default_modifier(PCall::batch_transfer_with_events_selectors());
+			tester.test_default_modifier(PCall::approve_with_deadline_selectors());
+			tester.test_default_modifier(PCall::permit_selectors());
+			tester.test_view_modifier(PCall::nonces_selectors());
+			tester.test_view_modifier(PCall::domain_separator_selectors());
+			tester.test_default_modifier(PCall::transfer_approved_selectors());
+			tester.test_default_modifier(PCall::cancel_approval_selectors());
+			tester.test_view_modifier(PCall::allowance_deadline_selectors());
+			tester.test_view_modifier(PCall::destroy_started_at_selectors());
+			tester.test_view_modifier(PCall::extended_config_selectors());
+			tester.test_default_modifier(PCall::force_create_selectors());
+			tester.test_default_modifier(PCall::force_set_metadata_selectors());
+			tester.test_default_modifier(PCall::force_clear_metadata_selectors());
+			tester.test_default_modifier(PCall::force_asset_status_selectors());
+			tester.test_default_modifier(PCall::force_transfer_selectors());
+			tester.test_default_modifier(PCall::rescue_missent_selectors());
+			tester.test_default_modifier(PCall::cancel_force_operation_selectors());
 		});
 }
 
@@ -118,63 +395,285 @@ fn convert_asset_id_to_address() {
 }
 
 #[test]
-fn create() {
+fn convert_asset_id_to_address_charges_the_reads_of_the_derivation() {
+	ExtBuilder::default().build().execute_with(|| {
+		let read_gas = RuntimeHelper::<Runtime>::weight_to_gas(AddressDerivationReadWeight::get());
+		assert!(read_gas > 0);
+
+		for reads in [1u64, 3] {
+			AddressDerivationReads::set(reads);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::convert_asset_id_to_address { id: 3u64 },
+				)
+				.expect_cost(reads * read_gas)
+				.expect_no_logs()
+				.execute_returns(Address(MockPeaqAccount::EVMu2Account.into()));
+		}
+	});
+}
+
+#[test]
+fn erc20_address_derives_the_facade_of_the_configured_prefix() {
+	ExtBuilder::default().build().execute_with(|| {
+		Erc20FacadePrefix::set(Some([0xee, 0xee, 0xee, 0x01]));
+
+		for (id, expected) in [
+			(7u64, [0xee, 0xee, 0xee, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7]),
+			(0x0102_0304, [0xee, 0xee, 0xee, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 3, 4]),
+			(
+				u64::MAX,
+				[
+					0xee, 0xee, 0xee, 1, 0, 0, 0, 0, 0, 0, 0, 0, 255, 255, 255, 255, 255, 255, 255,
+					255,
+				],
+			),
+		] {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::erc20_address { id },
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns(Address(H160(expected)));
+		}
+	});
+}
+
+#[test]
+fn erc20_address_reverts_without_a_facade() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::erc20_address { id: 7u64 },
+			)
+			.execute_reverts(|output| output == b"no erc20 facade");
+	});
+}
+
+#[test]
+fn asset_id_too_large_reports_the_max_id() {
+	// The mock asset ids are `u128`, so the bounded ids are checked on the conversion itself.
+	assert_eq!(max_asset_id::<u32>(), u32::MAX as u64);
+	assert_eq!(asset_id_of::<u32>(u32::MAX as u64), Ok(u32::MAX));
+	assert_eq!(
+		asset_id_of::<u32>(u32::MAX as u64 + 1).in_field("id").unwrap_err().to_string(),
+		"id: Value is too large for asset id (max 4294967295)"
+	);
+
+	// The ids of the Peaq runtimes end with the ones of the LP tokens.
+	assert_eq!(max_asset_id::<peaq_primitives_xcm::AssetId>(), (1 << 61) - 1);
+	assert_eq!(max_asset_id::<MockAssetId>(), u64::MAX);
+}
+
+#[test]
+fn classify_address() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
 		.execute_with(|| {
-			precompiles()
-				.prepare_test(
+			let precompiles = precompiles();
+			for id in [7, 1000] {
+				assert_ok!(Assets::create(
+					RuntimeOrigin::signed(MockPeaqAccount::Alice),
+					MockAssetId(id),
+					MockPeaqAccount::Alice,
+					1
+				));
+			}
+			let classify_address = |address: H160| {
+				precompiles.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::create {
-						id: 7u64,
-						admin: Address(MockPeaqAccount::Bob.into()),
-						min_balance: 500,
-					},
+					PCall::classify_address { address: Address(address) },
 				)
+			};
+
+			classify_address(Runtime::asset_id_to_address(MockAssetId(7)))
 				.expect_no_logs()
-				.execute_returns(());
+				.execute_returns(AddressClass { kind: ADDRESS_KIND_LOCAL_ASSET, id: 7 });
+			classify_address(Runtime::asset_id_to_address(MockAssetId(1000)))
+				.expect_no_logs()
+				.execute_returns(AddressClass { kind: ADDRESS_KIND_XC_ASSET, id: 1000 });
+
+			// Neither an account nor the address of an asset which does not exist.
+			classify_address(MockPeaqAccount::Bob.into())
+				.expect_no_logs()
+				.execute_returns(AddressClass { kind: ADDRESS_KIND_NONE, id: 0 });
+			classify_address(Runtime::asset_id_to_address(MockAssetId(8)))
+				.expect_no_logs()
+				.execute_returns(AddressClass { kind: ADDRESS_KIND_NONE, id: 0 });
 		});
 }
 
 #[test]
-fn set_metadata() {
+fn mutating_methods_revert_while_the_pallet_is_migrating() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
 		.execute_with(|| {
-			precompiles()
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			let create = |id: u64| PCall::create {
+				id,
+				admin: Address(MockPeaqAccount::Alice.into()),
+				min_balance: 1,
+			};
+
+			AssetsMigrating::set(true);
+			precompiles
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, create(8))
+				.execute_reverts(|output| output == b"pallet migrating, try later");
+			assert!(Assets::maybe_total_supply(MockAssetId(8)).is_none());
+			precompiles
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::create {
+					PCall::mint {
 						id: 7u64,
-						admin: Address(MockPeaqAccount::Bob.into()),
-						min_balance: 500,
+						beneficiary: Address(MockPeaqAccount::Bob.into()),
+						amount: 100.into(),
 					},
 				)
-				.expect_no_logs()
-				.execute_returns(());
+				.execute_reverts(|output| output == b"pallet migrating, try later");
 
-			precompiles()
+			// The views still read the assets.
+			precompiles
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::set_metadata {
-						id: 7u64,
-						name: vec![1u8, 2u8, 3u8].into(),
-						symbol: vec![4u8, 5u8, 6u8].into(),
-						decimals: 18u8,
+					PCall::address_is_live_asset {
+						address: Address(Runtime::asset_id_to_address(MockAssetId(7))),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::address_is_live_asset {
+						address: Address(Runtime::asset_id_to_address(MockAssetId(8))),
 					},
 				)
 				.expect_no_logs()
+				.execute_returns(false);
+
+			AssetsMigrating::set(false);
+			precompiles
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, create(8))
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
 				.execute_returns(());
 		});
 }
 
 #[test]
-fn set_min_balance() {
+fn address_is_live_asset() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			for id in [7, 8, 9] {
+				assert_ok!(Assets::create(
+					RuntimeOrigin::signed(MockPeaqAccount::Alice),
+					MockAssetId(id),
+					MockPeaqAccount::Alice,
+					1
+				));
+			}
+			assert_ok!(Assets::freeze_asset(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(8)
+			));
+			assert_ok!(Assets::start_destroy(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(9)
+			));
+			let address_is_live_asset = |address: H160| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::address_is_live_asset { address: Address(address) },
+				)
+			};
+
+			address_is_live_asset(Runtime::asset_id_to_address(MockAssetId(7)))
+				.expect_no_logs()
+				.execute_returns(true);
+			// Frozen, being destroyed, never created, and not the address of an asset.
+			address_is_live_asset(Runtime::asset_id_to_address(MockAssetId(8)))
+				.execute_returns(false);
+			address_is_live_asset(Runtime::asset_id_to_address(MockAssetId(9)))
+				.execute_returns(false);
+			address_is_live_asset(Runtime::asset_id_to_address(MockAssetId(10)))
+				.execute_returns(false);
+			address_is_live_asset(MockPeaqAccount::Bob.into()).execute_returns(false);
+		});
+}
+
+#[test]
+fn caller_account() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::caller_account {},
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(H256::repeat_byte(0xAA));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::caller_account {},
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(H256(MockPeaqAccount::David.into()));
+	});
+}
+
+#[test]
+fn chain_id() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, PCall::chain_id {})
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(<Runtime as pallet_evm::Config>::ChainId::get());
+	});
+}
+
+#[test]
+fn account_deposit() {
+	ExtBuilder::default().build().execute_with(|| {
+		AssetAccountDeposit::set(25);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::account_deposit {},
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(U256::from(AssetAccountDeposit::get()));
+	});
+}
+
+#[test]
+fn create() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
@@ -189,26 +688,50 @@ fn set_min_balance() {
 						min_balance: 500,
 					},
 				)
-				.expect_no_logs()
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
 				.execute_returns(());
+		});
+}
 
+#[test]
+fn create_with_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::set_min_balance { id: 7u64, min_balance: 1000 },
+					PCall::create_with_metadata {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+						name: b"Seven".to_vec().into(),
+						symbol: b"SVN".to_vec().into(),
+						decimals: 18,
+					},
 				)
-				.expect_no_logs()
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(7, b"Seven".to_vec(), b"SVN".to_vec(), 18))
 				.execute_returns(());
+
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 500);
+			assert_eq!(Assets::name(MockAssetId(7)), b"Seven");
+			assert_eq!(Assets::symbol(MockAssetId(7)), b"SVN");
+			assert_eq!(Assets::decimals(MockAssetId(7)), 18);
 		});
 }
 
 #[test]
-fn set_team() {
+fn create_sets_the_default_metadata_when_configured() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
 		.execute_with(|| {
+			DefaultMetadata::set(Some((b"Unnamed".to_vec(), b"UNN".to_vec())));
+
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Alice,
@@ -219,60 +742,114 @@ fn set_team() {
 						min_balance: 500,
 					},
 				)
-				.expect_no_logs()
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(7, b"Unnamed".to_vec(), b"UNN".to_vec(), 18))
 				.execute_returns(());
 
+			assert_eq!(Assets::name(MockAssetId(7)), b"Unnamed");
+			assert_eq!(Assets::symbol(MockAssetId(7)), b"UNN");
+			assert_eq!(Assets::decimals(MockAssetId(7)), 18);
+
+			// A default name longer than the string limit fails the create as a whole.
+			DefaultMetadata::set(Some((vec![b'a'; 51], b"UNN".to_vec())));
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::set_team {
-						id: 7u64,
-						issuer: Address(MockPeaqAccount::Charlie.into()),
-						admin: Address(MockPeaqAccount::Charlie.into()),
-						freezer: Address(MockPeaqAccount::Charlie.into()),
+					PCall::create {
+						id: 8u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
 					},
 				)
-				.expect_no_logs()
-				.execute_returns(());
+				.execute_reverts(|output| output == b"name: exceeds the string limit of 50");
+			assert!(Assets::maybe_total_supply(MockAssetId(8)).is_none());
 		});
 }
 
 #[test]
-fn transfer_ownership() {
+fn creates_with_metadata_skip_the_default_metadata() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
 		.execute_with(|| {
-			precompiles()
+			let precompiles = precompiles();
+			DefaultMetadata::set(Some((b"Unnamed".to_vec(), b"UNN".to_vec())));
+
+			// The metadata is set once, so only its dispatch and its log are charged.
+			precompiles
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::create {
+					PCall::create_with_metadata {
 						id: 7u64,
 						admin: Address(MockPeaqAccount::Bob.into()),
 						min_balance: 500,
+						name: b"Seven".to_vec().into(),
+						symbol: b"SVN".to_vec().into(),
+						decimals: 6,
 					},
 				)
-				.expect_no_logs()
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::create {
+						id: MockAssetId(7),
+						admin: MockPeaqAccount::Bob,
+						min_balance: 500,
+					}) + dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+						id: MockAssetId(7),
+						name: b"Seven".to_vec(),
+						symbol: b"SVN".to_vec(),
+						decimals: 6,
+					}) + log_cost(&asset_created_log(
+						7,
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::Bob,
+					)) + log_cost(&metadata_set_log(7, b"Seven".to_vec(), b"SVN".to_vec(), 6)),
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(7, b"Seven".to_vec(), b"SVN".to_vec(), 6))
 				.execute_returns(());
+			assert_eq!(Assets::name(MockAssetId(7)), b"Seven");
 
-			precompiles()
+			precompiles
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::transfer_ownership {
-						id: 7u64,
-						owner: Address(MockPeaqAccount::Bob.into()),
+					PCall::safe_launch { params: launch_params(8, MockPeaqAccount::Alice, 0) },
+				)
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(8, b"Seven".to_vec(), b"SVN".to_vec(), 18))
+				.expect_log(team_changed_log(
+					8,
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::Charlie,
+				))
+				.execute_returns(());
+			assert_eq!(Assets::symbol(MockAssetId(8)), b"SVN");
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create_from_template {
+						ids: vec![9u64].into(),
+						names: vec![b"Nine".to_vec().into()].into(),
+						symbols: vec![b"NIN".to_vec().into()].into(),
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+						decimals: 6,
 					},
 				)
-				.expect_no_logs()
+				.expect_log(asset_created_log(9, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(9, b"Nine".to_vec(), b"NIN".to_vec(), 6))
 				.execute_returns(());
+			assert_eq!(Assets::symbol(MockAssetId(9)), b"NIN");
 		});
 }
 
 #[test]
-fn start_destroy() {
+fn create_sets_no_metadata_by_default() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
@@ -287,55 +864,7154 @@ fn start_destroy() {
 						min_balance: 500,
 					},
 				)
-				.expect_no_logs()
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
 				.execute_returns(());
 
-			precompiles()
-				.prepare_test(
-					MockPeaqAccount::Alice,
-					MockPeaqAccount::EVMu1Account,
-					PCall::start_destroy { id: 7u64 },
-				)
-				.expect_no_logs()
-				.execute_returns(());
+			assert!(Assets::name(MockAssetId(7)).is_empty());
+			assert!(Assets::symbol(MockAssetId(7)).is_empty());
+			assert_eq!(Assets::decimals(MockAssetId(7)), 0);
+			assert!(assets_storage::Metadata::<Runtime, ()>::try_get(MockAssetId(7)).is_err());
 		});
 }
 
 #[test]
-fn end_destroy() {
+fn create_with_metadata_rolls_back_create_when_metadata_fails() {
 	ExtBuilder::default()
 		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
 		.build()
 		.execute_with(|| {
+			// The name is longer than the string limit of the pallet.
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::create {
+					PCall::create_with_metadata {
 						id: 7u64,
 						admin: Address(MockPeaqAccount::Bob.into()),
 						min_balance: 500,
+						name: vec![b'a'; 51].into(),
+						symbol: b"SVN".to_vec().into(),
+						decimals: 18,
 					},
 				)
-				.expect_no_logs()
-				.execute_returns(());
+				.execute_reverts(|output| output == b"name: exceeds the string limit of 50");
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 0);
+		});
+}
+
+fn create_from_template(ids: Vec<u64>, names: Vec<&[u8]>, symbols: Vec<&[u8]>) -> PCall {
+	PCall::create_from_template {
+		ids: ids.into(),
+		names: names.into_iter().map(|name| name.to_vec().into()).collect::<Vec<_>>().into(),
+		symbols: symbols
+			.into_iter()
+			.map(|symbol| symbol.to_vec().into())
+			.collect::<Vec<_>>()
+			.into(),
+		admin: Address(MockPeaqAccount::Bob.into()),
+		min_balance: 500,
+		decimals: 12,
+	}
+}
 
+#[test]
+fn create_from_template_applies_the_shared_fields_to_each_id() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::start_destroy { id: 7u64 },
+					create_from_template(vec![7, 8], vec![b"Seven", b"Eight"], vec![b"S7", b"E8"]),
 				)
-				.expect_no_logs()
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(7, b"Seven".to_vec(), b"S7".to_vec(), 12))
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(8, b"Eight".to_vec(), b"E8".to_vec(), 12))
 				.execute_returns(());
 
+			for (id, name, symbol) in
+				[(7, b"Seven".to_vec(), b"S7".to_vec()), (8, b"Eight".to_vec(), b"E8".to_vec())]
+			{
+				assert_eq!(Assets::owner(MockAssetId(id)), Some(MockPeaqAccount::Alice));
+				assert_eq!(Assets::admin(MockAssetId(id)), Some(MockPeaqAccount::Bob));
+				assert_eq!(Assets::minimum_balance(MockAssetId(id)), 500);
+				assert_eq!(Assets::name(MockAssetId(id)), name);
+				assert_eq!(Assets::symbol(MockAssetId(id)), symbol);
+				assert_eq!(Assets::decimals(MockAssetId(id)), 12);
+			}
+		});
+}
+
+#[test]
+fn create_from_template_rejects_length_mismatch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					create_from_template(vec![7, 8], vec![b"Seven"], vec![b"S7", b"E8"]),
+				)
+				.execute_reverts(|output| output == b"names: ids and names lengths differ");
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					create_from_template(vec![7, 8], vec![b"Seven", b"Eight"], vec![b"S7"]),
+				)
+				.execute_reverts(|output| output == b"symbols: ids and symbols lengths differ");
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 0);
+		});
+}
+
+#[test]
+fn create_from_template_rolls_back_the_batch_when_an_entry_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(8),
+				MockPeaqAccount::Charlie,
+				true,
+				1
+			));
+
+			// The second entry fails, so the first asset is not created either.
 			precompiles()
 				.prepare_test(
 					MockPeaqAccount::Alice,
 					MockPeaqAccount::EVMu1Account,
-					PCall::finish_destroy { id: 7u64 },
+					create_from_template(
+						vec![7, 8, 9],
+						vec![b"Seven", b"Eight", b"Nine"],
+						vec![b"S7", b"E8", b"N9"],
+					),
+				)
+				.execute_reverts(|output| output == b"ids[1]: id: asset already exists");
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			assert!(Assets::maybe_total_supply(MockAssetId(9)).is_none());
+			assert_eq!(Assets::owner(MockAssetId(8)), Some(MockPeaqAccount::Charlie));
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 0);
+		});
+}
+
+#[test]
+fn create_with_team() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create_with_team {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+						issuer: Address(MockPeaqAccount::Charlie.into()),
+						freezer: Address(MockPeaqAccount::David.into()),
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(team_changed_log(
+					7,
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::David,
+				))
+				.execute_returns(());
+
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+			assert_eq!(Assets::issuer(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+			assert_eq!(Assets::freezer(MockAssetId(7)), Some(MockPeaqAccount::David));
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 500);
+		});
+}
+
+#[test]
+fn create_with_team_rolls_back_create_when_set_team_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let create_cost =
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(7),
+					admin: MockPeaqAccount::Bob,
+					min_balance: 500,
+				}) + log_cost(&asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob));
+			let set_team_cost = dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+				id: MockAssetId(7),
+				issuer: MockPeaqAccount::Charlie,
+				admin: MockPeaqAccount::Bob,
+				freezer: MockPeaqAccount::David,
+			});
+			let team_changed_cost = log_cost(&team_changed_log(
+				7,
+				MockPeaqAccount::Charlie,
+				MockPeaqAccount::Bob,
+				MockPeaqAccount::David,
+			));
+
+			// Enough gas to create the asset, but not to set its team.
+			let transaction = precompiles();
+			transaction
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create_with_team {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+						issuer: Address(MockPeaqAccount::Charlie.into()),
+						freezer: Address(MockPeaqAccount::David.into()),
+					},
+				)
+				.with_target_gas(Some(team_changed_cost + create_cost + set_team_cost - 1))
+				.execute_reverts(|output| output == out_of_gas_reason(set_team_cost));
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 0);
+
+			transaction
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::last_error {},
+				)
+				.execute_returns(LastError {
+					code: LAST_ERROR_REVERT,
+					message: out_of_gas_reason(set_team_cost).into(),
+				});
+		});
+}
+
+fn launch_params(id: u64, issuer: MockPeaqAccount, initial_supply: u128) -> LaunchParams {
+	LaunchParams {
+		id,
+		admin: Address(MockPeaqAccount::Bob.into()),
+		min_balance: 500,
+		name: b"Seven".to_vec().into(),
+		symbol: b"SVN".to_vec().into(),
+		decimals: 18,
+		issuer: Address(issuer.into()),
+		freezer: Address(MockPeaqAccount::Charlie.into()),
+		initial_supply: initial_supply.into(),
+		initial_holder: Address(MockPeaqAccount::Charlie.into()),
+	}
+}
+
+#[test]
+fn safe_launch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params: launch_params(7, MockPeaqAccount::Alice, 1000) },
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(7, b"Seven".to_vec(), b"SVN".to_vec(), 18))
+				.expect_log(team_changed_log(
+					7,
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::Charlie,
+				))
+				.expect_log(minted_log(7, MockPeaqAccount::Charlie, 1000))
+				.execute_returns(());
+
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+			assert_eq!(Assets::issuer(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::freezer(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 500);
+			assert_eq!(Assets::name(MockAssetId(7)), b"Seven");
+			assert_eq!(Assets::symbol(MockAssetId(7)), b"SVN");
+			assert_eq!(Assets::decimals(MockAssetId(7)), 18);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 1000);
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 1000);
+		});
+}
+
+#[test]
+fn safe_launch_rolls_back_every_step_when_one_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// Bob is the issuer, so Alice cannot mint the initial supply.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params: launch_params(7, MockPeaqAccount::Bob, 1000) },
+				)
+				.execute_reverts(|output| {
+					output.starts_with(b"mint: Dispatched call failed with error: ")
+				});
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			assert!(Assets::name(MockAssetId(7)).is_empty());
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 0);
+
+			// Without an initial supply, the issuer does not have to be the caller.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params: launch_params(7, MockPeaqAccount::Bob, 0) },
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.expect_log(metadata_set_log(7, b"Seven".to_vec(), b"SVN".to_vec(), 18))
+				.expect_log(team_changed_log(
+					7,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::Charlie,
+				))
+				.execute_returns(());
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 0);
+		});
+}
+
+#[test]
+fn safe_launch_validates_its_parameters() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Charlie, 109)])
+		.build()
+		.execute_with(|| {
+			let mut params = launch_params(7, MockPeaqAccount::Alice, 0);
+			params.min_balance = 0;
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params },
+				)
+				.execute_reverts(|output| output == b"validate: min balance is zero");
+
+			let mut params = launch_params(7, MockPeaqAccount::Alice, 0);
+			params.admin = Address(H160::zero());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params },
+				)
+				.execute_reverts(|output| output == b"validate: admin is the zero address");
+
+			// Charlie can afford the deposit of the asset, but not the one of its metadata.
+			AssetDeposit::set(100);
+			MetadataDepositBase::set(10);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params: launch_params(7, MockPeaqAccount::Charlie, 0) },
+				)
+				.execute_reverts(|output| output == b"validate: insufficient balance for deposits");
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn create_self_admin() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create_self_admin { id: 7u64, min_balance: 500 },
+				)
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::create {
+						id: MockAssetId(7),
+						admin: MockPeaqAccount::Alice,
+						min_balance: 500,
+					}) + log_cost(&asset_created_log(
+						7,
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::Alice,
+					)),
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 500);
+		});
+}
+
+#[test]
+fn create_idempotent() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let create_idempotent = |admin: MockPeaqAccount, min_balance: u128| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create_idempotent {
+						id: 7u64,
+						admin: Address(admin.into()),
+						min_balance,
+					},
+				)
+			};
+
+			// The first call creates the asset.
+			create_idempotent(MockPeaqAccount::Bob, 500)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+
+			// Re-running it with the same parameters is a no-op.
+			create_idempotent(MockPeaqAccount::Bob, 500)
+				.expect_no_logs()
+				.execute_returns(());
+
+			create_idempotent(MockPeaqAccount::Charlie, 500)
+				.execute_reverts(|output| output == b"asset exists with different parameters");
+			create_idempotent(MockPeaqAccount::Bob, 400)
+				.execute_reverts(|output| output == b"asset exists with different parameters");
+
+			// Unlike create, which fails on an existing asset.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.execute_reverts(|output| output == b"id: asset already exists");
+		});
+}
+
+/// Call to `create` of `who`, as its own admin.
+fn create_as(who: MockPeaqAccount, id: u64) -> PCall {
+	PCall::create { id, admin: Address(who.into()), min_balance: 1 }
+}
+
+#[test]
+fn reserve_id_then_create() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			// Reserving it again does not renew the reservation.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 7u64 },
+				)
+				.execute_reverts(|output| output == b"id reservation cannot be renewed");
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Alice, 7),
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+
+			// The reservation is removed once the asset is created, which cannot be reserved
+			// anymore.
+			assert!(id_reservation::IdReservationOf::<Runtime, ()>::get(&(
+				instance_index::<Runtime, ()>(),
+				MockAssetId(7)
+			))
+			.is_none());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 7u64 },
+				)
+				.execute_reverts(|output| output == b"id: asset already exists");
+		});
+}
+
+#[test]
+fn reserved_id_cannot_be_created_by_another_account() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 7u64 },
+				)
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Bob, 7),
+				)
+				.execute_reverts(|output| output == b"id reserved by another account");
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 7u64 },
+				)
+				.execute_reverts(|output| output == b"id reserved by another account");
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+
+			// Other ids are not reserved.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Bob, 8),
+				)
+				.expect_log(asset_created_log(8, MockPeaqAccount::Bob, MockPeaqAccount::Bob))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn second_create_of_an_id_in_a_block_fails_without_side_effects() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			AssetDeposit::set(100);
+			MaxCreatesPerBlock::set(Some(2));
+			let create = |who: MockPeaqAccount, id: u64| {
+				precompiles.prepare_test(who, MockPeaqAccount::EVMu1Account, create_as(who, id))
+			};
+			let creates_remaining = |who: MockPeaqAccount, expected: u32| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::creates_remaining { account: Address(who.into()) },
+					)
+					.execute_returns(expected);
+			};
+
+			create(MockPeaqAccount::Alice, 7)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			create(MockPeaqAccount::Bob, 7)
+				.execute_reverts(|output| output == b"id: asset already exists");
+			create(MockPeaqAccount::Alice, 7)
+				.execute_reverts(|output| output == b"id: asset already exists");
+
+			// The first create stands, and the others noted nothing.
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 100);
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Bob), 0);
+			creates_remaining(MockPeaqAccount::Alice, 1);
+			creates_remaining(MockPeaqAccount::Bob, 2);
+
+			// An id reserved in the block can only be created once, by the account which
+			// reserved it.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 8u64 },
+				)
+				.execute_returns(());
+			create(MockPeaqAccount::Alice, 8)
+				.execute_reverts(|output| output == b"id reserved by another account");
+			create(MockPeaqAccount::Bob, 8)
+				.expect_log(asset_created_log(8, MockPeaqAccount::Bob, MockPeaqAccount::Bob))
+				.execute_returns(());
+			create(MockPeaqAccount::Alice, 8)
+				.execute_reverts(|output| output == b"id: asset already exists");
+			create(MockPeaqAccount::Bob, 8)
+				.execute_reverts(|output| output == b"id: asset already exists");
+
+			assert_eq!(Assets::owner(MockAssetId(8)), Some(MockPeaqAccount::Bob));
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Bob), 100);
+			creates_remaining(MockPeaqAccount::Alice, 1);
+			creates_remaining(MockPeaqAccount::Bob, 1);
+		});
+}
+
+#[test]
+fn id_reservation_expires() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 7u64 },
+				)
+				.execute_returns(());
+
+			// The reservation lasts until the end of its last block.
+			System::set_block_number(1 + IdReservationLifetime::get());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Bob, 7),
+				)
+				.execute_reverts(|output| output == b"id reserved by another account");
+
+			System::set_block_number(2 + IdReservationLifetime::get());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Bob, 7),
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Bob, MockPeaqAccount::Bob))
+				.execute_returns(());
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+		});
+}
+
+#[test]
+fn id_reservations_are_capped_and_not_renewed() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let reserve = |who: MockPeaqAccount, id: u64| {
+				precompiles.prepare_test(
+					who,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id },
+				)
+			};
+
+			// An account holds `MaxIdReservations` unexpired reservations at most.
+			assert_eq!(MaxIdReservations::get(), 2);
+			reserve(MockPeaqAccount::Alice, 7).expect_no_logs().execute_returns(());
+			reserve(MockPeaqAccount::Alice, 8).expect_no_logs().execute_returns(());
+			reserve(MockPeaqAccount::Alice, 9)
+				.execute_reverts(|output| output == b"too many id reservations");
+			reserve(MockPeaqAccount::Bob, 9).execute_returns(());
+
+			// Creating a reserved asset frees its slot.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Alice, 8),
+				)
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			reserve(MockPeaqAccount::Alice, 10).execute_returns(());
+			reserve(MockPeaqAccount::Alice, 11)
+				.execute_reverts(|output| output == b"too many id reservations");
+
+			// Once expired, the reservations free their slots but cannot be renewed, so that an
+			// account cannot hold an id for good.
+			System::set_block_number(2 + IdReservationLifetime::get());
+			reserve(MockPeaqAccount::Alice, 7)
+				.execute_reverts(|output| output == b"id reservation cannot be renewed");
+			reserve(MockPeaqAccount::Alice, 11).execute_returns(());
+			reserve(MockPeaqAccount::Alice, 12).execute_returns(());
+
+			// Another account can reserve the id, after which its first holder can again once
+			// that reservation expires.
+			reserve(MockPeaqAccount::Bob, 7).execute_returns(());
+			reserve(MockPeaqAccount::Alice, 7)
+				.execute_reverts(|output| output == b"id reserved by another account");
+			System::set_block_number(3 + 2 * IdReservationLifetime::get());
+			reserve(MockPeaqAccount::Alice, 7).execute_returns(());
+		});
+}
+
+#[test]
+fn set_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![1u8, 2u8, 3u8].into(),
+						symbol: vec![4u8, 5u8, 6u8].into(),
+						decimals: 18u8,
+					},
+				)
+				.expect_log(metadata_set_log(7, vec![1u8, 2u8, 3u8], vec![4u8, 5u8, 6u8], 18u8))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn set_metadata_rejects_oversized_input_before_copying_it() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			// Nothing is charged, not even the log the 64KB name would be part of.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![b'a'; 1 << 16].into(),
+						symbol: b"SVN".to_vec().into(),
+						decimals: 18,
+					},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_reverts(|output| output == b"name: exceeds the string limit of 50");
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: b"Seven".to_vec().into(),
+						symbol: vec![b'S'; 51].into(),
+						decimals: 18,
+					},
+				)
+				.expect_cost(0)
+				.execute_reverts(|output| output == b"symbol: exceeds the string limit of 50");
+
+			// Up to the limit, the metadata is set.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![b'a'; 50].into(),
+						symbol: b"SVN".to_vec().into(),
+						decimals: 18,
+					},
+				)
+				.execute_returns(());
+			assert_eq!(Assets::name(MockAssetId(7)), vec![b'a'; 50]);
+		});
+}
+
+#[test]
+fn set_metadata_defaults() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::default_decimals {},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns(DefaultDecimals::get());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata_defaults {
+						id: 7u64,
+						name: b"Peaq".to_vec().into(),
+						symbol: b"PQ".to_vec().into(),
+					},
+				)
+				.expect_log(metadata_set_log(
+					7,
+					b"Peaq".to_vec(),
+					b"PQ".to_vec(),
+					DefaultDecimals::get(),
+				))
+				.execute_returns(());
+
+			assert_eq!(Assets::decimals(MockAssetId(7)), DefaultDecimals::get());
+			assert_eq!(Assets::name(MockAssetId(7)), b"Peaq".to_vec());
+		});
+}
+
+#[test]
+fn rename_keeps_the_decimals() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			let rename = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::rename {
+						id: 7u64,
+						name: b"Krest".to_vec().into(),
+						symbol: b"KRST".to_vec().into(),
+					},
+				)
+			};
+
+			// Without metadata, there are no decimals to keep.
+			rename().execute_reverts(|output| output == b"asset has no metadata to rename");
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: b"Peaq".to_vec().into(),
+						symbol: b"PQ".to_vec().into(),
+						decimals: 6,
+					},
+				)
+				.execute_returns(());
+
+			rename()
+				.expect_log(metadata_set_log(7, b"Krest".to_vec(), b"KRST".to_vec(), 6))
+				.execute_returns(());
+			assert_eq!(Assets::name(MockAssetId(7)), b"Krest".to_vec());
+			assert_eq!(Assets::symbol(MockAssetId(7)), b"KRST".to_vec());
+			assert_eq!(Assets::decimals(MockAssetId(7)), 6);
+		});
+}
+
+#[test]
+fn copy_metadata_sets_the_metadata_of_the_source_on_the_destination() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			create_and_mint(8, MockPeaqAccount::Alice, 0);
+			create_and_mint(9, MockPeaqAccount::Bob, 0);
+			let copy_metadata = |caller: MockPeaqAccount, from: u64, to: u64| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::EVMu1Account,
+					PCall::copy_metadata { from, to },
+				)
+			};
+
+			copy_metadata(MockPeaqAccount::Alice, 7, 8)
+				.execute_reverts(|output| output == b"asset has no metadata to copy");
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: b"Peaq".to_vec().into(),
+						symbol: b"PQ".to_vec().into(),
+						decimals: 6,
+					},
+				)
+				.execute_returns(());
+
+			// The caller must own both assets.
+			copy_metadata(MockPeaqAccount::Alice, 7, 9)
+				.execute_reverts(|output| output == b"to: caller is not the asset owner");
+			copy_metadata(MockPeaqAccount::Bob, 7, 9)
+				.execute_reverts(|output| output == b"from: caller is not the asset owner");
+			copy_metadata(MockPeaqAccount::Alice, 7, 10)
+				.execute_reverts(|output| output == b"to: asset not found");
+
+			copy_metadata(MockPeaqAccount::Alice, 7, 8)
+				.expect_log(metadata_set_log(8, b"Peaq".to_vec(), b"PQ".to_vec(), 6))
+				.execute_returns(());
+			assert_eq!(Assets::name(MockAssetId(8)), Assets::name(MockAssetId(7)));
+			assert_eq!(Assets::symbol(MockAssetId(8)), Assets::symbol(MockAssetId(7)));
+			assert_eq!(Assets::decimals(MockAssetId(8)), Assets::decimals(MockAssetId(7)));
+			assert_eq!(Assets::name(MockAssetId(8)), b"Peaq".to_vec());
+		});
+}
+
+#[test]
+fn set_metadata_rejects_frozen_metadata() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_set_metadata {
+						id: 7u64,
+						name: vec![1u8, 2u8, 3u8].into(),
+						symbol: vec![4u8, 5u8, 6u8].into(),
+						decimals: 18u8,
+						is_frozen: true,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![7u8].into(),
+						symbol: vec![8u8].into(),
+						decimals: 6u8,
+					},
+				)
+				.execute_reverts(|output| output == b"metadata is frozen");
+			assert_eq!(Assets::decimals(MockAssetId(7)), 18);
+		});
+}
+
+#[test]
+fn set_min_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_min_balance { id: 7u64, min_balance: 1000 },
+				)
+				.expect_log(min_balance_changed_log(7, 1000))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn min_balance_floor() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			MinBalanceFloor::set(Some(100));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::min_balance_floor {},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns(100u128);
+
+			let create = |id: u64, min_balance: u128| PCall::create {
+				id,
+				admin: Address(MockPeaqAccount::Alice.into()),
+				min_balance,
+			};
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, create(7, 99))
+				.execute_reverts(|output| output == b"min_balance below floor");
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, create(7, 100))
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, create(8, 101))
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+
+			let set_min_balance =
+				|min_balance: u128| PCall::set_min_balance { id: 7u64, min_balance };
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_min_balance(99),
+				)
+				.execute_reverts(|output| output == b"min_balance below floor");
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 100);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_min_balance(150),
+				)
+				.expect_log(min_balance_changed_log(7, 150))
+				.execute_returns(());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_min_balance(100),
+				)
+				.expect_log(min_balance_changed_log(7, 100))
+				.execute_returns(());
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 100);
+		});
+}
+
+#[test]
+fn set_min_balance_rejects_values_too_large_for_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// The factory at address 3 is over the assets instance with `u64` balances.
+			let foreign_factory = H160::from_low_u64_be(3);
+			let min_balance = |id: u64| {
+				assets_storage::Asset::<Runtime, pallet_assets::Instance1>::get(MockAssetId(
+					id.into(),
+				))
+				.expect("asset exists")
+				.min_balance
+			};
+
+			assert_ok!(ForeignAssets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				1
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::set_min_balance { id: 7u64, min_balance: u64::MAX as u128 + 1 },
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+			assert_eq!(min_balance(7), 1);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::set_min_balance { id: 7u64, min_balance: u64::MAX as u128 },
+				)
+				.expect_log(log2(
+					foreign_factory,
+					SELECTOR_LOG_MIN_BALANCE_CHANGED,
+					H256::from_low_u64_be(7),
+					solidity::encode_event_data(u64::MAX as u128),
+				))
+				.execute_returns(());
+			assert_eq!(min_balance(7), u64::MAX);
+		});
+}
+
+#[test]
+fn create_paths_reject_min_balance_too_large_for_balance() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// The factory at address 3 is over the assets instance with `u64` balances.
+			let foreign_factory = H160::from_low_u64_be(3);
+			let too_large = u64::MAX as u128 + 1;
+			let precompiles = precompiles();
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: too_large,
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::create_idempotent {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: too_large,
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					foreign_factory,
+					ForeignPCall::force_create {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						is_sufficient: true,
+						min_balance: too_large,
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+			assert!(assets_storage::Asset::<Runtime, pallet_assets::Instance1>::get(MockAssetId(
+				7
+			))
+			.is_none());
+
+			// An existing asset is not matched against a saturated min balance either, and its
+			// status is not forced to one.
+			assert_ok!(ForeignAssets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				u64::MAX
+			));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::create_idempotent {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: too_large,
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					foreign_factory,
+					ForeignPCall::force_asset_status {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						issuer: Address(MockPeaqAccount::Alice.into()),
+						admin: Address(MockPeaqAccount::Alice.into()),
+						freezer: Address(MockPeaqAccount::Alice.into()),
+						min_balance: too_large,
+						is_sufficient: true,
+						is_frozen: false,
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalance: Value is too large for balance type"
+				});
+			let details =
+				assets_storage::Asset::<Runtime, pallet_assets::Instance1>::get(MockAssetId(7))
+					.expect("asset exists");
+			assert_eq!(details.min_balance, u64::MAX);
+			assert!(!details.is_sufficient);
+		});
+}
+
+#[test]
+fn set_min_balance_batch_rejects_length_mismatch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				1
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_min_balance_batch {
+						ids: vec![7u64].into(),
+						min_balances: vec![10u128, 20].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"minBalances: ids and minBalances lengths differ"
+				});
+
+			assert_eq!(Assets::minimum_balance(MockAssetId(7)), 1);
+		});
+}
+
+#[test]
+fn set_min_balance_batch_rolls_back_the_batch_on_an_overflow() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// The factory at address 3 is over the assets instance with `u64` balances.
+			let foreign_factory = H160::from_low_u64_be(3);
+			let min_balance = |id: u64| {
+				assets_storage::Asset::<Runtime, pallet_assets::Instance1>::get(MockAssetId(
+					id.into(),
+				))
+				.expect("asset exists")
+				.min_balance
+			};
+			for id in [7, 8, 9] {
+				assert_ok!(ForeignAssets::create(
+					RuntimeOrigin::signed(MockPeaqAccount::Alice),
+					MockAssetId(id),
+					MockPeaqAccount::Alice,
+					1
+				));
+			}
+
+			// The second value does not fit `u64`, so the first one is not set either.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::set_min_balance_batch {
+						ids: vec![7u64, 8, 9].into(),
+						min_balances: vec![10u128, u64::MAX as u128 + 1, 30].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"ids[1]: minBalance: Value is too large for balance type"
+				});
+			assert_eq!(min_balance(7), 1);
+			assert_eq!(min_balance(8), 1);
+			assert_eq!(min_balance(9), 1);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					foreign_factory,
+					ForeignPCall::set_min_balance_batch {
+						ids: vec![7u64, 8].into(),
+						min_balances: vec![10u128, 20].into(),
+					},
+				)
+				.expect_log(log2(
+					foreign_factory,
+					SELECTOR_LOG_MIN_BALANCE_CHANGED,
+					H256::from_low_u64_be(7),
+					solidity::encode_event_data(10u128),
+				))
+				.expect_log(log2(
+					foreign_factory,
+					SELECTOR_LOG_MIN_BALANCE_CHANGED,
+					H256::from_low_u64_be(8),
+					solidity::encode_event_data(20u128),
+				))
+				.execute_returns(());
+			assert_eq!(min_balance(7), 10);
+			assert_eq!(min_balance(8), 20);
+		});
+}
+
+#[test]
+fn set_team() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_team {
+						id: 7u64,
+						issuer: Address(MockPeaqAccount::Charlie.into()),
+						admin: Address(MockPeaqAccount::Charlie.into()),
+						freezer: Address(MockPeaqAccount::Charlie.into()),
+					},
+				)
+				.expect_log(team_changed_log(
+					7,
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::Charlie,
+				))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn set_team_with_the_current_team_is_a_no_op() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+			System::reset_events();
+
+			let set_team = |caller: MockPeaqAccount| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_team {
+						id: 7u64,
+						issuer: Address(MockPeaqAccount::Bob.into()),
+						admin: Address(MockPeaqAccount::Bob.into()),
+						freezer: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+			};
+
+			// Nothing is dispatched, so no gas is charged and no event is deposited.
+			set_team(MockPeaqAccount::Alice)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(assets_events(), vec![]);
+
+			// The caller must still be allowed to set the team.
+			set_team(MockPeaqAccount::Bob).execute_reverts(|output| {
+				output.starts_with(b"Dispatched call failed with error: ")
+			});
+			assert_eq!(assets_events(), vec![]);
+		});
+}
+
+#[test]
+fn set_team_roles_must_be_distinct_when_configured() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			let roles_must_be_distinct = |expected: bool| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::roles_must_be_distinct {},
+					)
+					.expect_cost(0)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+			let set_team = |issuer: MockPeaqAccount, freezer: MockPeaqAccount| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_team {
+						id: 7u64,
+						issuer: Address(issuer.into()),
+						admin: Address(MockPeaqAccount::Bob.into()),
+						freezer: Address(freezer.into()),
+					},
+				)
+			};
+
+			// Duplicate roles are allowed by default.
+			roles_must_be_distinct(false);
+			set_team(MockPeaqAccount::Charlie, MockPeaqAccount::Charlie).execute_returns(());
+			assert_eq!(Assets::freezer(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+
+			RolesMustBeDistinct::set(true);
+			roles_must_be_distinct(true);
+			set_team(MockPeaqAccount::David, MockPeaqAccount::David)
+				.execute_reverts(|output| output == b"roles must be distinct");
+			set_team(MockPeaqAccount::Bob, MockPeaqAccount::David)
+				.execute_reverts(|output| output == b"roles must be distinct");
+			assert_eq!(Assets::issuer(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+
+			set_team(MockPeaqAccount::Charlie, MockPeaqAccount::David).execute_returns(());
+			assert_eq!(Assets::issuer(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+			assert_eq!(Assets::freezer(MockAssetId(7)), Some(MockPeaqAccount::David));
+		});
+}
+
+#[test]
+fn transfer_ownership() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn owner_of_follows_the_transfers_of_ownership() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let owner_of = |id: u64| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					PCall::owner_of { id },
+				)
+			};
+			let transfer_ownership = |from: MockPeaqAccount, to: MockPeaqAccount| {
+				precompiles
+					.prepare_test(
+						from,
+						MockPeaqAccount::EVMu1Account,
+						PCall::transfer_ownership { id: 7u64, owner: Address(to.into()) },
+					)
+					.execute_returns(())
+			};
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			owner_of(7).expect_no_logs().execute_returns((
+				Address(MockPeaqAccount::Alice.into()),
+				H256(MockPeaqAccount::Alice.into()),
+			));
+
+			transfer_ownership(MockPeaqAccount::Alice, MockPeaqAccount::Bob);
+			owner_of(7).execute_returns((
+				Address(MockPeaqAccount::Bob.into()),
+				H256(MockPeaqAccount::Bob.into()),
+			));
+
+			// The account of David is not mapped back to an address by the mock.
+			transfer_ownership(MockPeaqAccount::Bob, MockPeaqAccount::David);
+			owner_of(7)
+				.execute_returns((Address(H160::zero()), H256(MockPeaqAccount::David.into())));
+
+			owner_of(8).execute_reverts(|output| output == b"id: asset not found");
+		});
+}
+
+#[test]
+fn transfer_ownership_to_contract() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			// Charlie is a contract.
+			pallet_evm::AccountCodes::<Runtime>::insert(
+				H160::from(MockPeaqAccount::Charlie),
+				vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0xfd],
+			);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership_to_contract {
+						id: 7u64,
+						contract_addr: Address(MockPeaqAccount::Charlie.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+		});
+}
+
+#[test]
+fn transfer_ownership_to_contract_rejects_eoa() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership_to_contract {
+						id: 7u64,
+						contract_addr: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.execute_reverts(|output| output == b"target is not a contract");
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+		});
+}
+
+#[test]
+fn transfer_ownership_and_set_team() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership_and_set_team {
+						id: 7u64,
+						new_owner: Address(MockPeaqAccount::Bob.into()),
+						issuer: Address(MockPeaqAccount::Charlie.into()),
+						admin: Address(MockPeaqAccount::Bob.into()),
+						freezer: Address(MockPeaqAccount::David.into()),
+					},
+				)
+				.expect_log(team_changed_log(
+					7,
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::David,
+				))
+				.execute_returns(());
+
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+			assert_eq!(Assets::issuer(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+			assert_eq!(Assets::freezer(MockAssetId(7)), Some(MockPeaqAccount::David));
+
+			// Alice is not the owner anymore.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership_and_set_team {
+						id: 7u64,
+						new_owner: Address(MockPeaqAccount::Alice.into()),
+						issuer: Address(MockPeaqAccount::Alice.into()),
+						admin: Address(MockPeaqAccount::Alice.into()),
+						freezer: Address(MockPeaqAccount::Alice.into()),
+					},
+				)
+				.execute_reverts(|output| {
+					output.starts_with(b"Dispatched call failed with error: ")
+				});
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Bob));
+		});
+}
+
+#[test]
+fn transfer_ownership_and_set_team_rolls_back_the_team_when_the_transfer_fails() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			let set_team_cost = dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+				id: MockAssetId(7),
+				issuer: MockPeaqAccount::Charlie,
+				admin: MockPeaqAccount::Bob,
+				freezer: MockPeaqAccount::David,
+			});
+			let transfer_cost = dispatch_cost(pallet_assets::Call::<Runtime>::transfer_ownership {
+				id: MockAssetId(7),
+				owner: MockPeaqAccount::Bob,
+			});
+			let team_changed_cost = log_cost(&team_changed_log(
+				7,
+				MockPeaqAccount::Charlie,
+				MockPeaqAccount::Bob,
+				MockPeaqAccount::David,
+			));
+
+			// Enough gas to set the team, but not to transfer the ownership.
+			let transaction = precompiles();
+			transaction
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership_and_set_team {
+						id: 7u64,
+						new_owner: Address(MockPeaqAccount::Bob.into()),
+						issuer: Address(MockPeaqAccount::Charlie.into()),
+						admin: Address(MockPeaqAccount::Bob.into()),
+						freezer: Address(MockPeaqAccount::David.into()),
+					},
+				)
+				.with_target_gas(Some(team_changed_cost + set_team_cost + transfer_cost - 1))
+				.execute_reverts(|output| output == out_of_gas_reason(transfer_cost));
+
+			assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::issuer(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::admin(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+			assert_eq!(Assets::freezer(MockAssetId(7)), Some(MockPeaqAccount::Alice));
+
+			transaction
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::last_error {},
+				)
+				.execute_returns(LastError {
+					code: LAST_ERROR_REVERT,
+					message: out_of_gas_reason(transfer_cost).into(),
+				});
+		});
+}
+
+#[test]
+fn start_destroy() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(destroy_token(7, 1));
+		});
+}
+
+#[test]
+fn end_destroy() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(destroy_token(7, 1));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 1) },
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn asset_count_tracks_creates_and_destroys() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let asset_count = |expected: u32| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::asset_count {},
+					)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+			asset_count(0);
+
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			asset_count(1);
+			start_destroying_asset_with_accounts(8);
+			asset_count(2);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_create {
+						id: 9u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						is_sufficient: true,
+						min_balance: 1,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			asset_count(3);
+
+			// A failing create is not counted.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 1,
+					},
+				)
+				.execute_reverts(|output| output == b"id: asset already exists");
+			asset_count(3);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 8u64,
+						token: destroy_token(8, 1),
+						max_iterations: 10,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+			asset_count(2);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 9u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(destroy_token(9, 1));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 9u64, token: destroy_token(9, 1) },
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			asset_count(1);
+		});
+}
+
+#[test]
+fn asset_count_ignores_assets_created_outside_of_the_factory() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let asset_count = |expected: u32| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::asset_count {},
+					)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+			let destroy = |id: u64| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::start_destroy { id },
+					)
+					.execute_returns(destroy_token(id, 1));
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::finish_destroy { id, token: destroy_token(id, 1) },
+					)
+					.expect_no_logs()
+					.execute_returns(());
+			};
+
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			assert_ok!(Assets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(8),
+				MockPeaqAccount::Alice,
+				1
+			));
+			asset_count(1);
+
+			// The asset created through the pallet was never counted, so destroying it through
+			// the factory leaves the count as it is.
+			destroy(8);
+			asset_count(1);
+
+			destroy(7);
+			asset_count(0);
+		});
+}
+
+#[test]
+fn finish_destroy_requires_confirmation_token() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			create_and_mint(8, MockPeaqAccount::Alice, 0);
+
+			let start_destroy = |id: u64| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id },
+				)
+			};
+			let finish_destroy = |id: u64, token: H256| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id, token },
+				)
+			};
+
+			start_destroy(7).execute_returns(destroy_token(7, 1));
+			start_destroy(8).execute_returns(destroy_token(8, 1));
+
+			// Neither an arbitrary token nor the token of another destruction is accepted.
+			finish_destroy(7, H256::repeat_byte(0x11))
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			finish_destroy(7, destroy_token(8, 1))
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_some());
+
+			// The token can still be used at the last block of its lifetime.
+			System::set_block_number(1 + DestroyTokenLifetime::get());
+			finish_destroy(7, destroy_token(7, 1)).execute_returns(());
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			finish_destroy(7, destroy_token(7, 1))
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+
+			// But not after.
+			System::set_block_number(2 + DestroyTokenLifetime::get());
+			finish_destroy(8, destroy_token(8, 1))
+				.execute_reverts(|output| output == b"token: destroy confirmation token expired");
+			assert!(Assets::maybe_total_supply(MockAssetId(8)).is_some());
+		});
+}
+
+#[test]
+fn finish_destroy_by_initiator_when_required() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			OnlyInitiatorFinishesDestroy::set(true);
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+				.execute_returns(destroy_token(7, 1));
+			let finish_destroy = |who: MockPeaqAccount| {
+				precompiles.prepare_test(
+					who,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 1) },
+				)
+			};
+
+			finish_destroy(MockPeaqAccount::Bob)
+				.execute_reverts(|output| output == b"destroy must be finished by initiator");
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 7u64,
+						token: destroy_token(7, 1),
+						max_iterations: 1,
+					},
+				)
+				.execute_reverts(|output| output == b"destroy must be finished by initiator");
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_some());
+
+			finish_destroy(MockPeaqAccount::Alice).execute_returns(());
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn finish_destroy_by_anyone_by_default() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+				.execute_returns(destroy_token(7, 1));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 1) },
+				)
+				.execute_returns(());
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn start_destroy_renews_expired_confirmation_token() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			let start_destroy = |who: MockPeaqAccount| {
+				precompiles.prepare_test(
+					who,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+			};
+
+			start_destroy(MockPeaqAccount::Alice).execute_returns(destroy_token(7, 1));
+
+			System::set_block_number(20);
+			// Only the owner can get a new token.
+			start_destroy(MockPeaqAccount::Bob).execute_reverts(|output| {
+				output.starts_with(b"Dispatched call failed with error: ")
+			});
+			start_destroy(MockPeaqAccount::Alice).execute_returns(destroy_token(7, 20));
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 1) },
+				)
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 20) },
+				)
+				.execute_returns(());
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn destroy_started_at() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			let destroy_started_at = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::destroy_started_at { id: 7u64 },
+				)
+			};
+			let start_destroy = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+			};
+
+			destroy_started_at().expect_no_logs().execute_returns(0u64);
+
+			System::set_block_number(5);
+			start_destroy().execute_returns(destroy_token(7, 5));
+			destroy_started_at().execute_returns(5u64);
+
+			// Renewing the token does not restart the destruction.
+			System::set_block_number(30);
+			start_destroy().execute_returns(destroy_token(7, 30));
+			destroy_started_at().execute_returns(5u64);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 30) },
+				)
+				.execute_returns(());
+			destroy_started_at().execute_returns(0u64);
+
+			// A destruction started outside of the factory has no recorded start.
+			create_and_mint(8, MockPeaqAccount::Alice, 0);
+			assert_ok!(Assets::start_destroy(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(8)
+			));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::destroy_started_at { id: 8u64 },
+				)
+				.execute_returns(0u64);
+		});
+}
+
+/// Creates an asset held by 6 accounts, with an approval, and starts destroying it.
+/// Starts the destruction of an asset with accounts and an approval through the factory, the
+/// confirmation token of which is `destroy_token(id, 1)`.
+fn start_destroying_asset_with_accounts(id: u64) {
+	let holders = [
+		MockPeaqAccount::Alice,
+		MockPeaqAccount::Bob,
+		MockPeaqAccount::Charlie,
+		MockPeaqAccount::David,
+		MockPeaqAccount::EVMu1Account,
+		MockPeaqAccount::EVMu2Account,
+	];
+
+	create_and_mint(id, MockPeaqAccount::Alice, 1000);
+	for holder in holders.into_iter().skip(1) {
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(MockPeaqAccount::Alice),
+			MockAssetId(id.into()),
+			holder,
+			100
+		));
+	}
+	assert_ok!(Assets::approve_transfer(
+		RuntimeOrigin::signed(MockPeaqAccount::Alice),
+		MockAssetId(id.into()),
+		MockPeaqAccount::Bob,
+		50
+	));
+	precompiles()
+		.prepare_test(
+			MockPeaqAccount::Alice,
+			MockPeaqAccount::EVMu1Account,
+			PCall::start_destroy { id },
+		)
+		.execute_returns(destroy_token(id, 1));
+}
+
+fn destroy_balances() -> Vec<(MockPeaqAccount, u128)> {
+	vec![
+		(MockPeaqAccount::Alice, 5000),
+		(MockPeaqAccount::Bob, 5000),
+		(MockPeaqAccount::Charlie, 5000),
+		(MockPeaqAccount::David, 5000),
+		(MockPeaqAccount::EVMu1Account, 5000),
+		(MockPeaqAccount::EVMu2Account, 5000),
+	]
+}
+
+#[test]
+fn force_finish_destroy_in_one_call() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			start_destroying_asset_with_accounts(7);
+
+			// 3 calls to destroy the accounts, 1 for the approval and 1 to finish.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 7u64,
+						token: destroy_token(7, 1),
+						max_iterations: 10,
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn force_finish_destroy_in_several_calls() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			start_destroying_asset_with_accounts(7);
+
+			let force_finish_destroy = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 7u64,
+						token: destroy_token(7, 1),
+						max_iterations: 2,
+					},
+				)
+			};
+
+			force_finish_destroy().expect_no_logs().execute_returns(false);
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_some());
+			force_finish_destroy().expect_no_logs().execute_returns(false);
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_some());
+			force_finish_destroy().expect_no_logs().execute_returns(true);
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+
+			// The token is removed along with the asset.
+			force_finish_destroy()
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+		});
+}
+
+#[test]
+fn force_finish_destroy_requires_destruction_to_be_started() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			// No token is issued until the destruction is started.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 7u64,
+						token: destroy_token(7, 1),
+						max_iterations: 10,
+					},
+				)
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 1000);
+		});
+}
+
+#[test]
+fn force_finish_destroy_requires_force_origin_and_token() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			start_destroying_asset_with_accounts(7);
+
+			let force_finish_destroy = |caller: MockPeaqAccount, token: H256| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy { id: 7u64, token, max_iterations: 10 },
+				)
+			};
+
+			// Not even the owner, which started the destruction, can force it.
+			force_finish_destroy(MockPeaqAccount::Alice, destroy_token(7, 1))
+				.execute_reverts(|output| output == b"caller is not allowed to force");
+			force_finish_destroy(MockPeaqAccount::David, H256::zero())
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			force_finish_destroy(MockPeaqAccount::David, destroy_token(7, 2))
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 100);
+
+			force_finish_destroy(MockPeaqAccount::David, destroy_token(7, 1))
+				.expect_no_logs()
+				.execute_returns(true);
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+		});
+}
+
+#[test]
+fn permissions_depend_on_the_role_of_the_caller() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let permissions = |id: u64, caller: MockPeaqAccount, expected: u8| {
+				precompiles()
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::permissions { id, caller: Address(caller.into()) },
+					)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+
+			let origin = RuntimeOrigin::signed(MockPeaqAccount::Alice);
+			assert_ok!(Assets::create(origin.clone(), MockAssetId(7), MockPeaqAccount::Bob, 1));
+			assert_ok!(Assets::set_team(
+				origin,
+				MockAssetId(7),
+				MockPeaqAccount::Charlie,
+				MockPeaqAccount::Bob,
+				MockPeaqAccount::Bob,
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Charlie),
+				MockAssetId(7),
+				MockPeaqAccount::David,
+				100,
+			));
+
+			permissions(7, MockPeaqAccount::Alice, PERMISSION_SET_METADATA | PERMISSION_DESTROY);
+			permissions(7, MockPeaqAccount::Bob, PERMISSION_BURN);
+			permissions(7, MockPeaqAccount::Charlie, PERMISSION_MINT);
+			permissions(7, MockPeaqAccount::David, PERMISSION_TRANSFER);
+			permissions(7, MockPeaqAccount::Bogus, 0);
+
+			// Only the destruction is still allowed once the asset is frozen.
+			assert_ok!(Assets::freeze_asset(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(7)
+			));
+			permissions(7, MockPeaqAccount::Alice, PERMISSION_DESTROY);
+			permissions(7, MockPeaqAccount::Bob, 0);
+			permissions(7, MockPeaqAccount::Charlie, 0);
+			permissions(7, MockPeaqAccount::David, 0);
+
+			// Anyone can create an asset which does not exist, except the native one.
+			permissions(8, MockPeaqAccount::Bogus, PERMISSION_CREATE);
+			permissions(0, MockPeaqAccount::Bogus, 0);
+		});
+}
+
+#[test]
+fn block_and_is_blocked() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				1000
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(7),
+				MockPeaqAccount::Charlie,
+				1000
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::is_blocked { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+
+			// Bob is the freezer of the asset.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::block { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+				)
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::is_blocked { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+				)
+				.expect_no_logs()
+				.execute_returns(true);
+
+			// A nonexistent account is never blocked.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::is_blocked { id: 7u64, who: Address(MockPeaqAccount::David.into()) },
+				)
+				.expect_no_logs()
+				.execute_returns(false);
+
+			// Transfers from and to a blocked account fail.
+			assert!(Assets::transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Charlie),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				100
+			)
+			.is_err());
+			assert!(Assets::transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(7),
+				MockPeaqAccount::Charlie,
+				100
+			)
+			.is_err());
+		});
+}
+
+/// Events deposited by the `pallet_assets` instance used by the factory.
+fn assets_events() -> Vec<pallet_assets::Event<Runtime>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::Assets(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn freezing_emits_the_same_pallet_events_as_extrinsics() {
+	let ext = || {
+		let mut ext = ExtBuilder::default()
+			.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Charlie, 5000)])
+			.build();
+		ext.execute_with(|| {
+			// Alice is the admin and freezer of the asset.
+			assert_ok!(Assets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				1
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Charlie,
+				1000
+			));
+			System::reset_events();
+		});
+		ext
+	};
+
+	let via_precompile = ext().execute_with(|| {
+		for call in [
+			PCall::freeze { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+			PCall::thaw { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+			PCall::freeze_asset { id: 7u64 },
+			PCall::thaw_asset { id: 7u64 },
+			PCall::block { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+		] {
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, call)
+				.expect_no_logs()
+				.execute_returns(());
+		}
+
+		assets_events()
+	});
+
+	let via_extrinsics = ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(MockPeaqAccount::Alice);
+		assert_ok!(Assets::freeze(origin.clone(), MockAssetId(7), MockPeaqAccount::Charlie));
+		assert_ok!(Assets::thaw(origin.clone(), MockAssetId(7), MockPeaqAccount::Charlie));
+		assert_ok!(Assets::freeze_asset(origin.clone(), MockAssetId(7)));
+		assert_ok!(Assets::thaw_asset(origin.clone(), MockAssetId(7)));
+		assert_ok!(Assets::block(origin, MockAssetId(7), MockPeaqAccount::Charlie));
+
+		assets_events()
+	});
+
+	assert_eq!(
+		via_precompile,
+		vec![
+			pallet_assets::Event::Frozen {
+				asset_id: MockAssetId(7),
+				who: MockPeaqAccount::Charlie
+			},
+			pallet_assets::Event::Thawed {
+				asset_id: MockAssetId(7),
+				who: MockPeaqAccount::Charlie
+			},
+			pallet_assets::Event::AssetFrozen { asset_id: MockAssetId(7) },
+			pallet_assets::Event::AssetThawed { asset_id: MockAssetId(7) },
+			pallet_assets::Event::Blocked {
+				asset_id: MockAssetId(7),
+				who: MockPeaqAccount::Charlie
+			},
+		]
+	);
+	assert_eq!(via_precompile, via_extrinsics);
+}
+
+#[test]
+fn paused_asset_blocks_the_moves_of_its_balances() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			create_and_mint(8, MockPeaqAccount::Alice, 1000);
+			let set_asset_paused = |caller: MockPeaqAccount, paused: bool| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_asset_paused { id: 7u64, paused },
+				)
+			};
+			let asset_paused = |id: u64, expected: bool| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Bob,
+						MockPeaqAccount::EVMu1Account,
+						PCall::asset_paused { id },
+					)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+
+			// Only the owner pauses the asset.
+			set_asset_paused(MockPeaqAccount::Bob, true)
+				.execute_reverts(|output| output == b"caller is not the asset owner");
+			set_asset_paused(MockPeaqAccount::Alice, true)
+				.expect_no_logs()
+				.execute_returns(());
+			asset_paused(7, true);
+			asset_paused(8, false);
+
+			let alice = Address(MockPeaqAccount::Alice.into());
+			let bob = Address(MockPeaqAccount::Bob.into());
+			let moves = |id: u64| {
+				[
+					PCall::mint { id, beneficiary: bob, amount: 10.into() },
+					PCall::burn { id, who: alice, amount: 10.into() },
+					batch_mint(id, vec![MockPeaqAccount::Bob], vec![10]),
+					PCall::transfer_and_touch { id, to: bob, amount: 10.into() },
+					PCall::batch_transfer {
+						id,
+						recipients: vec![bob].into(),
+						amounts: vec![U256::from(10)].into(),
+					},
+					PCall::transfer_approved {
+						id,
+						owner: bob,
+						destination: alice,
+						amount: 10.into(),
+					},
+				]
+			};
+			for call in moves(7) {
+				precompiles
+					.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, call)
+					.execute_reverts(|output| output == b"asset paused");
+			}
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 1000);
+
+			// The reads, and the other operations of its owner, are not affected.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::permissions { id: 7u64, caller: alice },
+				)
+				.execute_returns(PERMISSION_SET_METADATA | PERMISSION_DESTROY);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_min_balance { id: 7u64, min_balance: 2 },
+				)
+				.execute_returns(());
+
+			// Nor are the other assets.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::mint { id: 8u64, beneficiary: bob, amount: 10.into() },
+				)
+				.execute_returns(());
+
+			set_asset_paused(MockPeaqAccount::Alice, false).execute_returns(());
+			asset_paused(7, false);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::mint { id: 7u64, beneficiary: bob, amount: 10.into() },
+				)
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 10);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_asset_paused { id: 9u64, paused: true },
+				)
+				.execute_reverts(|output| output == b"id: asset not found");
+		});
+}
+
+#[test]
+fn create_and_set_metadata_emit_the_same_pallet_events_as_extrinsics() {
+	let ext = || {
+		ExtBuilder::default()
+			.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+			.build()
+	};
+
+	let via_precompile = ext().execute_with(|| {
+		for call in [
+			PCall::create {
+				id: 7u64,
+				admin: Address(MockPeaqAccount::Bob.into()),
+				min_balance: 500,
+			},
+			PCall::set_metadata {
+				id: 7u64,
+				name: b"Seven".to_vec().into(),
+				symbol: b"SVN".to_vec().into(),
+				decimals: 18,
+			},
+		] {
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, call)
+				.execute_returns(());
+		}
+
+		assets_events()
+	});
+
+	let via_extrinsics = ext().execute_with(|| {
+		let origin = RuntimeOrigin::signed(MockPeaqAccount::Alice);
+		assert_ok!(Assets::create(origin.clone(), MockAssetId(7), MockPeaqAccount::Bob, 500));
+		assert_ok!(Assets::set_metadata(
+			origin,
+			MockAssetId(7),
+			b"Seven".to_vec(),
+			b"SVN".to_vec(),
+			18
+		));
+
+		assets_events()
+	});
+
+	// The caller of the precompile is the creator, as the signer of the extrinsic.
+	assert_eq!(
+		via_precompile,
+		vec![
+			pallet_assets::Event::Created {
+				asset_id: MockAssetId(7),
+				creator: MockPeaqAccount::Alice,
+				owner: MockPeaqAccount::Bob
+			},
+			pallet_assets::Event::MetadataSet {
+				asset_id: MockAssetId(7),
+				name: b"Seven".to_vec(),
+				symbol: b"SVN".to_vec(),
+				decimals: 18,
+				is_frozen: false
+			},
+		]
+	);
+	assert_eq!(via_precompile, via_extrinsics);
+}
+
+fn mint(id: u64, amount: u128) -> PCall {
+	PCall::mint { id, beneficiary: Address(MockPeaqAccount::Bob.into()), amount: amount.into() }
+}
+
+fn burn(id: u64, who: MockPeaqAccount, amount: u128) -> PCall {
+	PCall::burn { id, who: Address(who.into()), amount: amount.into() }
+}
+
+#[test]
+fn burn_from_account() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				500
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					burn(7, MockPeaqAccount::Bob, 200),
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::burn {
+					id: MockAssetId(7),
+					who: MockPeaqAccount::Bob,
+					amount: 200,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 300);
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 300);
+		});
+}
+
+#[test]
+fn burn_from_frozen_account_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				500
+			));
+			assert_ok!(Assets::freeze(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					burn(7, MockPeaqAccount::Bob, 200),
+				)
+				.execute_reverts(|output| output == b"account frozen");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 500);
+
+			// Blocked accounts are frozen as well.
+			assert_ok!(Assets::block(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob
+			));
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					burn(7, MockPeaqAccount::Bob, 200),
+				)
+				.execute_reverts(|output| output == b"account frozen");
+		});
+}
+
+#[test]
+fn burn_from_frozen_asset_reverts() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				500
+			));
+			assert_ok!(Assets::freeze_asset(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7)
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					burn(7, MockPeaqAccount::Bob, 200),
+				)
+				.execute_reverts(|output| output == b"asset frozen");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 500);
+
+			assert_ok!(Assets::thaw_asset(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7)
+			));
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					burn(7, MockPeaqAccount::Bob, 200),
+				)
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 300);
+		});
+}
+
+fn set_supply_cap(id: u64, cap: u128) -> PCall {
+	PCall::set_supply_cap { id, cap: cap.into() }
+}
+
+#[test]
+fn mint_is_bounded_by_supply_cap() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::supply_cap { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(U256::zero());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 1000),
+				)
+				.expect_log(supply_cap_changed_log(7, 1000))
+				.execute_returns(());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::supply_cap { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(1000));
+
+			// Up to the cap.
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint(7, 600))
+				.expect_log(minted_log(7, MockPeaqAccount::Bob, 600))
+				.execute_returns(());
+			// At the cap.
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint(7, 400))
+				.expect_log(minted_log(7, MockPeaqAccount::Bob, 400))
+				.execute_returns(());
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 1000);
+
+			// Over the cap.
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint(7, 1))
+				.execute_reverts(|output| output == b"supply cap exceeded");
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 1000);
+
+			// Mints through the factory are unbounded once the cap is removed.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 0),
+				)
+				.expect_log(supply_cap_changed_log(7, 0))
+				.execute_returns(());
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint(7, 1))
+				.expect_log(minted_log(7, MockPeaqAccount::Bob, 1))
+				.execute_returns(());
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 1001);
+		});
+}
+
+fn batch_mint(id: u64, recipients: Vec<MockPeaqAccount>, amounts: Vec<u128>) -> PCall {
+	PCall::batch_mint {
+		id,
+		recipients: recipients
+			.into_iter()
+			.map(|who| Address(who.into()))
+			.collect::<Vec<_>>()
+			.into(),
+		amounts: amounts.into_iter().map(U256::from).collect::<Vec<_>>().into(),
+	}
+}
+
+#[test]
+fn batch_mint() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 1000),
+				)
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					batch_mint(
+						7,
+						vec![MockPeaqAccount::Bob, MockPeaqAccount::David],
+						vec![600, 400],
+					),
+				)
+				.expect_log(minted_log(7, MockPeaqAccount::Bob, 600))
+				.expect_log(minted_log(7, MockPeaqAccount::David, 400))
+				.execute_returns(());
+
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 600);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::David), 400);
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 1000);
+		});
+}
+
+#[test]
+fn batch_mint_without_events() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			let mints_cost = dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+				id: MockAssetId(7),
+				beneficiary: MockPeaqAccount::Bob,
+				amount: 600,
+			}) + dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+				id: MockAssetId(7),
+				beneficiary: MockPeaqAccount::David,
+				amount: 400,
+			});
+			let logs_cost = log_cost(&minted_log(7, MockPeaqAccount::Bob, 600)) +
+				log_cost(&minted_log(7, MockPeaqAccount::David, 400));
+			let mint = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					batch_mint(
+						7,
+						vec![MockPeaqAccount::Bob, MockPeaqAccount::David],
+						vec![600, 400],
+					),
+				)
+			};
+
+			mint()
+				.expect_cost(mints_cost + logs_cost)
+				.expect_log(minted_log(7, MockPeaqAccount::Bob, 600))
+				.expect_log(minted_log(7, MockPeaqAccount::David, 400))
+				.execute_returns(());
+
+			// When the runtime opts out of the batch events, neither the logs nor their cost are
+			// recorded.
+			assert!(logs_cost > 0);
+			EmitBatchEvents::set(false);
+			mint().expect_cost(mints_cost).expect_no_logs().execute_returns(());
+
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 1200);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::David), 800);
+		});
+}
+
+#[test]
+fn batch_mint_checks_total_against_supply_cap() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 600);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 1000),
+				)
+				.execute_returns(());
+
+			// Each amount fits under the cap, but not their total.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					batch_mint(
+						7,
+						vec![MockPeaqAccount::Bob, MockPeaqAccount::David],
+						vec![300, 200],
+					),
+				)
+				.expect_no_logs()
+				.execute_reverts(|output| output == b"supply cap exceeded");
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 600);
+		});
+}
+
+#[test]
+fn batch_mint_rejects_length_mismatch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					batch_mint(7, vec![MockPeaqAccount::Bob], vec![100, 250]),
+				)
+				.expect_no_logs()
+				.execute_reverts(|output| {
+					output == b"amounts: recipients and amounts lengths differ"
+				});
+			assert_eq!(Assets::total_supply(MockAssetId(7)), 0);
+		});
+}
+
+#[test]
+fn set_transfer_fee_bps() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 0);
+
+			let transfer_fee_bps = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_fee_bps { id: 7u64 },
+				)
+			};
+			let set_transfer_fee_bps = |caller: MockPeaqAccount, bps: u16| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_transfer_fee_bps { id: 7u64, bps },
+				)
+			};
+			let stored_fee = || {
+				transfer_fee::TransferFeeOf::<Runtime, ()>::get(&(
+					<Assets as PalletInfoAccess>::index() as u8,
+					MockAssetId(7),
+				))
+			};
+
+			transfer_fee_bps().expect_no_logs().execute_returns(0u16);
+
+			set_transfer_fee_bps(MockPeaqAccount::Alice, 250)
+				.expect_no_logs()
+				.execute_returns(());
+			transfer_fee_bps().expect_no_logs().execute_returns(250u16);
+			assert_eq!(
+				stored_fee(),
+				Some(transfer_fee::TransferFee { bps: 250, treasury: TransferFeeTreasury::get() })
+			);
+
+			// Up to the maximum of the runtime.
+			set_transfer_fee_bps(MockPeaqAccount::Alice, 501)
+				.execute_reverts(|output| output == b"bps: transfer fee above maximum");
+			set_transfer_fee_bps(MockPeaqAccount::Alice, 500).execute_returns(());
+			transfer_fee_bps().execute_returns(500u16);
+
+			// Only the owner sets the fee.
+			set_transfer_fee_bps(MockPeaqAccount::Bob, 100)
+				.execute_reverts(|output| output == b"caller is not the asset owner");
+
+			// A zero fee removes it.
+			set_transfer_fee_bps(MockPeaqAccount::Alice, 0)
+				.expect_no_logs()
+				.execute_returns(());
+			transfer_fee_bps().execute_returns(0u16);
+			assert_eq!(stored_fee(), None);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_transfer_fee_bps { id: 8u64, bps: 100 },
+				)
+				.execute_reverts(|output| output == b"id: asset not found");
+		});
+}
+
+#[test]
+fn extended_config() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let extended_config = |id: u64| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::extended_config { id },
+				)
+			};
+
+			// Nothing is kept about an asset which does not exist.
+			extended_config(7).expect_no_logs().execute_returns(ExtendedConfig::default());
+
+			create_and_mint(7, MockPeaqAccount::Alice, 500);
+			System::set_block_number(3);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_supply_cap { id: 7u64, cap: 1_000.into() },
+				)
+				.execute_returns(());
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_transfer_fee_bps { id: 7u64, bps: 250 },
+				)
+				.execute_returns(());
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+				.execute_returns(destroy_token(7, 3));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 9u64 },
+				)
+				.execute_returns(());
+
+			extended_config(7).expect_no_logs().execute_returns(ExtendedConfig {
+				supply_cap: 1_000.into(),
+				transfer_fee_bps: 250,
+				transfer_fee_treasury: Address(TransferFeeTreasury::get()),
+				destroy_token_issued_at: 3,
+				destroy_started_at: 3,
+				..Default::default()
+			});
+			// The reservation of an id is reported before the asset exists, even once expired.
+			System::set_block_number(4 + IdReservationLifetime::get());
+			extended_config(9).execute_returns(ExtendedConfig {
+				reserved_by: H256(MockPeaqAccount::Bob.into()),
+				reserved_at: 3,
+				..Default::default()
+			});
+		});
+}
+
+#[test]
+fn set_supply_cap_checks() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 500);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 1000),
+				)
+				.execute_reverts(|output| output == b"caller is not the asset owner");
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(8, 1000),
+				)
+				.execute_reverts(|output| output == b"id: asset not found");
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 499),
+				)
+				.execute_reverts(|output| output == b"cap below current supply");
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::supply_cap { id: 7u64 },
+				)
+				.execute_returns(U256::zero());
+
+			// The cap can match the current supply, stopping further mints.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 500),
+				)
+				.expect_log(supply_cap_changed_log(7, 500))
+				.execute_returns(());
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint(7, 1))
+				.execute_reverts(|output| output == b"supply cap exceeded");
+
+			// Raising it again allows further mints.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					set_supply_cap(7, 2000),
+				)
+				.expect_log(supply_cap_changed_log(7, 2000))
+				.execute_returns(());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::supply_cap { id: 7u64 },
+				)
+				.execute_returns(U256::from(2000));
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint(7, 1))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn account_counts() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Charlie, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let account_counts = || {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::account_counts { id: 7u64 },
+				)
+			};
+
+			account_counts().execute_reverts(|output| output == b"id: asset not found");
+
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			account_counts().expect_no_logs().execute_returns(AccountCounts::default());
+
+			// Accounts created by mints of a sufficient asset are sufficient ones.
+			for who in [MockPeaqAccount::Alice, MockPeaqAccount::Bob] {
+				assert_ok!(Assets::mint(
+					RuntimeOrigin::signed(MockPeaqAccount::Alice),
+					MockAssetId(7),
+					who,
+					100
+				));
+			}
+			// While a touched account exists because of its deposit.
+			assert_ok!(Assets::touch(
+				RuntimeOrigin::signed(MockPeaqAccount::Charlie),
+				MockAssetId(7)
+			));
+			assert_ok!(Assets::approve_transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(7),
+				MockPeaqAccount::David,
+				50
+			));
+
+			account_counts().expect_no_logs().execute_returns(AccountCounts {
+				accounts: 3,
+				sufficients: 2,
+				approvals: 1,
+			});
+		});
+}
+
+#[test]
+fn metadata_getters() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			assert_ok!(Assets::set_metadata(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				b"Seven".to_vec(),
+				b"SVN".to_vec(),
+				18
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::name { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(UnboundedBytes::from(b"Seven".to_vec()));
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::symbol { id: 7u64 },
+				)
+				.execute_returns(UnboundedBytes::from(b"SVN".to_vec()));
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::decimals { id: 7u64 },
+				)
+				.execute_returns(18u8);
+		});
+}
+
+#[test]
+fn metadata_getters_of_asset_without_metadata() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			MockAssetId(8),
+			MockPeaqAccount::Alice,
+			true,
+			1
+		));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::name { id: 8u64 },
+			)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(Vec::new()));
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::symbol { id: 8u64 },
+			)
+			.execute_returns(UnboundedBytes::from(Vec::new()));
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::decimals { id: 8u64 },
+			)
+			.execute_returns(0u8);
+	});
+}
+
+#[test]
+fn token_info_batch_does_not_truncate_the_supply() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, u128::MAX);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::token_info_batch { ids: vec![7u64].into() },
+				)
+				.expect_no_logs()
+				.execute_returns(vec![TokenInfo {
+					exists: true,
+					total_supply: U256::from(u128::MAX),
+					min_balance: 1.into(),
+					status: ASSET_STATUS_LIVE,
+					..Default::default()
+				}]);
+		});
+}
+
+#[test]
+fn token_info_batch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			assert_ok!(Assets::set_metadata(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				b"Seven".to_vec(),
+				b"SVN".to_vec(),
+				18
+			));
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				100
+			));
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(8),
+				MockPeaqAccount::Alice,
+				true,
+				5
+			));
+			assert_ok!(Assets::freeze_asset(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(8)
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::token_info_batch { ids: vec![7u64, 9, 8].into() },
+				)
+				.expect_no_logs()
+				.execute_returns(vec![
+					TokenInfo {
+						exists: true,
+						name: UnboundedBytes::from(b"Seven".to_vec()),
+						symbol: UnboundedBytes::from(b"SVN".to_vec()),
+						decimals: 18,
+						total_supply: 100.into(),
+						min_balance: 1.into(),
+						status: ASSET_STATUS_LIVE,
+					},
+					TokenInfo::default(),
+					TokenInfo {
+						exists: true,
+						min_balance: 5.into(),
+						status: ASSET_STATUS_FROZEN,
+						..Default::default()
+					},
+				]);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::token_info_batch { ids: vec![].into() },
+				)
+				.execute_returns(Vec::<TokenInfo>::new());
+		});
+}
+
+#[test]
+fn create_requires_caller_to_afford_deposit() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Charlie, 99)])
+		.build()
+		.execute_with(|| {
+			AssetDeposit::set(100);
+
+			// Charlie cannot afford the deposit, even if the admin could.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 1,
+					},
+				)
+				.execute_reverts(|output| output == b"insufficient balance for deposit");
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Charlie.into()),
+						min_balance: 1,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Charlie))
+				.execute_returns(());
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 100);
+		});
+}
+
+#[test]
+fn reserved_deposit_of() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			AssetDeposit::set(100);
+			MetadataDepositBase::set(10);
+			MetadataDepositPerByte::set(1);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserved_deposit_of {
+						id: 7u64,
+						account: Address(MockPeaqAccount::Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::zero());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserved_deposit_of {
+						id: 7u64,
+						account: Address(MockPeaqAccount::Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(100));
+
+			// The admin does not hold any deposit.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserved_deposit_of {
+						id: 7u64,
+						account: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::zero());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![1u8, 2u8, 3u8].into(),
+						symbol: vec![4u8, 5u8].into(),
+						decimals: 18u8,
+					},
+				)
+				.expect_log(metadata_set_log(7, vec![1u8, 2u8, 3u8], vec![4u8, 5u8], 18u8))
+				.execute_returns(());
+
+			// 10 base + 5 bytes of name and symbol.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserved_deposit_of {
+						id: 7u64,
+						account: Address(MockPeaqAccount::Alice.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::from(115));
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 115);
+		});
+}
+
+#[test]
+fn self_check_passes_on_the_mock() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::self_check {},
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+	});
+}
+
+#[test]
+fn self_check_fails_on_unsound_configurations() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		// The factory at address 3 is over the assets instance with `u64` balances.
+		let foreign_factory = H160::from_low_u64_be(3);
+		let self_check = |factory: H160, input: Vec<u8>, expected: bool| {
+			precompiles
+				.prepare_test(MockPeaqAccount::Alice, factory, input)
+				.expect_no_logs()
+				.execute_returns(expected);
+		};
+		let main_factory = H160::from(MockPeaqAccount::EVMu1Account);
+
+		// A default name longer than the string limit would fail every create.
+		DefaultMetadata::set(Some((vec![b'a'; 51], b"UNN".to_vec())));
+		self_check(main_factory, PCall::self_check {}.into(), false);
+		DefaultMetadata::set(Some((vec![b'a'; 50], b"UNN".to_vec())));
+		self_check(main_factory, PCall::self_check {}.into(), true);
+
+		// A floor above the largest `u64` only fails the factory over `u64` balances.
+		MinBalanceFloor::set(Some(u64::MAX as u128 + 1));
+		self_check(main_factory, PCall::self_check {}.into(), true);
+		self_check(foreign_factory, ForeignPCall::self_check {}.into(), false);
+		MinBalanceFloor::set(Some(u64::MAX as u128));
+		self_check(foreign_factory, ForeignPCall::self_check {}.into(), true);
+	});
+}
+
+#[test]
+fn approval_deposit_of_follows_approvals_and_cancels() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			ApprovalDeposit::set(10);
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			let approval_deposit_of = |expected: u128| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Bob,
+						MockPeaqAccount::EVMu1Account,
+						PCall::approval_deposit_of {
+							id: 7u64,
+							owner: Address(MockPeaqAccount::Alice.into()),
+						},
+					)
+					.expect_no_logs()
+					.execute_returns(U256::from(expected));
+			};
+
+			approval_deposit_of(0);
+			for (delegate, expected) in [(MockPeaqAccount::Bob, 10), (MockPeaqAccount::Charlie, 20)]
+			{
+				assert_ok!(Assets::approve_transfer(
+					RuntimeOrigin::signed(MockPeaqAccount::Alice),
+					MockAssetId(7),
+					delegate,
+					50
+				));
+				approval_deposit_of(expected);
+			}
+
+			// Raising an approval keeps its deposit.
+			assert_ok!(Assets::approve_transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				50
+			));
+			approval_deposit_of(20);
+
+			assert_ok!(Assets::cancel_approval(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob
+			));
+			approval_deposit_of(10);
+			assert_eq!(Balances::reserved_balance(MockPeaqAccount::Alice), 10);
+
+			// The deposits of the delegate's own approvals are not counted.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::approval_deposit_of {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Charlie.into()),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(U256::zero());
+		});
+}
+
+#[test]
+fn create_rate_limit() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			MaxCreatesPerBlock::set(Some(2));
+
+			let create = |who: MockPeaqAccount, id: u64| {
+				precompiles.prepare_test(
+					who,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create { id, admin: Address(who.into()), min_balance: 1 },
+				)
+			};
+			let creates_remaining = |who: MockPeaqAccount| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::creates_remaining { account: Address(who.into()) },
+				)
+			};
+
+			creates_remaining(MockPeaqAccount::Alice).execute_returns(2u32);
+			create(MockPeaqAccount::Alice, 7)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			create(MockPeaqAccount::Alice, 8)
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			creates_remaining(MockPeaqAccount::Alice).execute_returns(0u32);
+
+			create(MockPeaqAccount::Alice, 9)
+				.execute_reverts(|output| output == b"create rate limit exceeded");
+			assert!(Assets::maybe_total_supply(MockAssetId(9)).is_none());
+
+			// The quota is per account.
+			creates_remaining(MockPeaqAccount::Bob).execute_returns(2u32);
+			create(MockPeaqAccount::Bob, 9)
+				.expect_log(asset_created_log(9, MockPeaqAccount::Bob, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			// The quota resets in the next block.
+			System::set_block_number(2);
+			creates_remaining(MockPeaqAccount::Alice).execute_returns(2u32);
+			create(MockPeaqAccount::Alice, 10)
+				.expect_log(asset_created_log(10, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			creates_remaining(MockPeaqAccount::Alice).execute_returns(1u32);
+		});
+}
+
+#[test]
+fn creates_are_not_rate_limited_by_default() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			for id in 7u64..12 {
+				precompiles()
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::create {
+							id,
+							admin: Address(MockPeaqAccount::Alice.into()),
+							min_balance: 1,
+						},
+					)
+					.expect_log(asset_created_log(
+						id,
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::Alice,
+					))
+					.execute_returns(());
+			}
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::creates_remaining { account: Address(MockPeaqAccount::Alice.into()) },
+				)
+				.expect_no_logs()
+				.execute_returns(u32::MAX);
+		});
+}
+
+#[test]
+fn transfer_and_touch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 10,
+					},
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				1000
+			));
+
+			// Charlie has no account to hold the non sufficient asset.
+			assert!(Assets::transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Charlie,
+				100
+			)
+			.is_err());
+
+			// A failing transfer does not leave the touched account behind.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_and_touch {
+						id: 7u64,
+						to: Address(MockPeaqAccount::Charlie.into()),
+						amount: 2000.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_reverts(|output| {
+					output.starts_with(b"Dispatched call failed with error: ")
+				});
+			assert!(assets_storage::Account::<Runtime, ()>::get(
+				MockAssetId(7),
+				MockPeaqAccount::Charlie
+			)
+			.is_none());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_and_touch {
+						id: 7u64,
+						to: Address(MockPeaqAccount::Charlie.into()),
+						amount: 100.into(),
+					},
+				)
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::touch_other {
+						id: MockAssetId(7),
+						who: MockPeaqAccount::Charlie,
+					}) + dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+						id: MockAssetId(7),
+						target: MockPeaqAccount::Charlie,
+						amount: 100,
+					}),
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 100);
+
+			// The account now exists, so only the transfer is dispatched.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_and_touch {
+						id: 7u64,
+						to: Address(MockPeaqAccount::Charlie.into()),
+						amount: 50.into(),
+					},
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+					id: MockAssetId(7),
+					target: MockPeaqAccount::Charlie,
+					amount: 50,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 150);
+		});
+}
+
+#[test]
+fn factory_transfers_pay_the_transfer_fee() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::Charlie, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_transfer_fee_bps { id: 7u64, bps: 500 },
+				)
+				.execute_returns(());
+			let balance = |who: MockPeaqAccount| Assets::balance(MockAssetId(7), who);
+
+			// 5% of each amount goes to the treasury, Charlie, logged right after the amount.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::David.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::from(200)].into(),
+					},
+				)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 95))
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 5))
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::David, 190))
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 10))
+				.execute_returns(());
+			assert_eq!(balance(MockPeaqAccount::Alice), 700);
+			assert_eq!(balance(MockPeaqAccount::Bob), 95);
+			assert_eq!(balance(MockPeaqAccount::David), 190);
+			assert_eq!(balance(MockPeaqAccount::Charlie), 15);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_and_touch {
+						id: 7u64,
+						to: Address(MockPeaqAccount::Bob.into()),
+						amount: 40.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(balance(MockPeaqAccount::Alice), 660);
+			assert_eq!(balance(MockPeaqAccount::Bob), 133);
+			assert_eq!(balance(MockPeaqAccount::Charlie), 17);
+
+			// The fee is taken out of the approval along with the amount.
+			assert_ok!(Assets::approve_transfer(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::David,
+				100
+			));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_approved {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						destination: Address(MockPeaqAccount::Bob.into()),
+						amount: 60.into(),
+					},
+				)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 57))
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 3))
+				.execute_returns(());
+			assert_eq!(balance(MockPeaqAccount::Alice), 600);
+			assert_eq!(balance(MockPeaqAccount::Bob), 190);
+			assert_eq!(balance(MockPeaqAccount::Charlie), 20);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_approved {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						destination: Address(MockPeaqAccount::Bob.into()),
+						amount: 41.into(),
+					},
+				)
+				.execute_reverts(|output| output == b"allowance overflow");
+			assert_eq!(balance(MockPeaqAccount::Alice), 600);
+		});
+}
+
+fn create_and_mint(id: u64, owner: MockPeaqAccount, amount: u128) {
+	precompiles()
+		.prepare_test(
+			owner,
+			MockPeaqAccount::EVMu1Account,
+			PCall::create { id, admin: Address(owner.into()), min_balance: 1 },
+		)
+		.expect_log(asset_created_log(id, owner, owner))
+		.execute_returns(());
+	assert_ok!(Assets::mint(RuntimeOrigin::signed(owner), MockAssetId(id.into()), owner, amount));
+}
+
+#[test]
+fn batch_transfer() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::David.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::from(250)].into(),
+					},
+				)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100))
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::David, 250))
+				.execute_returns(());
+
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 650);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 100);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::David), 250);
+		});
+}
+
+#[test]
+fn batch_transfer_reverts_when_its_gas_accounting_overflows() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			// The address derivation charged before the loop leaves less gas to account for than
+			// the log of the second entry costs, once the first entry is charged. The factory
+			// reverts before the gas used of the handle would wrap around.
+			let entry_cost =
+				log_cost(&transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100)) +
+					dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+						id: MockAssetId(7),
+						target: MockPeaqAccount::Bob,
+						amount: 100,
+					});
+			let read_gas =
+				RuntimeHelper::<Runtime>::weight_to_gas(AddressDerivationReadWeight::get());
+			AddressDerivationReads::set((u64::MAX - entry_cost) / read_gas);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::Bob.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::from(100)].into(),
+					},
+				)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100))
+				.execute_reverts(|output| output == b"gas accounting overflow");
+
+			// The first transfer is rolled back with the batch.
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 1000);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 0);
+		});
+}
+
+#[test]
+fn amount_conversion_mode_is_checked() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::amount_conversion_mode {},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns(AMOUNT_CONVERSION_CHECKED);
+
+			// As the view tells, an amount too large for the balance is not clamped.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![Address(MockPeaqAccount::Bob.into())].into(),
+						amounts: vec![U256::MAX].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"amounts[0]: Value is too large for balance type"
+				});
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 1000);
+		});
+}
+
+#[test]
+fn batch_logs_follow_input_order() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::Charlie, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			// Neither sorted by recipient or amount nor grouped by recipient.
+			let items = [
+				(MockPeaqAccount::David, 30),
+				(MockPeaqAccount::Bob, 10),
+				(MockPeaqAccount::Charlie, 40),
+				(MockPeaqAccount::Bob, 20),
+				(MockPeaqAccount::David, 5),
+			];
+			let (recipients, amounts): (Vec<_>, Vec<_>) = items.into_iter().unzip();
+
+			let mut tester = precompiles.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				batch_mint(7, recipients.clone(), amounts.clone()),
+			);
+			for (to, amount) in items {
+				tester = tester.expect_log(minted_log(7, to, amount));
+			}
+			tester.execute_returns(());
+
+			let mut tester = precompiles.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::batch_transfer {
+					id: 7u64,
+					recipients: recipients
+						.into_iter()
+						.map(|who| Address(who.into()))
+						.collect::<Vec<_>>()
+						.into(),
+					amounts: amounts.into_iter().map(U256::from).collect::<Vec<_>>().into(),
+				},
+			);
+			for (to, amount) in items {
+				tester = tester.expect_log(transfer_log(7, MockPeaqAccount::Alice, to, amount));
+			}
+			tester.execute_returns(());
+
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 60);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::David), 70);
+		});
+}
+
+#[test]
+fn batch_transfer_without_events() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			let transfers_cost = dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+				id: MockAssetId(7),
+				target: MockPeaqAccount::Bob,
+				amount: 100,
+			}) + dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+				id: MockAssetId(7),
+				target: MockPeaqAccount::David,
+				amount: 250,
+			});
+			let logs_cost =
+				log_cost(&transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100)) +
+					log_cost(&transfer_log(
+						7,
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::David,
+						250,
+					));
+			let batch_transfer = |emit_events: bool| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer_with_events {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::David.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::from(250)].into(),
+						emit_events,
+					},
+				)
+			};
+
+			batch_transfer(true)
+				.expect_cost(transfers_cost + logs_cost)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100))
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::David, 250))
+				.execute_returns(());
+
+			// Skipping the events only saves their cost.
+			assert!(logs_cost > 0);
+			batch_transfer(false)
+				.expect_cost(transfers_cost)
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 300);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 200);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::David), 500);
+
+			// The runtime chooses when the caller does not.
+			EmitBatchEvents::set(false);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![Address(MockPeaqAccount::Bob.into())].into(),
+						amounts: vec![U256::from(100)].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 300);
+		});
+}
+
+#[test]
+fn empty_batches_are_no_ops() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// Not even the existence of the asset is checked.
+			for call in [
+				PCall::batch_mint { id: 7u64, recipients: vec![].into(), amounts: vec![].into() },
+				PCall::batch_transfer {
+					id: 7u64,
+					recipients: vec![].into(),
+					amounts: vec![].into(),
+				},
+				PCall::batch_transfer_with_events {
+					id: 7u64,
+					recipients: vec![].into(),
+					amounts: vec![].into(),
+					emit_events: true,
+				},
+			] {
+				precompiles()
+					.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, call)
+					.expect_cost(0)
+					.expect_no_logs()
+					.execute_returns(());
+			}
+
+			assert!(Assets::maybe_total_supply(MockAssetId(7)).is_none());
+			assert!(assets_events().is_empty());
+
+			// The lengths must still match.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_mint {
+						id: 7u64,
+						recipients: vec![].into(),
+						amounts: vec![U256::from(100)].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"amounts: recipients and amounts lengths differ"
+				});
+		});
+}
+
+#[test]
+fn batch_transfer_rejects_length_mismatch() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![Address(MockPeaqAccount::Bob.into())].into(),
+						amounts: vec![U256::from(100), U256::from(250)].into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_reverts(|output| {
+					output == b"amounts: recipients and amounts lengths differ"
+				});
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 1000);
+		});
+}
+
+#[test]
+fn batch_transfer_reverts_entirely_on_failure() {
+	ExtBuilder::default()
+		.with_balances(vec![
+			(MockPeaqAccount::Alice, 5000),
+			(MockPeaqAccount::Bob, 5000),
+			(MockPeaqAccount::David, 5000),
+		])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			// Charlie has no account to hold the non sufficient asset.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::Charlie.into()),
+							Address(MockPeaqAccount::David.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::from(100), U256::from(100)].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output.starts_with(b"recipients[1]: Dispatched call failed with error: ")
+				});
+
+			// The transfer to Bob is rolled back along with the failing one.
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Alice), 1000);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 0);
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::David), 0);
+
+			// Amounts not fitting the balance are attributed to their index as well.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::David.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::MAX].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"amounts[1]: Value is too large for balance type"
+				});
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 0);
+		});
+}
+
+#[test]
+fn last_error_is_kept_until_the_end_of_the_transaction() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			// The precompile set is created once per transaction.
+			let transaction = precompiles();
+			let last_error = |precompiles: &Precompiles<Runtime>, expected: LastError| {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::last_error {},
+					)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+
+			last_error(&transaction, LastError::default());
+
+			transaction
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![
+							Address(MockPeaqAccount::Bob.into()),
+							Address(MockPeaqAccount::Bob.into()),
+						]
+						.into(),
+						amounts: vec![U256::from(100), U256::MAX].into(),
+					},
+				)
+				.execute_reverts(|output| {
+					output == b"amounts[1]: Value is too large for balance type"
+				});
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 0);
+
+			// The failure is kept although the changes of the call are reverted.
+			last_error(
+				&transaction,
+				LastError {
+					code: LAST_ERROR_REVERT,
+					message: b"amounts[1]: Value is too large for balance type".into(),
+				},
+			);
+
+			// Later successful calls do not clear it.
+			transaction
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::batch_transfer {
+						id: 7u64,
+						recipients: vec![Address(MockPeaqAccount::Bob.into())].into(),
+						amounts: vec![U256::from(100)].into(),
+					},
+				)
+				.execute_returns(());
+			last_error(
+				&transaction,
+				LastError {
+					code: LAST_ERROR_REVERT,
+					message: b"amounts[1]: Value is too large for balance type".into(),
+				},
+			);
+
+			// Nor is it seen by the next transaction.
+			last_error(&precompiles(), LastError::default());
+		});
+}
+
+#[test]
+fn call_stats_count_the_mutating_calls_of_the_block() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let call_stats = |expected: CallStats| {
+				precompiles()
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::call_stats {},
+					)
+					.expect_no_logs()
+					.execute_returns(expected);
+			};
+			let call = |call: PCall| {
+				precompiles()
+					.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, call)
+					.execute_returns(());
+			};
+
+			call_stats(CallStats { block: 1, total: 0, methods: vec![] });
+
+			call(PCall::create {
+				id: 7u64,
+				admin: Address(MockPeaqAccount::Alice.into()),
+				min_balance: 1,
+			});
+			call(PCall::set_metadata {
+				id: 7u64,
+				name: b"Seven".to_vec().into(),
+				symbol: b"SVN".to_vec().into(),
+				decimals: 18,
+			});
+			for _ in 0..2 {
+				call(PCall::mint {
+					id: 7u64,
+					beneficiary: Address(MockPeaqAccount::Alice.into()),
+					amount: 100.into(),
+				});
+			}
+			// The views are not counted.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::supply_cap { id: 7u64 },
+				)
+				.execute_returns(U256::zero());
+
+			call_stats(CallStats {
+				block: 1,
+				total: 4,
+				methods: vec![
+					MethodCalls { selector: 0x9c28547e, calls: 1 },
+					MethodCalls { selector: 0xf2be4599, calls: 2 },
+					MethodCalls { selector: 0xf96ee86d, calls: 1 },
+				],
+			});
+
+			// The counts start over in the next block.
+			System::set_block_number(2);
+			call_stats(CallStats { block: 2, total: 0, methods: vec![] });
+
+			call(PCall::mint {
+				id: 7u64,
+				beneficiary: Address(MockPeaqAccount::Alice.into()),
+				amount: 100.into(),
+			});
+			call_stats(CallStats {
+				block: 2,
+				total: 1,
+				methods: vec![MethodCalls { selector: 0xf2be4599, calls: 1 }],
+			});
+		});
+}
+
+#[test]
+fn force_methods_allowed_for_force_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::force_create {
+					id: 7u64,
+					owner: Address(MockPeaqAccount::Bob.into()),
+					is_sufficient: true,
+					min_balance: 1,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+		assert!(Assets::maybe_total_supply(MockAssetId(7)).is_some());
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::force_set_metadata {
+					id: 7u64,
+					name: vec![1u8, 2u8, 3u8].into(),
+					symbol: vec![4u8, 5u8, 6u8].into(),
+					decimals: 18u8,
+					is_frozen: false,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+		assert_eq!(Assets::decimals(MockAssetId(7)), 18);
+
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(MockPeaqAccount::Bob),
+			MockAssetId(7),
+			MockPeaqAccount::Bob,
+			100
+		));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::force_transfer {
+					id: 7u64,
+					source: Address(MockPeaqAccount::Bob.into()),
+					dest: Address(MockPeaqAccount::Charlie.into()),
+					amount: 40.into(),
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+		assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 40);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::force_clear_metadata { id: 7u64 },
+			)
+			.expect_no_logs()
+			.execute_returns(());
+		assert_eq!(Assets::decimals(MockAssetId(7)), 0);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::force_asset_status {
+					id: 7u64,
+					owner: Address(MockPeaqAccount::Charlie.into()),
+					issuer: Address(MockPeaqAccount::Charlie.into()),
+					admin: Address(MockPeaqAccount::Charlie.into()),
+					freezer: Address(MockPeaqAccount::Charlie.into()),
+					min_balance: 1,
+					is_sufficient: true,
+					is_frozen: false,
+				},
+			)
+			.expect_no_logs()
+			.execute_returns(());
+		assert_eq!(Assets::owner(MockAssetId(7)), Some(MockPeaqAccount::Charlie));
+	});
+}
+
+#[test]
+fn force_methods_rejected_for_other_callers() {
+	ExtBuilder::default().build().execute_with(|| {
+		let inputs = [
+			PCall::force_create {
+				id: 7u64,
+				owner: Address(MockPeaqAccount::Bob.into()),
+				is_sufficient: true,
+				min_balance: 1,
+			},
+			PCall::force_set_metadata {
+				id: 7u64,
+				name: vec![1u8, 2u8, 3u8].into(),
+				symbol: vec![4u8, 5u8, 6u8].into(),
+				decimals: 18u8,
+				is_frozen: false,
+			},
+			PCall::force_clear_metadata { id: 7u64 },
+			PCall::force_asset_status {
+				id: 7u64,
+				owner: Address(MockPeaqAccount::Alice.into()),
+				issuer: Address(MockPeaqAccount::Alice.into()),
+				admin: Address(MockPeaqAccount::Alice.into()),
+				freezer: Address(MockPeaqAccount::Alice.into()),
+				min_balance: 1,
+				is_sufficient: true,
+				is_frozen: false,
+			},
+			PCall::force_transfer {
+				id: 7u64,
+				source: Address(MockPeaqAccount::Bob.into()),
+				dest: Address(MockPeaqAccount::Alice.into()),
+				amount: 40.into(),
+			},
+			PCall::rescue_missent { id: 7u64, to: Address(MockPeaqAccount::Alice.into()) },
+			PCall::cancel_force_operation { id: 7u64 },
+		];
+
+		for input in inputs {
+			precompiles()
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, input)
+				.expect_no_logs()
+				.execute_reverts(|output| output == b"caller is not allowed to force");
+		}
+	});
+}
+
+#[test]
+fn methods_on_existing_assets_revert_for_unknown_ids() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let alice = Address(MockPeaqAccount::Alice.into());
+			let bob = Address(MockPeaqAccount::Bob.into());
+			let inputs = [
+				PCall::set_metadata {
+					id: 7u64,
+					name: b"Seven".to_vec().into(),
+					symbol: b"SVN".to_vec().into(),
+					decimals: 18,
+				},
+				PCall::set_metadata_defaults {
+					id: 7u64,
+					name: b"Seven".to_vec().into(),
+					symbol: b"SVN".to_vec().into(),
+				},
+				PCall::rename {
+					id: 7u64,
+					name: b"Seven".to_vec().into(),
+					symbol: b"SVN".to_vec().into(),
+				},
+				PCall::set_min_balance { id: 7u64, min_balance: 1 },
+				PCall::set_team { id: 7u64, issuer: alice, admin: alice, freezer: alice },
+				PCall::transfer_ownership { id: 7u64, owner: bob },
+				PCall::transfer_ownership_and_set_team {
+					id: 7u64,
+					new_owner: bob,
+					issuer: bob,
+					admin: bob,
+					freezer: bob,
+				},
+				PCall::start_destroy { id: 7u64 },
+				PCall::finish_destroy { id: 7u64, token: H256::zero() },
+				PCall::force_finish_destroy { id: 7u64, token: H256::zero(), max_iterations: 1 },
+				PCall::block { id: 7u64, who: bob },
+				PCall::freeze { id: 7u64, who: bob },
+				PCall::thaw { id: 7u64, who: bob },
+				PCall::freeze_asset { id: 7u64 },
+				PCall::thaw_asset { id: 7u64 },
+				PCall::mint { id: 7u64, beneficiary: bob, amount: 1.into() },
+				PCall::burn { id: 7u64, who: bob, amount: 1.into() },
+				PCall::batch_mint {
+					id: 7u64,
+					recipients: vec![bob].into(),
+					amounts: vec![U256::one()].into(),
+				},
+				PCall::set_transfer_fee_bps { id: 7u64, bps: 100 },
+				PCall::set_supply_cap { id: 7u64, cap: 100.into() },
+				PCall::transfer_and_touch { id: 7u64, to: bob, amount: 1.into() },
+				PCall::batch_transfer {
+					id: 7u64,
+					recipients: vec![bob].into(),
+					amounts: vec![U256::one()].into(),
+				},
+				PCall::batch_transfer_with_events {
+					id: 7u64,
+					recipients: vec![bob].into(),
+					amounts: vec![U256::one()].into(),
+					emit_events: true,
+				},
+				PCall::approve_with_deadline {
+					id: 7u64,
+					spender: bob,
+					amount: 1.into(),
+					deadline_block: 10,
+				},
+				PCall::permit {
+					id: 7u64,
+					owner: alice,
+					spender: bob,
+					value: 1.into(),
+					deadline: u64::MAX,
+					v: 27,
+					r: H256::zero(),
+					s: H256::zero(),
+				},
+				PCall::transfer_approved {
+					id: 7u64,
+					owner: alice,
+					destination: bob,
+					amount: 1.into(),
+				},
+				PCall::cancel_approval { id: 7u64, spender: bob },
+			];
+			for input in inputs {
+				precompiles()
+					.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, input)
+					.expect_no_logs()
+					.execute_reverts(|output| output == b"id: asset not found");
+			}
+
+			let force_inputs = [
+				PCall::force_set_metadata {
+					id: 7u64,
+					name: b"Seven".to_vec().into(),
+					symbol: b"SVN".to_vec().into(),
+					decimals: 18,
+					is_frozen: false,
+				},
+				PCall::force_clear_metadata { id: 7u64 },
+				PCall::force_asset_status {
+					id: 7u64,
+					owner: alice,
+					issuer: alice,
+					admin: alice,
+					freezer: alice,
+					min_balance: 1,
+					is_sufficient: true,
+					is_frozen: false,
+				},
+				PCall::force_transfer { id: 7u64, source: alice, dest: bob, amount: 1.into() },
+				PCall::rescue_missent { id: 7u64, to: alice },
+			];
+			for input in force_inputs {
+				precompiles()
+					.prepare_test(MockPeaqAccount::David, MockPeaqAccount::EVMu1Account, input)
+					.expect_no_logs()
+					.execute_reverts(|output| output == b"id: asset not found");
+			}
+
+			// Creating the asset is not checked.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create { id: 7u64, admin: alice, min_balance: 1 },
+				)
+				.execute_returns(());
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_min_balance { id: 7u64, min_balance: 2 },
+				)
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn rescue_missent() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		let asset_account = MockPeaqAccount::AssetId(MockAssetId(7));
+		assert_ok!(Assets::force_create(
+			RuntimeOrigin::root(),
+			MockAssetId(7),
+			MockPeaqAccount::Bob,
+			true,
+			1
+		));
+
+		let rescue = || {
+			precompiles.prepare_test(
+				MockPeaqAccount::David,
+				MockPeaqAccount::EVMu1Account,
+				PCall::rescue_missent { id: 7u64, to: Address(MockPeaqAccount::Charlie.into()) },
+			)
+		};
+
+		rescue().execute_reverts(|output| output == b"nothing to rescue");
+
+		// Assets sent by mistake to the address of the asset.
+		assert_ok!(Assets::mint(
+			RuntimeOrigin::signed(MockPeaqAccount::Bob),
+			MockAssetId(7),
+			asset_account,
+			300
+		));
+
+		rescue()
+			.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::force_transfer {
+				id: MockAssetId(7),
+				source: asset_account,
+				dest: MockPeaqAccount::Charlie,
+				amount: 300,
+			}))
+			.expect_no_logs()
+			.execute_returns(());
+
+		assert_eq!(Assets::balance(MockAssetId(7), asset_account), 0);
+		assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 300);
+	});
+}
+
+#[test]
+fn cancel_force_operation() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let cancel = |caller: MockPeaqAccount, id: u64| {
+				precompiles.prepare_test(
+					caller,
+					MockPeaqAccount::EVMu1Account,
+					PCall::cancel_force_operation { id },
+				)
+			};
+			let extended_config = |id: u64| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::extended_config { id },
+				)
+			};
+
+			// A destruction left half-finished by forceFinishDestroy, along with a reservation.
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Bob,
+				100
+			));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 7u64 },
+				)
+				.execute_returns(destroy_token(7, 1));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 7u64,
+						token: destroy_token(7, 1),
+						max_iterations: 1,
+					},
+				)
+				.execute_returns(false);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 9u64 },
+				)
+				.execute_returns(());
+
+			cancel(MockPeaqAccount::Alice, 7)
+				.execute_reverts(|output| output == b"caller is not allowed to force");
+
+			cancel(MockPeaqAccount::David, 7).expect_no_logs().execute_returns(());
+			cancel(MockPeaqAccount::David, 9).expect_no_logs().execute_returns(());
+			extended_config(7).execute_returns(ExtendedConfig::default());
+			extended_config(9).execute_returns(ExtendedConfig::default());
+
+			// The cancelled token can neither finish nor force the destruction, which carries on in
+			// the pallet.
+			assert_eq!(
+				assets_storage::Asset::<Runtime, ()>::get(MockAssetId(7)).unwrap().status,
+				AssetStatus::Destroying
+			);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 7u64, token: destroy_token(7, 1) },
+				)
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_finish_destroy {
+						id: 7u64,
+						token: destroy_token(7, 1),
+						max_iterations: 1,
+					},
+				)
+				.execute_reverts(|output| output == b"token: wrong destroy confirmation token");
+
+			// The id is free to be created by another account.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					create_as(MockPeaqAccount::Alice, 9),
+				)
+				.execute_returns(());
+
+			// Cancelling when nothing is in progress does nothing.
+			cancel(MockPeaqAccount::David, 8).execute_returns(());
+		});
+}
+
+#[test]
+fn approve_with_deadline() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			let approve = |amount: u128, deadline_block: u64| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::approve_with_deadline {
+						id: 7u64,
+						spender: Address(MockPeaqAccount::Bob.into()),
+						amount: amount.into(),
+						deadline_block,
+					},
+				)
+			};
+			approve(500, 5)
+				.expect_log(approval_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 500))
+				.execute_returns(());
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &MockPeaqAccount::Alice, &MockPeaqAccount::Bob),
+				500
+			);
+
+			// The previous approval is replaced rather than added to.
+			approve(300, 5).execute_returns(());
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &MockPeaqAccount::Alice, &MockPeaqAccount::Bob),
+				300
+			);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::allowance_deadline {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						spender: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.expect_cost(0)
+				.expect_no_logs()
+				.execute_returns(5u64);
+
+			// The deadline block itself is still in the window.
+			System::set_block_number(5);
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_approved {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						destination: Address(MockPeaqAccount::Charlie.into()),
+						amount: 100.into(),
+					},
+				)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 100))
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 100);
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &MockPeaqAccount::Alice, &MockPeaqAccount::Bob),
+				200
+			);
+
+			approve(300, 4)
+				.execute_reverts(|output| output == b"deadlineBlock: deadline already passed");
+		});
+}
+
+#[test]
+fn expired_approval_reverts_and_can_be_cancelled() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::approve_with_deadline {
+						id: 7u64,
+						spender: Address(MockPeaqAccount::Bob.into()),
+						amount: 500.into(),
+						deadline_block: 5,
+					},
+				)
+				.execute_returns(());
+
+			System::set_block_number(6);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_approved {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						destination: Address(MockPeaqAccount::Charlie.into()),
+						amount: 100.into(),
+					},
+				)
+				.expect_no_logs()
+				.execute_reverts(|output| output == b"allowance expired");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 0);
+
+			// The pallet keeps the expired approval until its owner cancels it.
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &MockPeaqAccount::Alice, &MockPeaqAccount::Bob),
+				500
+			);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::cancel_approval {
+						id: 7u64,
+						spender: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.expect_log(approval_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 0))
+				.execute_returns(());
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &MockPeaqAccount::Alice, &MockPeaqAccount::Bob),
+				0
+			);
+			assert_eq!(
+				assets_storage::Asset::<Runtime, ()>::get(MockAssetId(7)).unwrap().approvals,
+				0
+			);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::allowance_deadline {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						spender: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.execute_returns(0u64);
+		});
+}
+
+#[test]
+fn transfer_approved_reports_missing_and_exceeded_approvals() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			let transfer_approved = |amount: u128| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_approved {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Alice.into()),
+						destination: Address(MockPeaqAccount::Charlie.into()),
+						amount: amount.into(),
+					},
+				)
+			};
+
+			transfer_approved(100).execute_reverts(|output| output == b"no approval");
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::approve_with_deadline {
+						id: 7u64,
+						spender: Address(MockPeaqAccount::Bob.into()),
+						amount: 100.into(),
+						deadline_block: 10,
+					},
+				)
+				.execute_returns(());
+			transfer_approved(150).execute_reverts(|output| output == b"allowance overflow");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 0);
+
+			// Spending the whole approval removes it.
+			transfer_approved(100)
+				.expect_log(transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Charlie, 100))
+				.execute_returns(());
+			transfer_approved(1).execute_reverts(|output| output == b"no approval");
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Charlie), 100);
+		});
+}
+
+fn permit_key(seed: &[u8]) -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&sp_io::hashing::keccak_256(seed)).unwrap()
+}
+
+fn permit_owner(secret: &libsecp256k1::SecretKey) -> H160 {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret);
+
+	H160::from_slice(&sp_io::hashing::keccak_256(&public.serialize()[1..65])[12..])
+}
+
+fn permit_domain_separator(id: u64) -> H256 {
+	H256(sp_io::hashing::keccak_256(&solidity::encode_arguments((
+		H256(sp_io::hashing::keccak_256(
+			b"EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)",
+		)),
+		H256(sp_io::hashing::keccak_256(&Assets::name(MockAssetId(id.into())))),
+		H256(sp_io::hashing::keccak_256(b"1")),
+		U256::from(ChainId::get()),
+		Address(Runtime::asset_id_to_address(MockAssetId(id.into()))),
+	))))
+}
+
+/// Returns the hash `owner` signs to permit `spender` to transfer `value` of the asset `id`.
+fn permit_message(
+	id: u64,
+	owner: H160,
+	spender: MockPeaqAccount,
+	value: u128,
+	nonce: u64,
+	deadline: u64,
+) -> [u8; 32] {
+	let permit_hash = sp_io::hashing::keccak_256(&solidity::encode_arguments((
+		H256(sp_io::hashing::keccak_256(
+			b"Permit(address owner,address spender,uint256 value,uint256 nonce,uint256 deadline)",
+		)),
+		Address(owner),
+		Address(spender.into()),
+		U256::from(value),
+		U256::from(nonce),
+		U256::from(deadline),
+	)));
+	let mut message = b"\x19\x01".to_vec();
+	message.extend_from_slice(permit_domain_separator(id).as_bytes());
+	message.extend_from_slice(&permit_hash);
+
+	sp_io::hashing::keccak_256(&message)
+}
+
+/// Signs the permit of `value` of the asset `id` to `spender` as an EIP-712 signature, returning
+/// its `v`, `r` and `s`.
+fn sign_permit(
+	secret: &libsecp256k1::SecretKey,
+	id: u64,
+	spender: MockPeaqAccount,
+	value: u128,
+	nonce: u64,
+	deadline: u64,
+) -> (u8, H256, H256) {
+	let message = permit_message(id, permit_owner(secret), spender, value, nonce, deadline);
+	let (signature, recovery_id) =
+		libsecp256k1::sign(&libsecp256k1::Message::parse(&message), secret);
+	let signature = signature.serialize();
+
+	(
+		27 + recovery_id.serialize(),
+		H256::from_slice(&signature[..32]),
+		H256::from_slice(&signature[32..]),
+	)
+}
+
+fn permit_call(
+	owner: H160,
+	spender: MockPeaqAccount,
+	value: u128,
+	deadline: u64,
+	(v, r, s): (u8, H256, H256),
+) -> PCall {
+	PCall::permit {
+		id: 7u64,
+		owner: Address(owner),
+		spender: Address(spender.into()),
+		value: value.into(),
+		deadline,
+		v,
+		r,
+		s,
+	}
+}
+
+#[test]
+fn permit() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			// In seconds, the timestamp is 1000.
+			Timestamp::set_timestamp(1_000_000);
+
+			let secret = permit_key(b"Permit owner");
+			let owner = permit_owner(&secret);
+			// The address of the owner is not one of the mock, so it is mapped to `Bogus`.
+			let owner_account = MockPeaqAccount::from(owner);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::domain_separator { id: 7u64 },
+				)
+				.execute_returns(permit_domain_separator(7));
+
+			// Anyone can submit the permit.
+			let signature = sign_permit(&secret, 7, MockPeaqAccount::Bob, 500, 0, 1000);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 500, 1000, signature),
+				)
+				.expect_log(log3(
+					Runtime::asset_id_to_address(MockAssetId(7)),
+					SELECTOR_LOG_APPROVAL,
+					owner,
+					H160::from(MockPeaqAccount::Bob),
+					solidity::encode_event_data(U256::from(500)),
+				))
+				.execute_returns(());
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &owner_account, &MockPeaqAccount::Bob),
+				500
+			);
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::nonces { id: 7u64, owner: Address(owner) },
+				)
+				.execute_returns(U256::one());
+
+			// The nonce was consumed, so the permit cannot be replayed.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 500, 1000, signature),
+				)
+				.execute_reverts(|output| output == b"invalid permit signature");
+
+			// A permit signed with the next nonce replaces the approval.
+			let signature = sign_permit(&secret, 7, MockPeaqAccount::Bob, 200, 1, 1000);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 200, 1000, signature),
+				)
+				.execute_returns(());
+			assert_eq!(
+				Assets::allowance(MockAssetId(7), &owner_account, &MockPeaqAccount::Bob),
+				200
+			);
+		});
+}
+
+#[test]
+fn nonces_increment_with_each_permit() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			Timestamp::set_timestamp(1_000_000);
+
+			let secret = permit_key(b"Permit owner");
+			let owner = permit_owner(&secret);
+			let nonce = || {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::nonces { id: 7u64, owner: Address(owner) },
+					)
+					.expect_no_logs()
+			};
+
+			nonce().execute_returns(U256::zero());
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(
+						owner,
+						MockPeaqAccount::Bob,
+						500,
+						1000,
+						sign_permit(&secret, 7, MockPeaqAccount::Bob, 500, 0, 1000),
+					),
+				)
+				.execute_returns(());
+			nonce().execute_returns(U256::one());
+
+			// A new permit signed with the old nonce is rejected.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(
+						owner,
+						MockPeaqAccount::Bob,
+						300,
+						1000,
+						sign_permit(&secret, 7, MockPeaqAccount::Bob, 300, 0, 1000),
+					),
+				)
+				.execute_reverts(|output| output == b"invalid permit signature");
+			nonce().execute_returns(U256::one());
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(
+						owner,
+						MockPeaqAccount::Bob,
+						300,
+						1000,
+						sign_permit(&secret, 7, MockPeaqAccount::Bob, 300, 1, 1000),
+					),
+				)
+				.execute_returns(());
+			nonce().execute_returns(U256::from(2));
+
+			// The nonces are kept by asset.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::nonces { id: 8u64, owner: Address(owner) },
+				)
+				.execute_returns(U256::zero());
+		});
+}
+
+#[test]
+fn expired_permit_reverts() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			Timestamp::set_timestamp(1_000_000);
+
+			let secret = permit_key(b"Permit owner");
+			let owner = permit_owner(&secret);
+
+			let signature = sign_permit(&secret, 7, MockPeaqAccount::Bob, 500, 0, 999);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 500, 999, signature),
+				)
+				.execute_reverts(|output| output == b"permit expired");
+
+			assert_eq!(
+				Assets::allowance(
+					MockAssetId(7),
+					&MockPeaqAccount::from(owner),
+					&MockPeaqAccount::Bob
+				),
+				0
+			);
+		});
+}
+
+#[test]
+fn permit_with_high_s_reverts() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			Timestamp::set_timestamp(1_000_000);
+
+			let secret = permit_key(b"Permit owner");
+			let owner = permit_owner(&secret);
+
+			// The signatures are signed with a low `s`, from which the same signer is recovered
+			// with the `s` of the other half of the order and the other `v`.
+			let (v, r, s) = sign_permit(&secret, 7, MockPeaqAccount::Bob, 500, 0, 1000);
+			assert!(U256::from_big_endian(s.as_bytes()) <= permit::SECP256K1_HALF_ORDER);
+			let order = U256::from_str_radix(
+				"fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+				16,
+			)
+			.unwrap();
+			let mut high_s = [0u8; 32];
+			(order - U256::from_big_endian(s.as_bytes())).to_big_endian(&mut high_s);
+			let malleated = (if v == 27 { 28 } else { 27 }, r, H256(high_s));
+			let mut signature = [0u8; 65];
+			signature[..32].copy_from_slice(r.as_bytes());
+			signature[32..64].copy_from_slice(&high_s);
+			signature[64] = malleated.0;
+			assert_eq!(
+				sp_io::crypto::secp256k1_ecdsa_recover(
+					&signature,
+					&permit_message(7, owner, MockPeaqAccount::Bob, 500, 0, 1000),
+				)
+				.ok()
+				.map(|public| H160::from(H256(sp_io::hashing::keccak_256(&public)))),
+				Some(owner)
+			);
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 500, 1000, malleated),
+				)
+				.execute_reverts(|output| output == b"invalid permit signature");
+			assert_eq!(
+				Assets::allowance(
+					MockAssetId(7),
+					&MockPeaqAccount::from(owner),
+					&MockPeaqAccount::Bob
+				),
+				0
+			);
+
+			// The original signature is still valid.
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 500, 1000, (v, r, s)),
+				)
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn permit_with_bad_signature_reverts() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			Timestamp::set_timestamp(1_000_000);
+
+			let secret = permit_key(b"Permit owner");
+			let owner = permit_owner(&secret);
+
+			// Signed by another key.
+			let signature =
+				sign_permit(&permit_key(b"Someone else"), 7, MockPeaqAccount::Bob, 500, 0, 1000);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 500, 1000, signature),
+				)
+				.execute_reverts(|output| output == b"invalid permit signature");
+
+			// Signed for another value.
+			let signature = sign_permit(&secret, 7, MockPeaqAccount::Bob, 500, 0, 1000);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::EVMu1Account,
+					permit_call(owner, MockPeaqAccount::Bob, 5000, 1000, signature),
+				)
+				.execute_reverts(|output| output == b"invalid permit signature");
+
+			assert_eq!(
+				Assets::allowance(
+					MockAssetId(7),
+					&MockPeaqAccount::from(owner),
+					&MockPeaqAccount::Bob
+				),
+				0
+			);
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::nonces { id: 7u64, owner: Address(owner) },
+				)
+				.execute_returns(U256::zero());
+		});
+}
+
+/// Gas charged for dispatching `call`. Together with the cost of the emitted logs, this is the
+/// whole cost of a mutating method: any extra gas would be a charge for logs that never get
+/// emitted.
+fn dispatch_cost(call: impl Into<RuntimeCall>) -> u64 {
+	let call: RuntimeCall = call.into();
+	<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
+		call.get_dispatch_info().weight,
+	)
+}
+
+fn out_of_gas_reason(required_gas: u64) -> Vec<u8> {
+	format!("{OUT_OF_GAS_REVERT_PREFIX}dispatch needs {required_gas} gas").into_bytes()
+}
+
+#[test]
+fn dispatch_out_of_gas_is_told_apart_from_a_failure() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			create_and_mint(7, MockPeaqAccount::Alice, 1000);
+			let mint = || PCall::mint {
+				id: 7u64,
+				beneficiary: Address(MockPeaqAccount::Bob.into()),
+				amount: 10.into(),
+			};
+			let mint_cost = dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+				id: MockAssetId(7),
+				beneficiary: MockPeaqAccount::Bob,
+				amount: 10,
+			});
+
+			// Without enough gas, the revert gives the gas the dispatch needs.
+			precompiles
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint())
+				.with_target_gas(Some(mint_cost - 1))
+				.execute_reverts(|output| output == out_of_gas_reason(mint_cost));
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 0);
+
+			// A failure of the call is not tagged, whatever the gas.
+			precompiles
+				.prepare_test(MockPeaqAccount::Bob, MockPeaqAccount::EVMu1Account, mint())
+				.execute_reverts(|output| {
+					output.starts_with(b"Dispatched call failed with error: ") &&
+						!output.starts_with(OUT_OF_GAS_REVERT_PREFIX.as_bytes())
+				});
+
+			precompiles
+				.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, mint())
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 10);
+		});
+}
+
+#[test]
+fn log_costs_match_emitted_logs() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::create {
+						id: MockAssetId(7),
+						admin: MockPeaqAccount::Bob,
+						min_balance: 500,
+					}) + log_cost(&asset_created_log(
+						7,
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::Bob,
+					)),
+				)
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![1u8, 2u8, 3u8].into(),
+						symbol: vec![4u8, 5u8].into(),
+						decimals: 18u8,
+					},
+				)
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+						id: MockAssetId(7),
+						name: vec![1u8, 2u8, 3u8],
+						symbol: vec![4u8, 5u8],
+						decimals: 18u8,
+					}) + log_cost(&metadata_set_log(7, vec![1u8, 2u8, 3u8], vec![4u8, 5u8], 18u8)),
+				)
+				.expect_log(metadata_set_log(7, vec![1u8, 2u8, 3u8], vec![4u8, 5u8], 18u8))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_min_balance { id: 7u64, min_balance: 100 },
+				)
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::set_min_balance {
+						id: MockAssetId(7),
+						min_balance: 100,
+					}) + log_cost(&min_balance_changed_log(7, 100)),
+				)
+				.expect_log(min_balance_changed_log(7, 100))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_team {
+						id: 7u64,
+						issuer: Address(MockPeaqAccount::Bob.into()),
+						admin: Address(MockPeaqAccount::Charlie.into()),
+						freezer: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.expect_cost(
+					dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+						id: MockAssetId(7),
+						issuer: MockPeaqAccount::Bob,
+						admin: MockPeaqAccount::Charlie,
+						freezer: MockPeaqAccount::Bob,
+					}) + log_cost(&team_changed_log(
+						7,
+						MockPeaqAccount::Bob,
+						MockPeaqAccount::Charlie,
+						MockPeaqAccount::Bob,
+					)),
+				)
+				.expect_log(team_changed_log(
+					7,
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::Charlie,
+					MockPeaqAccount::Bob,
+				))
+				.execute_returns(());
+
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(7),
+				MockPeaqAccount::Charlie,
+				1000
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::block { id: 7u64, who: Address(MockPeaqAccount::Charlie.into()) },
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::block {
+					id: MockAssetId(7),
+					who: MockPeaqAccount::Charlie,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::transfer_ownership {
+						id: 7u64,
+						owner: Address(MockPeaqAccount::Bob.into()),
+					},
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::transfer_ownership {
+					id: MockAssetId(7),
+					owner: MockPeaqAccount::Bob,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			// A fresh asset without accounts can be destroyed right away.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 8u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 1,
+					},
+				)
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 8u64 },
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::start_destroy {
+					id: MockAssetId(8),
+				}))
+				.expect_no_logs()
+				.execute_returns(destroy_token(8, 1));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::finish_destroy { id: 8u64, token: destroy_token(8, 1) },
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::finish_destroy {
+					id: MockAssetId(8),
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_create {
+						id: 9u64,
+						owner: Address(MockPeaqAccount::Bob.into()),
+						is_sufficient: true,
+						min_balance: 1,
+					},
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::force_create {
+					id: MockAssetId(9),
+					owner: MockPeaqAccount::Bob,
+					is_sufficient: true,
+					min_balance: 1,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_set_metadata {
+						id: 9u64,
+						name: vec![1u8, 2u8, 3u8].into(),
+						symbol: vec![4u8, 5u8].into(),
+						decimals: 18u8,
+						is_frozen: false,
+					},
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::force_set_metadata {
+					id: MockAssetId(9),
+					name: vec![1u8, 2u8, 3u8],
+					symbol: vec![4u8, 5u8],
+					decimals: 18u8,
+					is_frozen: false,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_clear_metadata { id: 9u64 },
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::force_clear_metadata {
+					id: MockAssetId(9),
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(MockPeaqAccount::Bob),
+				MockAssetId(9),
+				MockPeaqAccount::Bob,
+				100
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_transfer {
+						id: 9u64,
+						source: Address(MockPeaqAccount::Bob.into()),
+						dest: Address(MockPeaqAccount::Charlie.into()),
+						amount: 40.into(),
+					},
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::force_transfer {
+					id: MockAssetId(9),
+					source: MockPeaqAccount::Bob,
+					dest: MockPeaqAccount::Charlie,
+					amount: 40,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::David,
+					MockPeaqAccount::EVMu1Account,
+					PCall::force_asset_status {
+						id: 9u64,
+						owner: Address(MockPeaqAccount::Charlie.into()),
+						issuer: Address(MockPeaqAccount::Charlie.into()),
+						admin: Address(MockPeaqAccount::Charlie.into()),
+						freezer: Address(MockPeaqAccount::Charlie.into()),
+						min_balance: 1,
+						is_sufficient: true,
+						is_frozen: false,
+					},
+				)
+				.expect_cost(dispatch_cost(pallet_assets::Call::<Runtime>::force_asset_status {
+					id: MockAssetId(9),
+					owner: MockPeaqAccount::Charlie,
+					issuer: MockPeaqAccount::Charlie,
+					admin: MockPeaqAccount::Charlie,
+					freezer: MockPeaqAccount::Charlie,
+					min_balance: 1,
+					is_sufficient: true,
+					is_frozen: false,
+				}))
+				.expect_no_logs()
+				.execute_returns(());
+		});
+}
+
+/// Checks the gas used by the methods of the factory, recording which ones were checked.
+#[derive(Default)]
+struct GasMatrix {
+	checked: std::collections::BTreeSet<u32>,
+}
+
+impl GasMatrix {
+	fn check(
+		&mut self,
+		method: &str,
+		caller: MockPeaqAccount,
+		call: PCall,
+		cost: u64,
+		output: impl solidity::Codec,
+	) {
+		let input = call.encode();
+		self.checked.insert(u32::from_be_bytes(
+			input[..4].try_into().expect("calls start with their selector"),
+		));
+
+		// Run without the tester, whose assertions do not name the method which failed them.
+		let address = MockPeaqAccount::EVMu1Account.into();
+		let mut handle = MockHandle::new(
+			address,
+			fp_evm::Context { address, caller: caller.into(), apparent_value: U256::zero() },
+		);
+		handle.input = input;
+
+		let result = fp_evm::PrecompileSet::execute(&precompiles(), &mut handle);
+		assert_eq!(
+			result,
+			Some(Ok(fp_evm::PrecompileOutput {
+				exit_status: fp_evm::ExitSucceed::Returned,
+				output: solidity::encode_return_value(output),
+			})),
+			"{method} did not return the expected output",
+		);
+		assert_eq!(handle.gas_used, cost, "{method} did not use the expected gas");
+	}
+}
+
+/// Every method of the factory, views included, is called once and must use exactly the gas of
+/// the calls it dispatches and of the logs it emits, as converted by the `GasWeightMapping` of
+/// the mock. The storage accesses of the mock are free, so there is no margin to allow for: any
+/// drift of the gas of a method fails here, and so does a method added without a case.
+#[test]
+fn gas_matrix_of_all_methods() {
+	ExtBuilder::default()
+		.with_balances(destroy_balances())
+		.build()
+		.execute_with(|| {
+			use MockPeaqAccount::{Alice, Bob, Charlie, David};
+			let mut matrix = GasMatrix::default();
+
+			// Checked first, before any call is counted.
+			matrix.check(
+				"callStats",
+				Alice,
+				PCall::call_stats {},
+				0,
+				CallStats { block: 1, total: 0, methods: vec![] },
+			);
+			matrix.check(
+				"supportedSelectors",
+				Alice,
+				PCall::supported_selectors {},
+				0,
+				PCall::selectors()
+					.iter()
+					.map(|&selector| Bytes4::from(selector))
+					.collect::<Vec<_>>(),
+			);
+			matrix.check("selfCheck", Alice, PCall::self_check {}, 0, true);
+
+			matrix.check("reserveId", Alice, PCall::reserve_id { id: 7u64 }, 0, ());
+			matrix.check(
+				"create",
+				Alice,
+				PCall::create { id: 7u64, admin: Address(Alice.into()), min_balance: 1 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(7),
+					admin: Alice,
+					min_balance: 1,
+				}) + log_cost(&asset_created_log(7, Alice, Alice)),
+				(),
+			);
+			matrix.check(
+				"createIdempotent",
+				Alice,
+				PCall::create_idempotent { id: 7u64, admin: Address(Alice.into()), min_balance: 1 },
+				0,
+				(),
+			);
+			matrix.check(
+				"setMetadata",
+				Alice,
+				PCall::set_metadata {
+					id: 7u64,
+					name: b"Peaq".to_vec().into(),
+					symbol: b"PQ".to_vec().into(),
+					decimals: 18,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(7),
+					name: b"Peaq".to_vec(),
+					symbol: b"PQ".to_vec(),
+					decimals: 18,
+				}) + log_cost(&metadata_set_log(7, b"Peaq".to_vec(), b"PQ".to_vec(), 18)),
+				(),
+			);
+			matrix.check(
+				"setMetadataDefaults",
+				Alice,
+				PCall::set_metadata_defaults {
+					id: 7u64,
+					name: b"Peaq".to_vec().into(),
+					symbol: b"PQ".to_vec().into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(7),
+					name: b"Peaq".to_vec(),
+					symbol: b"PQ".to_vec(),
+					decimals: 18,
+				}) + log_cost(&metadata_set_log(7, b"Peaq".to_vec(), b"PQ".to_vec(), 18)),
+				(),
+			);
+			matrix.check(
+				"rename",
+				Alice,
+				PCall::rename {
+					id: 7u64,
+					name: b"Peaq".to_vec().into(),
+					symbol: b"PQ".to_vec().into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(7),
+					name: b"Peaq".to_vec(),
+					symbol: b"PQ".to_vec(),
+					decimals: 18,
+				}) + log_cost(&metadata_set_log(7, b"Peaq".to_vec(), b"PQ".to_vec(), 18)),
+				(),
+			);
+			matrix.check(
+				"setMinBalance",
+				Alice,
+				PCall::set_min_balance { id: 7u64, min_balance: 10 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_min_balance {
+					id: MockAssetId(7),
+					min_balance: 10,
+				}) + log_cost(&min_balance_changed_log(7, 10)),
+				(),
+			);
+			matrix.check(
+				"setMinBalanceBatch",
+				Alice,
+				PCall::set_min_balance_batch {
+					ids: vec![7u64].into(),
+					min_balances: vec![10u128].into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_min_balance {
+					id: MockAssetId(7),
+					min_balance: 10,
+				}) + log_cost(&min_balance_changed_log(7, 10)),
+				(),
+			);
+			matrix.check(
+				"setTeam",
+				Alice,
+				PCall::set_team {
+					id: 7u64,
+					issuer: Address(Alice.into()),
+					admin: Address(Alice.into()),
+					freezer: Address(Bob.into()),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+					id: MockAssetId(7),
+					issuer: Alice,
+					admin: Alice,
+					freezer: Bob,
+				}) + log_cost(&team_changed_log(7, Alice, Alice, Bob)),
+				(),
+			);
+			matrix.check(
+				"setSupplyCap",
+				Alice,
+				PCall::set_supply_cap { id: 7u64, cap: 1_000_000.into() },
+				log_cost(&supply_cap_changed_log(7, 1_000_000)),
+				(),
+			);
+			matrix.check(
+				"mint",
+				Alice,
+				PCall::mint { id: 7u64, beneficiary: Address(Alice.into()), amount: 1000.into() },
+				dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+					id: MockAssetId(7),
+					beneficiary: Alice,
+					amount: 1000,
+				}) + log_cost(&minted_log(7, Alice, 1000)),
+				(),
+			);
+			matrix.check(
+				"batchMint",
+				Alice,
+				PCall::batch_mint {
+					id: 7u64,
+					recipients: vec![Address(Bob.into()), Address(Charlie.into())].into(),
+					amounts: vec![U256::from(100), U256::from(100)].into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+					id: MockAssetId(7),
+					beneficiary: Bob,
+					amount: 100,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+					id: MockAssetId(7),
+					beneficiary: Charlie,
+					amount: 100,
+				}) + log_cost(&minted_log(7, Bob, 100)) +
+					log_cost(&minted_log(7, Charlie, 100)),
+				(),
+			);
+			matrix.check(
+				"burn",
+				Alice,
+				PCall::burn { id: 7u64, who: Address(Bob.into()), amount: 10.into() },
+				dispatch_cost(pallet_assets::Call::<Runtime>::burn {
+					id: MockAssetId(7),
+					who: Bob,
+					amount: 10,
+				}),
+				(),
+			);
+			matrix.check(
+				"setTransferFeeBps",
+				Alice,
+				PCall::set_transfer_fee_bps { id: 7u64, bps: 100 },
+				0,
+				(),
+			);
+			matrix.check(
+				"transferAndTouch",
+				Alice,
+				PCall::transfer_and_touch {
+					id: 7u64,
+					to: Address(David.into()),
+					amount: 50.into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::touch_other {
+					id: MockAssetId(7),
+					who: David,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+					id: MockAssetId(7),
+					target: David,
+					amount: 50,
+				}),
+				(),
+			);
+			matrix.check(
+				"batchTransfer",
+				Alice,
+				PCall::batch_transfer {
+					id: 7u64,
+					recipients: vec![Address(Bob.into()), Address(David.into())].into(),
+					amounts: vec![U256::from(10), U256::from(20)].into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+					id: MockAssetId(7),
+					target: Bob,
+					amount: 10,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+					id: MockAssetId(7),
+					target: David,
+					amount: 20,
+				}) + log_cost(&transfer_log(7, Alice, Bob, 10)) +
+					log_cost(&transfer_log(7, Alice, David, 20)),
+				(),
+			);
+			matrix.check(
+				"batchTransfer without events",
+				Alice,
+				PCall::batch_transfer_with_events {
+					id: 7u64,
+					recipients: vec![Address(Bob.into())].into(),
+					amounts: vec![U256::from(10)].into(),
+					emit_events: false,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::transfer {
+					id: MockAssetId(7),
+					target: Bob,
+					amount: 10,
+				}),
+				(),
+			);
+			matrix.check(
+				"freeze",
+				Bob,
+				PCall::freeze { id: 7u64, who: Address(David.into()) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::freeze {
+					id: MockAssetId(7),
+					who: David,
+				}),
+				(),
+			);
+			matrix.check(
+				"thaw",
+				Alice,
+				PCall::thaw { id: 7u64, who: Address(David.into()) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::thaw {
+					id: MockAssetId(7),
+					who: David,
+				}),
+				(),
+			);
+			matrix.check(
+				"block",
+				Bob,
+				PCall::block { id: 7u64, who: Address(Charlie.into()) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::block {
+					id: MockAssetId(7),
+					who: Charlie,
+				}),
+				(),
+			);
+			matrix.check(
+				"freezeAsset",
+				Bob,
+				PCall::freeze_asset { id: 7u64 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::freeze_asset { id: MockAssetId(7) }),
+				(),
+			);
+			matrix.check(
+				"thawAsset",
+				Alice,
+				PCall::thaw_asset { id: 7u64 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::thaw_asset { id: MockAssetId(7) }),
+				(),
+			);
+			matrix.check(
+				"setAssetPaused",
+				Alice,
+				PCall::set_asset_paused { id: 7u64, paused: true },
+				0,
+				(),
+			);
+			matrix.check("assetPaused", Alice, PCall::asset_paused { id: 7u64 }, 0, true);
+			// Unpaused for the methods checked next.
+			matrix.check(
+				"setAssetPaused",
+				Alice,
+				PCall::set_asset_paused { id: 7u64, paused: false },
+				0,
+				(),
+			);
+			matrix.check(
+				"approveWithDeadline",
+				Alice,
+				PCall::approve_with_deadline {
+					id: 7u64,
+					spender: Address(Bob.into()),
+					amount: 100.into(),
+					deadline_block: 10,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::approve_transfer {
+					id: MockAssetId(7),
+					delegate: Bob,
+					amount: 100,
+				}) + log_cost(&approval_log(7, Alice, Bob, 100)),
+				(),
+			);
+			matrix.check(
+				"transferApproved",
+				Bob,
+				PCall::transfer_approved {
+					id: 7u64,
+					owner: Address(Alice.into()),
+					destination: Address(David.into()),
+					amount: 40.into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::transfer_approved {
+					id: MockAssetId(7),
+					owner: Alice,
+					destination: David,
+					amount: 40,
+				}) + log_cost(&transfer_log(7, Alice, David, 40)),
+				(),
+			);
+
+			// The views only read storage.
+			matrix.check(
+				"convertAssetIdToAddress",
+				Alice,
+				PCall::convert_asset_id_to_address { id: 7u64 },
+				0,
+				Address(Runtime::asset_id_to_address(MockAssetId(7))),
+			);
+			Erc20FacadePrefix::set(Some([0xee; 4]));
+			matrix.check(
+				"erc20Address",
+				Alice,
+				PCall::erc20_address { id: 7u64 },
+				0,
+				Address(H160::from_slice(&[
+					0xee, 0xee, 0xee, 0xee, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7,
+				])),
+			);
+			matrix.check("assetCount", Alice, PCall::asset_count {}, 0, 1u32);
+			matrix.check(
+				"classifyAddress",
+				Alice,
+				PCall::classify_address {
+					address: Address(Runtime::asset_id_to_address(MockAssetId(7))),
+				},
+				0,
+				AddressClass { kind: ADDRESS_KIND_LOCAL_ASSET, id: 7 },
+			);
+			matrix.check(
+				"addressIsLiveAsset",
+				Alice,
+				PCall::address_is_live_asset {
+					address: Address(Runtime::asset_id_to_address(MockAssetId(7))),
+				},
+				0,
+				true,
+			);
+			matrix.check("callerAccount", David, PCall::caller_account {}, 0, H256(David.into()));
+			matrix.check("chainId", Alice, PCall::chain_id {}, 0, ChainId::get());
+			matrix.check("defaultDecimals", Alice, PCall::default_decimals {}, 0, 18u8);
+			matrix.check("rolesMustBeDistinct", Alice, PCall::roles_must_be_distinct {}, 0, false);
+			matrix.check(
+				"amountConversionMode",
+				Alice,
+				PCall::amount_conversion_mode {},
+				0,
+				AMOUNT_CONVERSION_CHECKED,
+			);
+			matrix.check("accountDeposit", Alice, PCall::account_deposit {}, 0, U256::zero());
+			matrix.check("minBalanceFloor", Alice, PCall::min_balance_floor {}, 0, 0u128);
+			matrix.check(
+				"createsRemaining",
+				Alice,
+				PCall::creates_remaining { account: Address(Alice.into()) },
+				0,
+				u32::MAX,
+			);
+			matrix.check(
+				"supplyCap",
+				Alice,
+				PCall::supply_cap { id: 7u64 },
+				0,
+				U256::from(1_000_000),
+			);
+			matrix.check("transferFeeBps", Alice, PCall::transfer_fee_bps { id: 7u64 }, 0, 100u16);
+			matrix.check(
+				"accountCounts",
+				Alice,
+				PCall::account_counts { id: 7u64 },
+				0,
+				AccountCounts { accounts: 4, sufficients: 0, approvals: 1 },
+			);
+			matrix.check(
+				"name",
+				Alice,
+				PCall::name { id: 7u64 },
+				0,
+				UnboundedBytes::from(b"Peaq".to_vec()),
+			);
+			matrix.check(
+				"symbol",
+				Alice,
+				PCall::symbol { id: 7u64 },
+				0,
+				UnboundedBytes::from(b"PQ".to_vec()),
+			);
+			matrix.check("decimals", Alice, PCall::decimals { id: 7u64 }, 0, 18u8);
+			matrix.check(
+				"tokenInfoBatch",
+				Alice,
+				PCall::token_info_batch { ids: vec![7u64].into() },
+				0,
+				vec![TokenInfo {
+					exists: true,
+					name: b"Peaq".to_vec().into(),
+					symbol: b"PQ".to_vec().into(),
+					decimals: 18,
+					total_supply: U256::from(1190),
+					min_balance: U256::from(10),
+					status: ASSET_STATUS_LIVE,
+				}],
+			);
+			matrix.check(
+				"lastError",
+				Alice,
+				PCall::last_error {},
+				0,
+				LastError { code: LAST_ERROR_NONE, message: UnboundedBytes::default() },
+			);
+			matrix.check(
+				"permissions",
+				Alice,
+				PCall::permissions { id: 7u64, caller: Address(Alice.into()) },
+				0,
+				PERMISSION_MINT |
+					PERMISSION_BURN | PERMISSION_TRANSFER |
+					PERMISSION_SET_METADATA |
+					PERMISSION_DESTROY,
+			);
+			matrix.check(
+				"ownerOf",
+				Alice,
+				PCall::owner_of { id: 7u64 },
+				0,
+				(Address(Alice.into()), H256(Alice.into())),
+			);
+			matrix.check(
+				"isBlocked",
+				Alice,
+				PCall::is_blocked { id: 7u64, who: Address(Charlie.into()) },
+				0,
+				true,
+			);
+			matrix.check(
+				"reservedDepositOf",
+				Alice,
+				PCall::reserved_deposit_of { id: 7u64, account: Address(Alice.into()) },
+				0,
+				U256::zero(),
+			);
+			matrix.check(
+				"approvalDepositOf",
+				Alice,
+				PCall::approval_deposit_of { id: 7u64, owner: Address(Alice.into()) },
+				0,
+				U256::zero(),
+			);
+
+			matrix.check(
+				"allowanceDeadline",
+				Alice,
+				PCall::allowance_deadline {
+					id: 7u64,
+					owner: Address(Alice.into()),
+					spender: Address(Bob.into()),
+				},
+				0,
+				10u64,
+			);
+
+			matrix.check(
+				"cancelApproval",
+				Alice,
+				PCall::cancel_approval { id: 7u64, spender: Address(Bob.into()) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::cancel_approval {
+					id: MockAssetId(7),
+					delegate: Bob,
+				}) + log_cost(&approval_log(7, Alice, Bob, 0)),
+				(),
+			);
+			let permit_secret = permit_key(b"Permit owner");
+			let permit_address = permit_owner(&permit_secret);
+			matrix.check(
+				"permit",
+				Charlie,
+				permit_call(
+					permit_address,
+					Bob,
+					100,
+					10,
+					sign_permit(&permit_secret, 7, Bob, 100, 0, 10),
+				),
+				permit::ECRECOVER_COST +
+					dispatch_cost(pallet_assets::Call::<Runtime>::approve_transfer {
+						id: MockAssetId(7),
+						delegate: Bob,
+						amount: 100,
+					}) + log_cost(&log3(
+					Runtime::asset_id_to_address(MockAssetId(7)),
+					SELECTOR_LOG_APPROVAL,
+					permit_address,
+					H160::from(Bob),
+					solidity::encode_event_data(U256::from(100)),
+				)),
+				(),
+			);
+			matrix.check(
+				"nonces",
+				Alice,
+				PCall::nonces { id: 7u64, owner: Address(permit_address) },
+				0,
+				U256::one(),
+			);
+			matrix.check(
+				"domainSeparator",
+				Alice,
+				PCall::domain_separator { id: 7u64 },
+				0,
+				permit_domain_separator(7),
+			);
+			matrix.check(
+				"transferOwnership",
+				Alice,
+				PCall::transfer_ownership { id: 7u64, owner: Address(Bob.into()) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::transfer_ownership {
+					id: MockAssetId(7),
+					owner: Bob,
+				}),
+				(),
+			);
+			pallet_evm::AccountCodes::<Runtime>::insert(
+				H160::from(Charlie),
+				vec![0x60, 0x00, 0x60, 0x00, 0x60, 0x00, 0xfd],
+			);
+			matrix.check(
+				"transferOwnershipToContract",
+				Bob,
+				PCall::transfer_ownership_to_contract {
+					id: 7u64,
+					contract_addr: Address(Charlie.into()),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::transfer_ownership {
+					id: MockAssetId(7),
+					owner: Charlie,
+				}),
+				(),
+			);
+
+			matrix.check(
+				"createWithMetadata",
+				Alice,
+				PCall::create_with_metadata {
+					id: 8u64,
+					admin: Address(Alice.into()),
+					min_balance: 1,
+					name: b"Eight".to_vec().into(),
+					symbol: b"E8".to_vec().into(),
+					decimals: 6,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(8),
+					admin: Alice,
+					min_balance: 1,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(8),
+					name: b"Eight".to_vec(),
+					symbol: b"E8".to_vec(),
+					decimals: 6,
+				}) + log_cost(&asset_created_log(8, Alice, Alice)) +
+					log_cost(&metadata_set_log(8, b"Eight".to_vec(), b"E8".to_vec(), 6)),
+				(),
+			);
+			matrix.check(
+				"createFromTemplate",
+				Alice,
+				PCall::create_from_template {
+					ids: vec![13u64].into(),
+					names: vec![b"Thirteen".to_vec().into()].into(),
+					symbols: vec![b"T13".to_vec().into()].into(),
+					admin: Address(Alice.into()),
+					min_balance: 1,
+					decimals: 6,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(13),
+					admin: Alice,
+					min_balance: 1,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(13),
+					name: b"Thirteen".to_vec(),
+					symbol: b"T13".to_vec(),
+					decimals: 6,
+				}) + log_cost(&asset_created_log(13, Alice, Alice)) +
+					log_cost(&metadata_set_log(13, b"Thirteen".to_vec(), b"T13".to_vec(), 6)),
+				(),
+			);
+			matrix.check(
+				"copyMetadata",
+				Alice,
+				PCall::copy_metadata { from: 8u64, to: 13u64 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(13),
+					name: b"Eight".to_vec(),
+					symbol: b"E8".to_vec(),
+					decimals: 6,
+				}) + log_cost(&metadata_set_log(13, b"Eight".to_vec(), b"E8".to_vec(), 6)),
+				(),
+			);
+			matrix.check(
+				"createWithTeam",
+				Alice,
+				PCall::create_with_team {
+					id: 9u64,
+					admin: Address(Alice.into()),
+					min_balance: 1,
+					issuer: Address(Bob.into()),
+					freezer: Address(Charlie.into()),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(9),
+					admin: Alice,
+					min_balance: 1,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+					id: MockAssetId(9),
+					issuer: Bob,
+					admin: Alice,
+					freezer: Charlie,
+				}) + log_cost(&asset_created_log(9, Alice, Alice)) +
+					log_cost(&team_changed_log(9, Bob, Alice, Charlie)),
+				(),
+			);
+			matrix.check(
+				"createSelfAdmin",
+				Bob,
+				PCall::create_self_admin { id: 11u64, min_balance: 1 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(11),
+					admin: Bob,
+					min_balance: 1,
+				}) + log_cost(&asset_created_log(11, Bob, Bob)),
+				(),
+			);
+			matrix.check(
+				"safeLaunch",
+				Alice,
+				PCall::safe_launch {
+					params: LaunchParams {
+						id: 12u64,
+						admin: Address(Bob.into()),
+						min_balance: 1,
+						name: b"Twelve".to_vec().into(),
+						symbol: b"TW".to_vec().into(),
+						decimals: 6,
+						issuer: Address(Alice.into()),
+						freezer: Address(Charlie.into()),
+						initial_supply: 100.into(),
+						initial_holder: Address(Bob.into()),
+					},
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::create {
+					id: MockAssetId(12),
+					admin: Bob,
+					min_balance: 1,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::set_metadata {
+					id: MockAssetId(12),
+					name: b"Twelve".to_vec(),
+					symbol: b"TW".to_vec(),
+					decimals: 6,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+					id: MockAssetId(12),
+					issuer: Alice,
+					admin: Bob,
+					freezer: Charlie,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::mint {
+					id: MockAssetId(12),
+					beneficiary: Bob,
+					amount: 100,
+				}) + log_cost(&asset_created_log(12, Alice, Bob)) +
+					log_cost(&metadata_set_log(12, b"Twelve".to_vec(), b"TW".to_vec(), 6)) +
+					log_cost(&team_changed_log(12, Alice, Bob, Charlie)) +
+					log_cost(&minted_log(12, Bob, 100)),
+				(),
+			);
+			matrix.check(
+				"transferOwnershipAndSetTeam",
+				Alice,
+				PCall::transfer_ownership_and_set_team {
+					id: 12u64,
+					new_owner: Address(Bob.into()),
+					issuer: Address(Bob.into()),
+					admin: Address(Charlie.into()),
+					freezer: Address(Alice.into()),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::set_team {
+					id: MockAssetId(12),
+					issuer: Bob,
+					admin: Charlie,
+					freezer: Alice,
+				}) + dispatch_cost(pallet_assets::Call::<Runtime>::transfer_ownership {
+					id: MockAssetId(12),
+					owner: Bob,
+				}) + log_cost(&team_changed_log(12, Bob, Charlie, Alice)),
+				(),
+			);
+
+			matrix.check(
+				"startDestroy",
+				Alice,
+				PCall::start_destroy { id: 8u64 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::start_destroy { id: MockAssetId(8) }),
+				destroy_token(8, 1),
+			);
+			matrix.check(
+				"destroyStartedAt",
+				Alice,
+				PCall::destroy_started_at { id: 8u64 },
+				0,
+				1u64,
+			);
+			matrix.check(
+				"extendedConfig",
+				Alice,
+				PCall::extended_config { id: 8u64 },
+				0,
+				ExtendedConfig {
+					destroy_token_issued_at: 1,
+					destroy_started_at: 1,
+					..Default::default()
+				},
+			);
+			matrix.check(
+				"finishDestroy",
+				Alice,
+				PCall::finish_destroy { id: 8u64, token: destroy_token(8, 1) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::finish_destroy {
+					id: MockAssetId(8),
+				}),
+				(),
+			);
+			// Without accounts nor approvals, only the final step is dispatched.
+			precompiles()
+				.prepare_test(
+					Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::start_destroy { id: 9u64 },
+				)
+				.execute_returns(destroy_token(9, 1));
+			matrix.check(
+				"forceFinishDestroy",
+				David,
+				PCall::force_finish_destroy {
+					id: 9u64,
+					token: destroy_token(9, 1),
+					max_iterations: 1,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::finish_destroy {
+					id: MockAssetId(9),
+				}),
+				true,
+			);
+
+			matrix.check(
+				"forceCreate",
+				David,
+				PCall::force_create {
+					id: 10u64,
+					owner: Address(Bob.into()),
+					is_sufficient: true,
+					min_balance: 1,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::force_create {
+					id: MockAssetId(10),
+					owner: Bob,
+					is_sufficient: true,
+					min_balance: 1,
+				}),
+				(),
+			);
+			matrix.check(
+				"forceSetMetadata",
+				David,
+				PCall::force_set_metadata {
+					id: 10u64,
+					name: b"Ten".to_vec().into(),
+					symbol: b"T".to_vec().into(),
+					decimals: 12,
+					is_frozen: false,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::force_set_metadata {
+					id: MockAssetId(10),
+					name: b"Ten".to_vec(),
+					symbol: b"T".to_vec(),
+					decimals: 12,
+					is_frozen: false,
+				}),
+				(),
+			);
+			matrix.check(
+				"forceClearMetadata",
+				David,
+				PCall::force_clear_metadata { id: 10u64 },
+				dispatch_cost(pallet_assets::Call::<Runtime>::force_clear_metadata {
+					id: MockAssetId(10),
+				}),
+				(),
+			);
+			assert_ok!(Assets::mint(RuntimeOrigin::signed(Bob), MockAssetId(10), Bob, 100));
+			matrix.check(
+				"forceTransfer",
+				David,
+				PCall::force_transfer {
+					id: 10u64,
+					source: Address(Bob.into()),
+					dest: Address(Charlie.into()),
+					amount: 40.into(),
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::force_transfer {
+					id: MockAssetId(10),
+					source: Bob,
+					dest: Charlie,
+					amount: 40,
+				}),
+				(),
+			);
+			matrix.check(
+				"forceAssetStatus",
+				David,
+				PCall::force_asset_status {
+					id: 10u64,
+					owner: Address(Charlie.into()),
+					issuer: Address(Charlie.into()),
+					admin: Address(Charlie.into()),
+					freezer: Address(Charlie.into()),
+					min_balance: 1,
+					is_sufficient: true,
+					is_frozen: false,
+				},
+				dispatch_cost(pallet_assets::Call::<Runtime>::force_asset_status {
+					id: MockAssetId(10),
+					owner: Charlie,
+					issuer: Charlie,
+					admin: Charlie,
+					freezer: Charlie,
+					min_balance: 1,
+					is_sufficient: true,
+					is_frozen: false,
+				}),
+				(),
+			);
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(Charlie),
+				MockAssetId(10),
+				MockPeaqAccount::AssetId(MockAssetId(10)),
+				100
+			));
+			matrix.check(
+				"rescueMissent",
+				David,
+				PCall::rescue_missent { id: 10u64, to: Address(Charlie.into()) },
+				dispatch_cost(pallet_assets::Call::<Runtime>::force_transfer {
+					id: MockAssetId(10),
+					source: MockPeaqAccount::AssetId(MockAssetId(10)),
+					dest: Charlie,
+					amount: 100,
+				}),
+				(),
+			);
+			matrix.check(
+				"cancelForceOperation",
+				David,
+				PCall::cancel_force_operation { id: 8u64 },
+				0,
+				(),
+			);
+
+			assert_eq!(
+				matrix.checked,
+				PCall::selectors().iter().copied().collect(),
+				"every method must have a case in the gas matrix",
+			);
+		});
+}
+
+#[test]
+fn enumeration_tags_assets_with_their_instance() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				1
+			));
+			assert_ok!(ForeignAssets::create(
+				RuntimeOrigin::signed(MockPeaqAccount::Alice),
+				MockAssetId(8),
+				MockPeaqAccount::Alice,
+				1
+			));
+
+			let mut tester = PrecompilesModifierTester::new(
+				precompiles(),
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu2Account,
+			);
+			tester.test_view_modifier(ECall::assets_selectors());
+			tester.test_view_modifier(ECall::assets_page_selectors());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu2Account,
+					ECall::assets {},
 				)
 				.expect_no_logs()
+				.execute_returns(vec![
+					InstanceAsset { instance: 0, id: 7 },
+					InstanceAsset { instance: 1, id: 8 },
+				]);
+		});
+}
+
+#[test]
+fn enumeration_pages_through_all_the_assets() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			let origin = RuntimeOrigin::signed(MockPeaqAccount::Alice);
+			for id in 1..=5 {
+				assert_ok!(Assets::create(
+					origin.clone(),
+					MockAssetId(id),
+					MockPeaqAccount::Alice,
+					1
+				));
+			}
+			for id in [8, 9] {
+				assert_ok!(ForeignAssets::create(
+					origin.clone(),
+					MockAssetId(id),
+					MockPeaqAccount::Alice,
+					1
+				));
+			}
+
+			// The assets are listed in the order of their storage keys, instance by instance.
+			let local_key =
+				|id: u128| assets_storage::Asset::<Runtime, ()>::hashed_key_for(MockAssetId(id));
+			let foreign_key = |id: u128| {
+				assets_storage::Asset::<Runtime, pallet_assets::Instance1>::hashed_key_for(
+					MockAssetId(id),
+				)
+			};
+			let mut local_ids: Vec<u128> = (1..=5).collect();
+			local_ids.sort_by_key(|id| local_key(*id));
+			let mut foreign_ids = vec![8u128, 9];
+			foreign_ids.sort_by_key(|id| foreign_key(*id));
+			let ordered: Vec<(u8, u64, Vec<u8>)> = local_ids
+				.into_iter()
+				.map(|id| (0, id as u64, local_key(id)))
+				.chain(foreign_ids.into_iter().map(|id| (1, id as u64, foreign_key(id))))
+				.collect();
+			let assets = |entries: &[(u8, u64, Vec<u8>)]| -> Vec<InstanceAsset> {
+				entries
+					.iter()
+					.map(|(instance, id, _)| InstanceAsset { instance: *instance, id: *id })
+					.collect()
+			};
+
+			let page = |start_key: Vec<u8>, limit: u32| {
+				precompiles.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu2Account,
+					ECall::assets_page { start_key: start_key.into(), limit },
+				)
+			};
+
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu2Account,
+					ECall::assets {},
+				)
+				.execute_returns(assets(&ordered));
+
+			// Pages of 3 assets span both instances, each starting right after the last asset of
+			// the previous one, so that none is skipped nor returned twice.
+			page(Vec::new(), 3).expect_no_logs().execute_returns((
+				assets(&ordered[0..3]),
+				UnboundedBytes::from(ordered[2].2.clone()),
+			));
+			page(ordered[2].2.clone(), 3).execute_returns((
+				assets(&ordered[3..6]),
+				UnboundedBytes::from(ordered[5].2.clone()),
+			));
+			page(ordered[5].2.clone(), 3)
+				.execute_returns((assets(&ordered[6..]), UnboundedBytes::from(Vec::new())));
+
+			// Destroying the asset a cursor points to does not invalidate the cursor.
+			assert_ok!(Assets::start_destroy(origin.clone(), MockAssetId(ordered[2].1.into())));
+			assert_ok!(Assets::finish_destroy(origin, MockAssetId(ordered[2].1.into())));
+			page(ordered[2].2.clone(), 3).execute_returns((
+				assets(&ordered[3..6]),
+				UnboundedBytes::from(ordered[5].2.clone()),
+			));
+
+			page(vec![1u8; 64], 3).execute_reverts(|output| output == b"startKey: unknown cursor");
+			page(Vec::new(), 0).execute_reverts(|output| output == b"limit: must be at least 1");
+		});
+}
+
+#[test]
+fn events_abi_matches_emitted_logs() {
+	let abi =
+		|name: &str| *EVENTS.iter().find(|event| event.name == name).expect("event is published");
+
+	let asset_created = abi("AssetCreated");
+	assert_eq!(asset_created.signature(), b"AssetCreated(uint64,address,address)".to_vec());
+	assert_eq!(asset_created.selector(), SELECTOR_LOG_ASSET_CREATED);
+	let metadata_set = abi("MetadataSet");
+	assert_eq!(metadata_set.signature(), b"MetadataSet(uint64,bytes,bytes,uint8)".to_vec());
+	assert_eq!(metadata_set.selector(), SELECTOR_LOG_METADATA_SET);
+	let min_balance_changed = abi("MinBalanceChanged");
+	assert_eq!(min_balance_changed.signature(), b"MinBalanceChanged(uint64,uint128)".to_vec());
+	assert_eq!(min_balance_changed.selector(), SELECTOR_LOG_MIN_BALANCE_CHANGED);
+	let log = min_balance_changed_log(7, 100);
+	assert_eq!(log.topics[0], H256(min_balance_changed.selector()));
+	assert_eq!(log.topics.len(), min_balance_changed.topics());
+	let supply_cap_changed = abi("SupplyCapChanged");
+	assert_eq!(supply_cap_changed.signature(), b"SupplyCapChanged(uint64,uint256)".to_vec());
+	assert_eq!(supply_cap_changed.selector(), SELECTOR_LOG_SUPPLY_CAP_CHANGED);
+	let log = supply_cap_changed_log(7, 1000);
+	assert_eq!(log.topics[0], H256(supply_cap_changed.selector()));
+	assert_eq!(log.topics.len(), supply_cap_changed.topics());
+	let transfer = abi("Transfer");
+	assert_eq!(transfer.signature(), b"Transfer(address,address,uint256)".to_vec());
+	assert_eq!(transfer.selector(), SELECTOR_LOG_TRANSFER);
+	let minted = abi("Minted");
+	assert_eq!(minted.signature(), b"Minted(uint64,address,uint256)".to_vec());
+	assert_eq!(minted.selector(), SELECTOR_LOG_MINTED);
+	let team_changed = abi("TeamChanged");
+	assert_eq!(team_changed.signature(), b"TeamChanged(uint64,address,address,address)".to_vec());
+	assert_eq!(team_changed.selector(), SELECTOR_LOG_TEAM_CHANGED);
+	let log =
+		team_changed_log(7, MockPeaqAccount::Bob, MockPeaqAccount::Charlie, MockPeaqAccount::David);
+	assert_eq!(log.topics[0], H256(team_changed.selector()));
+	assert_eq!(log.topics.len(), team_changed.topics());
+	let log = minted_log(7, MockPeaqAccount::Bob, 100);
+	assert_eq!(log.topics[0], H256(minted.selector()));
+	assert_eq!(log.topics.len(), minted.topics());
+	let log = transfer_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100);
+	assert_eq!(log.topics[0], H256(transfer.selector()));
+	assert_eq!(log.topics.len(), transfer.topics());
+	let approval = abi("Approval");
+	assert_eq!(approval.signature(), b"Approval(address,address,uint256)".to_vec());
+	assert_eq!(approval.selector(), SELECTOR_LOG_APPROVAL);
+	let log = approval_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob, 100);
+	assert_eq!(log.topics[0], H256(approval.selector()));
+	assert_eq!(log.topics.len(), approval.topics());
+
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			let log = asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Bob);
+			assert_eq!(log.topics[0], H256(asset_created.selector()));
+			assert_eq!(log.topics.len(), asset_created.topics());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Bob.into()),
+						min_balance: 500,
+					},
+				)
+				.expect_log(log)
+				.execute_returns(());
+
+			let log = metadata_set_log(7, vec![1u8, 2u8, 3u8], vec![4u8, 5u8], 18u8);
+			assert_eq!(log.topics[0], H256(metadata_set.selector()));
+			assert_eq!(log.topics.len(), metadata_set.topics());
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::set_metadata {
+						id: 7u64,
+						name: vec![1u8, 2u8, 3u8].into(),
+						symbol: vec![4u8, 5u8].into(),
+						decimals: 18u8,
+					},
+				)
+				.expect_log(log)
 				.execute_returns(());
 		});
 }
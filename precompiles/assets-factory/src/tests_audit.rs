@@ -0,0 +1,329 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests of the audit trail of a factory compiled with the `audit` feature. Run them with:
+//!
+//! ```sh
+//! cargo test -p pallet-evm-precompile-assets-factory --features audit
+//! ```
+
+use crate::{mock::*, *};
+
+use frame_support::assert_ok;
+use pallet_evm::Log;
+use precompile_utils::{
+	prelude::{log2, log3},
+	testing::*,
+};
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+fn action_performed_log(caller: MockPeaqAccount, selector: u32, id: u64) -> Log {
+	let mut data = vec![0u8; 32];
+	data[..4].copy_from_slice(&selector.to_be_bytes());
+
+	log3(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_ACTION_PERFORMED,
+		H160::from(caller),
+		H256::from_low_u64_be(id),
+		data,
+	)
+}
+
+fn asset_created_log(id: u64, creator: MockPeaqAccount, admin: MockPeaqAccount) -> Log {
+	log3(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_ASSET_CREATED,
+		H256::from_low_u64_be(id),
+		H160::from(creator),
+		solidity::encode_event_data(Address(admin.into())),
+	)
+}
+
+fn create_rejected_log(id: u64, reason: u8) -> Log {
+	log2(
+		MockPeaqAccount::EVMu1Account,
+		SELECTOR_LOG_CREATE_REJECTED,
+		H256::from_low_u64_be(id),
+		solidity::encode_event_data(reason),
+	)
+}
+
+#[test]
+fn action_performed_abi_matches_the_log() {
+	let abi = EVENTS
+		.iter()
+		.find(|event| event.name == "ActionPerformed")
+		.expect("event is published");
+
+	assert_eq!(abi.signature(), b"ActionPerformed(address,bytes4,uint64)".to_vec());
+	assert_eq!(abi.selector(), SELECTOR_LOG_ACTION_PERFORMED);
+	let log = action_performed_log(MockPeaqAccount::Alice, 0x9c28547e, 7);
+	assert_eq!(log.topics[0], H256(abi.selector()));
+	assert_eq!(log.topics.len(), abi.topics());
+}
+
+#[test]
+fn mutating_methods_log_the_caller_selector_and_id() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			// create(uint64,address,uint128)
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 7u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 1,
+					},
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Alice, 0x9c28547e, 7))
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.execute_returns(());
+
+			// mint(uint64,address,uint256)
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::mint {
+						id: 7u64,
+						beneficiary: Address(MockPeaqAccount::Bob.into()),
+						amount: 500.into(),
+					},
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Alice, 0xf2be4599, 7))
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 500);
+
+			// burn(uint64,address,uint256)
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::burn {
+						id: 7u64,
+						who: Address(MockPeaqAccount::Bob.into()),
+						amount: 200.into(),
+					},
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Alice, 0xa4fa3313, 7))
+				.execute_returns(());
+			assert_eq!(Assets::balance(MockAssetId(7), MockPeaqAccount::Bob), 300);
+		});
+}
+
+#[test]
+fn safe_launch_logs_the_id_of_its_parameters() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let params = LaunchParams {
+				id: 9,
+				admin: Address(MockPeaqAccount::Bob.into()),
+				min_balance: 1,
+				name: b"Nine".to_vec().into(),
+				symbol: b"NIN".to_vec().into(),
+				decimals: 12,
+				issuer: Address(MockPeaqAccount::Bob.into()),
+				freezer: Address(MockPeaqAccount::Bob.into()),
+				initial_supply: 0.into(),
+				initial_holder: Address(MockPeaqAccount::Bob.into()),
+			};
+
+			// safeLaunch, whose parameters are encoded after an offset
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::safe_launch { params },
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Bob, 0xf2445571, 9))
+				.expect_log(asset_created_log(9, MockPeaqAccount::Bob, MockPeaqAccount::Bob))
+				.expect_log(log2(
+					MockPeaqAccount::EVMu1Account,
+					SELECTOR_LOG_METADATA_SET,
+					H256::from_low_u64_be(9),
+					solidity::encode_event_data((
+						UnboundedBytes::from(b"Nine".to_vec()),
+						UnboundedBytes::from(b"NIN".to_vec()),
+						12u8,
+					)),
+				))
+				.expect_log(log2(
+					MockPeaqAccount::EVMu1Account,
+					SELECTOR_LOG_TEAM_CHANGED,
+					H256::from_low_u64_be(9),
+					solidity::encode_event_data((
+						Address(MockPeaqAccount::Bob.into()),
+						Address(MockPeaqAccount::Bob.into()),
+						Address(MockPeaqAccount::Bob.into()),
+					)),
+				))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn views_log_no_action() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::supply_cap { id: 7u64 },
+				)
+				.expect_no_logs()
+				.execute_returns(U256::zero());
+		});
+}
+
+#[test]
+fn create_from_template_logs_each_of_its_ids() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// createFromTemplate(uint64[],bytes[],bytes[],address,uint128,uint8), logged before any
+			// create
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create_from_template {
+						ids: vec![7u64, 8u64].into(),
+						names: vec![b"Seven".to_vec().into(), b"Eight".to_vec().into()].into(),
+						symbols: vec![b"S7".to_vec().into(), b"E8".to_vec().into()].into(),
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 1,
+						decimals: 12,
+					},
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Alice, 0xb9be431f, 7))
+				.expect_log(action_performed_log(MockPeaqAccount::Alice, 0xb9be431f, 8))
+				.expect_log(asset_created_log(7, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.expect_log(log2(
+					MockPeaqAccount::EVMu1Account,
+					SELECTOR_LOG_METADATA_SET,
+					H256::from_low_u64_be(7),
+					solidity::encode_event_data((
+						UnboundedBytes::from(b"Seven".to_vec()),
+						UnboundedBytes::from(b"S7".to_vec()),
+						12u8,
+					)),
+				))
+				.expect_log(asset_created_log(8, MockPeaqAccount::Alice, MockPeaqAccount::Alice))
+				.expect_log(log2(
+					MockPeaqAccount::EVMu1Account,
+					SELECTOR_LOG_METADATA_SET,
+					H256::from_low_u64_be(8),
+					solidity::encode_event_data((
+						UnboundedBytes::from(b"Eight".to_vec()),
+						UnboundedBytes::from(b"E8".to_vec()),
+						12u8,
+					)),
+				))
+				.execute_returns(());
+		});
+}
+
+#[test]
+fn create_rejected_abi_matches_the_log() {
+	let abi = EVENTS
+		.iter()
+		.find(|event| event.name == "CreateRejected")
+		.expect("event is published");
+
+	assert_eq!(abi.signature(), b"CreateRejected(uint64,uint8)".to_vec());
+	assert_eq!(abi.selector(), SELECTOR_LOG_CREATE_REJECTED);
+	let log = create_rejected_log(7, CREATE_REJECTED_ALREADY_EXISTS);
+	assert_eq!(log.topics[0], H256(abi.selector()));
+	assert_eq!(log.topics.len(), abi.topics());
+}
+
+#[test]
+fn create_logs_the_rejection_of_a_disallowed_id_before_reverting() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000)])
+		.build()
+		.execute_with(|| {
+			// The id of the native token cannot be created.
+			precompiles()
+				.prepare_test(
+					MockPeaqAccount::Alice,
+					MockPeaqAccount::EVMu1Account,
+					PCall::create {
+						id: 0u64,
+						admin: Address(MockPeaqAccount::Alice.into()),
+						min_balance: 1,
+					},
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Alice, 0x9c28547e, 0))
+				.expect_log(create_rejected_log(0, CREATE_REJECTED_NOT_ALLOWED))
+				.execute_reverts(|output| output == b"Invalid asset id");
+		});
+}
+
+#[test]
+fn create_logs_the_rejection_of_existing_and_reserved_ids() {
+	ExtBuilder::default()
+		.with_balances(vec![(MockPeaqAccount::Alice, 5000), (MockPeaqAccount::Bob, 5000)])
+		.build()
+		.execute_with(|| {
+			let precompiles = precompiles();
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				MockAssetId(7),
+				MockPeaqAccount::Alice,
+				true,
+				1
+			));
+			precompiles
+				.prepare_test(
+					MockPeaqAccount::Bob,
+					MockPeaqAccount::EVMu1Account,
+					PCall::reserve_id { id: 8u64 },
+				)
+				.expect_log(action_performed_log(MockPeaqAccount::Bob, 0x8ff49ee6, 8))
+				.execute_returns(());
+
+			for (id, reason, output) in [
+				(7, CREATE_REJECTED_ALREADY_EXISTS, &b"id: asset already exists"[..]),
+				(8, CREATE_REJECTED_RESERVED, &b"id reserved by another account"[..]),
+			] {
+				precompiles
+					.prepare_test(
+						MockPeaqAccount::Alice,
+						MockPeaqAccount::EVMu1Account,
+						PCall::create {
+							id,
+							admin: Address(MockPeaqAccount::Alice.into()),
+							min_balance: 1,
+						},
+					)
+					.expect_log(action_performed_log(MockPeaqAccount::Alice, 0x9c28547e, id))
+					.expect_log(create_rejected_log(id, reason))
+					.execute_reverts(|reverted| reverted == output);
+			}
+		});
+}
@@ -0,0 +1,91 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Tests of a factory compiled with only the `create` group of methods. Run them with:
+//!
+//! ```sh
+//! cargo test -p pallet-evm-precompile-assets-factory --no-default-features --features std,create
+//! ```
+
+use crate::{mock::*, *};
+
+use precompile_utils::testing::*;
+
+fn precompiles() -> Precompiles<Runtime> {
+	PrecompilesValue::get()
+}
+
+#[test]
+fn only_create_and_view_selectors_are_compiled() {
+	// convertAssetIdToAddress, classifyAddress, callerAccount, chainId, defaultDecimals, create,
+	// createSelfAdmin, createIdempotent, createWithMetadata, setMetadata, setMetadataDefaults,
+	// setMinBalance, createsRemaining, supplyCap, transferFeeBps, accountCounts, tokenInfoBatch,
+	// lastError, callStats, permissions, isBlocked, reservedDepositOf, allowanceDeadline,
+	// nonces, domainSeparator, rolesMustBeDistinct, name, symbol, decimals, accountDeposit,
+	// destroyStartedAt, addressIsLiveAsset, reserveId, minBalanceFloor, extendedConfig, rename,
+	// assetPaused, ownerOf, supportedSelectors, createFromTemplate, approvalDepositOf,
+	// selfCheck, copyMetadata, setMinBalanceBatch, erc20Address, amountConversionMode,
+	// assetCount
+	for selector in [
+		0xa70174cb, 0x5b046ea2, 0x49e71f1d, 0x9a8a0592, 0x30f08abd, 0x9c28547e, 0x1afcff92,
+		0x5c2597b7, 0x206bfee8, 0xf96ee86d, 0x87e3eed5, 0x28bfefa1, 0xdfc3dab8, 0x84e0e2ad,
+		0x49b7940b, 0x39d54988, 0x6120b296, 0x29f0de3f, 0xfcb1d7ae, 0xd1dae163, 0x51c6b1e2,
+		0xdb398b0a, 0x9e5de11e, 0x38a6d67e, 0xe9b8305a, 0xa9a85140, 0x20fc811e, 0x7904fe8c,
+		0xff01a6f7, 0xbb384740, 0x8bbe21c7, 0x17fef253, 0x8ff49ee6, 0x29801d91, 0x1b48dd4d,
+		0x9f41d4a2, 0xb1213295, 0x7903a617, 0x8d057a25, 0xb9be431f, 0xe45eeaae, 0x1785e185,
+		0xca14fa7c, 0x91b14ce2, 0x1a296d66, 0x394b6c5a, 0xeafe7a74,
+	] {
+		assert!(PCall::supports_selector(selector), "{selector:#010x} should be supported");
+	}
+
+	// createWithTeam, safeLaunch, setTeam, transferOwnership, transferOwnershipToContract,
+	// startDestroy, finishDestroy, forceFinishDestroy, block, freeze, thaw, freezeAsset,
+	// thawAsset, mint, burn, batchMint, setSupplyCap, setTransferFeeBps, transferAndTouch,
+	// batchTransfer (both), approveWithDeadline, transferApproved, cancelApproval, forceCreate,
+	// forceSetMetadata, forceClearMetadata, forceAssetStatus, forceTransfer, rescueMissent, permit,
+	// transferOwnershipAndSetTeam, cancelForceOperation, setAssetPaused
+	for selector in [
+		0xfb589a2d, 0xb6e6b7d4, 0x0a94864e, 0xd17e790a, 0x13f946af, 0xacd19595, 0x4430b8fa,
+		0x63422462, 0x4d1a1635, 0xef33e57a, 0xfdade1d5, 0xc8ae38b6, 0xf2be4599, 0xa4fa3313,
+		0x3c2dcdcf, 0xab1c3510, 0x6947288f, 0xa58745dd, 0xadf58a37, 0x1191da99, 0xd86d35ae,
+		0x81051e8a, 0x1074f6ea, 0xa4075e04, 0xaa814f2c, 0xbdd2b221, 0x74293733, 0x69098f87,
+		0x709ea72e, 0xf2445571, 0x49448b04, 0xc20ea395, 0x706d9b49, 0x13a30b0d,
+	] {
+		assert!(!PCall::supports_selector(selector), "{selector:#010x} should not be supported");
+	}
+}
+
+#[test]
+fn compiled_out_method_is_an_unknown_selector() {
+	ExtBuilder::default().build().execute_with(|| {
+		// startDestroy(uint64)
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				solidity::encode_with_selector(0x13f946afu32, 7u64),
+			)
+			.execute_reverts(|output| output == b"Unknown selector");
+	});
+}
+
+#[test]
+fn supported_selectors_leave_out_compiled_out_methods() {
+	ExtBuilder::default().build().execute_with(|| {
+		let selectors: Vec<Bytes4> =
+			PCall::selectors().iter().map(|&selector| Bytes4::from(selector)).collect();
+		// mint(uint64,address,uint256) belongs to the `issuance` group.
+		assert!(!selectors.contains(&Bytes4::from(0xf2be4599)));
+		assert!(selectors.contains(&Bytes4::from(0x9c28547e)));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::supported_selectors {},
+			)
+			.execute_returns(selectors);
+	});
+}
@@ -40,6 +40,7 @@ impl Precompile {
 		let type_parameters = self.generics.type_params().map(|p| &p.ident);
 
 		let variants: Vec<_> = self.variants_content.keys().collect();
+		let variants_cfg: Vec<_> = self.variants_content.values().map(|v| &v.cfg_attrs).collect();
 		let idents: Vec<Vec<_>> = self
 			.variants_content
 			.values()
@@ -55,6 +56,7 @@ impl Precompile {
 			#[allow(non_camel_case_types)]
 			pub enum #enum_ident #ty_generics #where_clause {
 				#(
+					#(#variants_cfg)*
 					#variants {
 						#(
 							#idents: #types
@@ -76,6 +78,7 @@ impl Precompile {
 		let span = Span::call_site();
 
 		let fn_parse = self.variants_content.keys().map(Self::variant_ident_to_parse_fn);
+		let fn_parse_cfg = self.variants_content.values().map(|variant| &variant.cfg_attrs);
 
 		let modifier_check = self.variants_content.values().map(|variant| {
 			let modifier = match variant.modifier {
@@ -99,6 +102,7 @@ impl Precompile {
 
 		quote!(
 			#(
+				#(#fn_parse_cfg)*
 				fn #fn_parse(
 					handle: &mut impl PrecompileHandle
 				) -> ::precompile_utils::EvmResult<Self> {
@@ -149,11 +153,21 @@ impl Precompile {
 		let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
 		let match_selectors = self.selector_to_variant.keys();
+		let match_selectors_cfg: Vec<_> = self.selectors_cfg_attrs().collect();
 		let match_selectors2 = self.selector_to_variant.keys();
+		let (view_selectors_cfg, view_selectors): (Vec<_>, Vec<_>) = self
+			.selector_to_variant
+			.iter()
+			.map(|(selector, variant)| (selector, &self.variants_content[variant]))
+			.filter(|(_, variant)| matches!(variant.modifier, Modifier::View))
+			.map(|(selector, variant)| (&variant.cfg_attrs, selector))
+			.unzip();
 
 		let variants_parsing = self.expand_variants_parse_fn();
 
 		let variants_ident2: Vec<_> = self.variants_content.keys().collect();
+		let variants_cfg: Vec<_> =
+			self.variants_content.values().map(|variant| &variant.cfg_attrs).collect();
 		let variants_selectors_fn: Vec<_> = self
 			.variants_content
 			.keys()
@@ -185,6 +199,7 @@ impl Precompile {
 				pub fn supports_selector(selector: u32) -> bool {
 					match selector {
 						#(
+							#(#match_selectors_cfg)*
 							#match_selectors => true,
 						)*
 						_ => false,
@@ -193,11 +208,20 @@ impl Precompile {
 
 				pub fn selectors() -> &'static [u32] {
 					&[#(
+						#(#match_selectors_cfg)*
 						#match_selectors2
 					),*]
 				}
 
+				pub fn view_selectors() -> &'static [u32] {
+					&[#(
+						#(#view_selectors_cfg)*
+						#view_selectors
+					),*]
+				}
+
 				#(
+					#(#variants_cfg)*
 					pub fn #variants_selectors_fn() -> &'static [u32] {
 						&[#(
 							#variants_selectors
@@ -209,6 +233,7 @@ impl Precompile {
 					use ::precompile_utils::solidity::codec::Writer;
 					match self {
 						#(
+							#(#variants_cfg)*
 							Self::#variants_ident2 { #(#variants_list),* } => {
 								#variants_encode
 							},
@@ -233,6 +258,8 @@ impl Precompile {
 		let impl_type = &self.impl_type;
 
 		let variants_ident: Vec<_> = self.variants_content.keys().collect();
+		let variants_cfg: Vec<_> =
+			self.variants_content.values().map(|variant| &variant.cfg_attrs).collect();
 
 		let variants_arguments: Vec<Vec<_>> = self
 			.variants_content
@@ -278,6 +305,7 @@ impl Precompile {
 
 				let output = match self {
 					#(
+						#(#variants_cfg)*
 						Self::#variants_ident { #(#variants_arguments),* } => {
 							#variants_call
 						},
@@ -318,6 +346,7 @@ impl Precompile {
 	/// input, dispatch the decoding to one of the variants parsing function.
 	fn expand_enum_parse_call_data(&self) -> impl ToTokens {
 		let selectors = self.selector_to_variant.keys();
+		let selectors_cfg = self.selectors_cfg_attrs();
 		let parse_fn = self.selector_to_variant.values().map(Self::variant_ident_to_parse_fn);
 
 		let match_fallback = match &self.fallback_to_variant {
@@ -348,6 +377,7 @@ impl Precompile {
 
 				match selector {
 					#(
+						#(#selectors_cfg)*
 						Some(#selectors) => Self::#parse_fn(handle),
 					)*
 					#match_fallback
@@ -360,6 +390,13 @@ impl Precompile {
 		format_ident!("_parse_{}", ident)
 	}
 
+	/// `cfg` attributes of the variant handling each selector, in the selectors order.
+	fn selectors_cfg_attrs(&self) -> impl Iterator<Item = &Vec<syn::Attribute>> {
+		self.selector_to_variant
+			.values()
+			.map(|variant| &self.variants_content[variant].cfg_attrs)
+	}
+
 	/// Expands the impl of the Precomile(Set) trait.
 	pub fn expand_precompile_impl(&self) -> impl ToTokens {
 		let impl_type = &self.impl_type;
@@ -451,8 +488,10 @@ impl Precompile {
 				let solidity = &variant.solidity_arguments_type;
 				let name = ident.to_string();
 				let types: Vec<_> = variant.arguments.iter().map(|arg| &arg.ty).collect();
+				let cfg_attrs = &variant.cfg_attrs;
 
 				quote_spanned!(span=>
+					#(#cfg_attrs)*
 					assert_eq!(
 						#solidity,
 						<(#(#types,)*) as Codec>::signature(),
@@ -121,6 +121,10 @@ struct Variant {
 
 	/// Output of the variant fn (for better error messages).
 	fn_output: syn::Type,
+
+	/// `cfg` attributes of the method, copied on every item generated for this variant such that
+	/// a method can be compiled out.
+	cfg_attrs: Vec<syn::Attribute>,
 }
 
 #[derive(Debug)]
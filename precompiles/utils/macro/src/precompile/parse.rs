@@ -278,6 +278,17 @@ impl Precompile {
 			arguments.push(Argument { ident, ty })
 		}
 
+		// Conditional compilation attributes, which must also be applied to the generated code.
+		let cfg_attrs: Vec<_> =
+			method.attrs.iter().filter(|attr| attr.path.is_ident("cfg")).cloned().collect();
+
+		if is_fallback {
+			if let Some(attr) = cfg_attrs.first() {
+				let msg = "The fallback function cannot be conditionally compiled";
+				return Err(syn::Error::new(attr.span(), msg))
+			}
+		}
+
 		// Function output.
 		let output_type = match &method.sig.output {
 			syn::ReturnType::Type(_, t) => t,
@@ -299,6 +310,7 @@ impl Precompile {
 					modifier,
 					selectors,
 					fn_output: output_type.as_ref().clone(),
+					cfg_attrs,
 				},
 			)
 			.is_some()
@@ -214,6 +214,9 @@ where
     pub fn selectors() -> &'static [u32] {
         &[2044677020u32, 2531431096u32, 3473183175u32]
     }
+    pub fn view_selectors() -> &'static [u32] {
+        &[]
+    }
     pub fn batch_all_selectors() -> &'static [u32] {
         &[2531431096u32]
     }
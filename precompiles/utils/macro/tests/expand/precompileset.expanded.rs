@@ -819,6 +819,9 @@ where
             4173303445u32,
         ]
     }
+    pub fn view_selectors() -> &'static [u32] {
+        &[910484757u32, 2127478272u32]
+    }
     pub fn allowance_selectors() -> &'static [u32] {
         &[3714247998u32]
     }
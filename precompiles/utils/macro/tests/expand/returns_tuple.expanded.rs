@@ -77,6 +77,9 @@ impl ExamplePrecompileCall {
     pub fn selectors() -> &'static [u32] {
         &[1412775727u32]
     }
+    pub fn view_selectors() -> &'static [u32] {
+        &[]
+    }
     pub fn example_selectors() -> &'static [u32] {
         &[1412775727u32]
     }
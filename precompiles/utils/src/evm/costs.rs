@@ -17,10 +17,13 @@
 //! Cost calculations.
 //! TODO: PR EVM to make those cost calculations public.
 
-use crate::EvmResult;
-use fp_evm::{ExitError, PrecompileFailure};
+use crate::{solidity::revert::revert, EvmResult};
 use sp_core::U256;
 
+/// Reason of the revert of a precompile whose gas would overflow `u64` once a cost is added to
+/// it, which the gasometer would otherwise wrap around.
+pub const GAS_ACCOUNTING_OVERFLOW: &str = "gas accounting overflow";
+
 pub fn log_costs(topics: usize, data_len: usize) -> EvmResult<u64> {
 	// Cost calculation is copied from EVM code that is not publicly exposed by the crates.
 	// https://github.com/rust-blockchain/evm/blob/master/gasometer/src/costs.rs#L148
@@ -31,17 +34,17 @@ pub fn log_costs(topics: usize, data_len: usize) -> EvmResult<u64> {
 
 	let topic_cost = G_LOGTOPIC
 		.checked_mul(topics as u64)
-		.ok_or(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })?;
+		.ok_or_else(|| revert(GAS_ACCOUNTING_OVERFLOW))?;
 
 	let data_cost = G_LOGDATA
 		.checked_mul(data_len as u64)
-		.ok_or(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })?;
+		.ok_or_else(|| revert(GAS_ACCOUNTING_OVERFLOW))?;
 
 	G_LOG
 		.checked_add(topic_cost)
-		.ok_or(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })?
+		.ok_or_else(|| revert(GAS_ACCOUNTING_OVERFLOW))?
 		.checked_add(data_cost)
-		.ok_or(PrecompileFailure::Error { exit_status: ExitError::OutOfGas })
+		.ok_or_else(|| revert(GAS_ACCOUNTING_OVERFLOW))
 }
 
 // Compute the cost of doing a subcall.
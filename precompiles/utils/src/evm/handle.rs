@@ -15,10 +15,11 @@
 // along with Moonbeam.  If not, see <http://www.gnu.org/licenses/>.
 
 use crate::{
+	evm::costs::GAS_ACCOUNTING_OVERFLOW,
 	solidity::{
 		codec::Reader,
 		modifier::FunctionModifier,
-		revert::{MayRevert, RevertReason},
+		revert::{revert, MayRevert, RevertReason},
 	},
 	EvmResult,
 };
@@ -30,7 +31,11 @@ pub trait PrecompileHandleExt: PrecompileHandle {
 	fn record_db_read<Runtime: pallet_evm::Config>(
 		&mut self,
 		data_max_encoded_len: usize,
-	) -> Result<(), evm::ExitError>;
+	) -> EvmResult;
+
+	/// Record a cost, reverting with [`GAS_ACCOUNTING_OVERFLOW`] if adding it to the gas used
+	/// would overflow, instead of letting the gasometer wrap the gas used around past the limit.
+	fn record_cost_checked(&mut self, cost: u64) -> EvmResult;
 
 	/// Record cost of a log manually.
 	/// This can be useful to record log costs early when their content have static size.
@@ -54,16 +59,36 @@ impl<T: PrecompileHandle> PrecompileHandleExt for T {
 	fn record_db_read<Runtime: pallet_evm::Config>(
 		&mut self,
 		data_max_encoded_len: usize,
-	) -> Result<(), evm::ExitError> {
-		self.record_cost(crate::prelude::RuntimeHelper::<Runtime>::db_read_gas_cost())?;
+	) -> EvmResult {
+		self.record_cost_checked(crate::prelude::RuntimeHelper::<Runtime>::db_read_gas_cost())?;
 		// TODO: record ref time when precompile will be benchmarked
-		self.record_external_cost(None, Some(data_max_encoded_len as u64), None)
+		let proof_size =
+			u64::try_from(data_max_encoded_len).map_err(|_| revert(GAS_ACCOUNTING_OVERFLOW))?;
+		self.record_external_cost(None, Some(proof_size), None)?;
+
+		Ok(())
+	}
+
+	fn record_cost_checked(&mut self, cost: u64) -> EvmResult {
+		// The gas used plus the remaining gas is the limit, so the gas used can only overflow past
+		// a cost the remaining gas does not cover.
+		let remaining_gas = self.remaining_gas();
+		if cost > remaining_gas {
+			let used_gas = self.gas_limit().unwrap_or(remaining_gas).saturating_sub(remaining_gas);
+			if used_gas.checked_add(cost).is_none() {
+				return Err(revert(GAS_ACCOUNTING_OVERFLOW))
+			}
+		}
+
+		self.record_cost(cost)?;
+
+		Ok(())
 	}
 
 	/// Record cost of a log manualy.
 	/// This can be useful to record log costs early when their content have static size.
 	fn record_log_costs_manual(&mut self, topics: usize, data_len: usize) -> EvmResult {
-		self.record_cost(crate::evm::costs::log_costs(topics, data_len)?)?;
+		self.record_cost_checked(crate::evm::costs::log_costs(topics, data_len)?)?;
 
 		Ok(())
 	}
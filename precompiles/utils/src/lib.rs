@@ -24,7 +24,9 @@ extern crate self as precompile_utils;
 
 pub mod evm;
 pub mod precompile_set;
+pub mod storage;
 pub mod substrate;
+pub mod transient;
 
 pub mod solidity;
 
@@ -63,6 +65,7 @@ pub mod prelude {
 					BoundedBytes,
 					BoundedString,
 					BoundedVec,
+					Bytes4,
 					// Allow usage of Codec methods while not exporting the name directly.
 					Codec as _,
 					Convert,
@@ -74,7 +77,9 @@ pub mod prelude {
 					RevertReason,
 				},
 			},
-			substrate::{RuntimeHelper, TryDispatchError, SYSTEM_ACCOUNT_SIZE},
+			substrate::{
+				RuntimeHelper, TryDispatchError, OUT_OF_GAS_REVERT_PREFIX, SYSTEM_ACCOUNT_SIZE,
+			},
 			EvmResult,
 		},
 		alloc::string::String,
@@ -21,6 +21,7 @@
 use crate::{
 	evm::handle::PrecompileHandleExt,
 	solidity::{codec::String, revert::revert},
+	transient::{using_transient_storage, TransientStorage},
 	EvmResult,
 };
 use fp_evm::{
@@ -313,7 +314,7 @@ pub enum AddressType {
 pub fn get_address_type<R: pallet_evm::Config>(
 	handle: &mut impl PrecompileHandle,
 	address: H160,
-) -> Result<AddressType, ExitError> {
+) -> EvmResult<AddressType> {
 	// AccountCodesMetadata:
 	// Blake2128(16) + H160(20) + CodeMetadata(40)
 	handle.record_db_read::<R>(76)?;
@@ -342,7 +343,7 @@ pub fn get_address_type<R: pallet_evm::Config>(
 fn is_address_eoa_or_precompile<R: pallet_evm::Config>(
 	handle: &mut impl PrecompileHandle,
 	address: H160,
-) -> Result<bool, ExitError> {
+) -> EvmResult<bool> {
 	match get_address_type::<R>(handle, address)? {
 		AddressType::EOA | AddressType::Precompile => Ok(true),
 		_ => Ok(false),
@@ -1002,14 +1003,24 @@ where
 }
 
 /// Wraps a tuple of `PrecompileSetFragment` to make a real `PrecompileSet`.
+///
+/// A new instance is used for each transaction, which holds the transient storage of the
+/// precompiles.
 pub struct PrecompileSetBuilder<R, P> {
 	inner: P,
+	transient_storage: RefCell<TransientStorage>,
 	_phantom: PhantomData<R>,
 }
 
 impl<R: pallet_evm::Config, P: PrecompileSetFragment> PrecompileSet for PrecompileSetBuilder<R, P> {
 	fn execute(&self, handle: &mut impl PrecompileHandle) -> Option<PrecompileResult> {
-		self.inner.execute::<R>(handle)
+		// Subcalls execute within the transient storage of the outer call, which is still
+		// borrowed.
+		match self.transient_storage.try_borrow_mut() {
+			Ok(mut storage) =>
+				using_transient_storage(&mut storage, || self.inner.execute::<R>(handle)),
+			Err(_) => self.inner.execute::<R>(handle),
+		}
 	}
 
 	fn is_precompile(&self, address: H160, gas: u64) -> IsPrecompileResult {
@@ -1032,7 +1043,11 @@ impl<R: pallet_evm::Config, P: PrecompileSetFragment> Default for PrecompileSetB
 impl<R: pallet_evm::Config, P: PrecompileSetFragment> PrecompileSetBuilder<R, P> {
 	/// Create a new instance of the PrecompileSet.
 	pub fn new() -> Self {
-		Self { inner: P::new(), _phantom: PhantomData }
+		Self {
+			inner: P::new(),
+			transient_storage: RefCell::new(TransientStorage::new()),
+			_phantom: PhantomData,
+		}
 	}
 
 	/// Note: In peaq, we are use H256 as the AccountID, but eth address is H160, therefore, it's
@@ -160,6 +160,54 @@ impl Codec for Address {
 	}
 }
 
+/// The `bytes4` type of Solidity, e.g. for function selectors. Unlike a `uint32`, it is written
+/// at the start of its 32 bytes word.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Bytes4(pub [u8; 4]);
+
+impl From<u32> for Bytes4 {
+	fn from(value: u32) -> Bytes4 {
+		Bytes4(value.to_be_bytes())
+	}
+}
+
+impl From<Bytes4> for u32 {
+	fn from(value: Bytes4) -> u32 {
+		u32::from_be_bytes(value.0)
+	}
+}
+
+impl Codec for Bytes4 {
+	fn read(reader: &mut Reader) -> MayRevert<Self> {
+		let range = reader.move_cursor(32)?;
+
+		let data = reader
+			.input
+			.get(range)
+			.ok_or_else(|| RevertReason::read_out_of_bounds("bytes4"))?;
+
+		let mut value = [0u8; 4];
+		value.copy_from_slice(&data[..4]);
+
+		Ok(Bytes4(value))
+	}
+
+	fn write(writer: &mut Writer, value: Self) {
+		let mut word = [0u8; 32];
+		word[..4].copy_from_slice(&value.0);
+
+		writer.data.extend_from_slice(&word);
+	}
+
+	fn has_static_size() -> bool {
+		true
+	}
+
+	fn signature() -> String {
+		String::from("bytes4")
+	}
+}
+
 impl Codec for U256 {
 	fn read(reader: &mut Reader) -> MayRevert<Self> {
 		let range = reader.move_cursor(32)?;
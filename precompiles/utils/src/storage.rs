@@ -0,0 +1,92 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Storage owned by precompiles.
+//!
+//! Precompiles have no pallet to declare their storage in, so the state they keep is stored
+//! under [`PRECOMPILE_STORAGE_PREFIX`], followed by the name of the precompile and the name of the
+//! item. The items of different precompiles are thus isolated even if they share a name.
+//!
+//! The helpers do not record any gas: like for pallet storage, callers record the read of
+//! [`PrecompileStorageMap::entry_max_encoded_len`] bytes or the write before accessing it.
+
+use frame_support::{storage::unhashed, Blake2_128Concat, StorageHasher};
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Prefix of the storage of all the precompiles.
+pub const PRECOMPILE_STORAGE_PREFIX: &[u8] = b"PrecompileStorage";
+
+/// Names identifying a storage item owned by a precompile.
+pub trait StorageItemNames {
+	/// Name of the precompile owning the item.
+	const PRECOMPILE: &'static str;
+	/// Name of the item, unique among the items of the precompile.
+	const ITEM: &'static str;
+}
+
+/// Map from `Key` to `Value` owned by a precompile, whose entries are stored at
+/// `twox128(PRECOMPILE_STORAGE_PREFIX) ++ twox128(PRECOMPILE) ++ twox128(ITEM) ++
+/// blake2_128_concat(key)`. A single value can be stored with a `()` key.
+pub struct PrecompileStorageMap<Names, Key, Value>(PhantomData<(Names, Key, Value)>);
+
+impl<Names, Key, Value> PrecompileStorageMap<Names, Key, Value>
+where
+	Names: StorageItemNames,
+	Key: Encode + MaxEncodedLen,
+	Value: Encode + Decode + MaxEncodedLen,
+{
+	/// Storage key of the entry of `key`.
+	pub fn hashed_key(key: &Key) -> Vec<u8> {
+		let mut hashed_key = Self::prefix();
+		hashed_key.extend_from_slice(&Blake2_128Concat::hash(&key.encode()));
+
+		hashed_key
+	}
+
+	/// Storage key prefix of all the entries of the map.
+	pub fn prefix() -> Vec<u8> {
+		let mut prefix = Vec::with_capacity(48);
+		prefix.extend_from_slice(&sp_io::hashing::twox_128(PRECOMPILE_STORAGE_PREFIX));
+		prefix.extend_from_slice(&sp_io::hashing::twox_128(Names::PRECOMPILE.as_bytes()));
+		prefix.extend_from_slice(&sp_io::hashing::twox_128(Names::ITEM.as_bytes()));
+
+		prefix
+	}
+
+	/// Maximum size of an entry, to record the cost of reading it.
+	pub fn entry_max_encoded_len() -> usize {
+		// Blake2_128Concat hash of the key, and the value.
+		16 + Key::max_encoded_len() + Value::max_encoded_len()
+	}
+
+	/// Returns the value of `key`, if any.
+	pub fn get(key: &Key) -> Option<Value> {
+		unhashed::get(&Self::hashed_key(key))
+	}
+
+	/// Whether `key` has a value.
+	pub fn contains_key(key: &Key) -> bool {
+		unhashed::exists(&Self::hashed_key(key))
+	}
+
+	/// Sets the value of `key`.
+	pub fn insert(key: &Key, value: &Value) {
+		unhashed::put(&Self::hashed_key(key), value)
+	}
+
+	/// Sets the value of `key`, removing it if `value` is `None`.
+	pub fn set(key: &Key, value: Option<&Value>) {
+		match value {
+			Some(value) => Self::insert(key, value),
+			None => Self::remove(key),
+		}
+	}
+
+	/// Removes the value of `key`.
+	pub fn remove(key: &Key) {
+		unhashed::kill(&Self::hashed_key(key))
+	}
+}
@@ -36,18 +36,40 @@ pub const SYSTEM_ACCOUNT_SIZE: u64 = 160;
 pub enum TryDispatchError {
 	Evm(ExitError),
 	Substrate(DispatchError),
+	/// The remaining gas does not cover the weight of the call, which costs this much gas. Only
+	/// returned by `try_dispatch_tagged`, `try_dispatch` returning `Evm(ExitError::OutOfGas)`.
+	OutOfGas(u64),
 }
 
+/// Prefix of the revert reason of a call dispatched with `try_dispatch_tagged` without enough
+/// gas, telling it apart from the call failing.
+pub const OUT_OF_GAS_REVERT_PREFIX: &str = "out of gas: ";
+
 impl From<TryDispatchError> for PrecompileFailure {
 	fn from(f: TryDispatchError) -> PrecompileFailure {
 		match f {
 			TryDispatchError::Evm(e) => PrecompileFailure::Error { exit_status: e },
 			TryDispatchError::Substrate(e) =>
 				revert(alloc::format!("Dispatched call failed with error: {e:?}")),
+			TryDispatchError::OutOfGas(required_gas) => revert(alloc::format!(
+				"{OUT_OF_GAS_REVERT_PREFIX}dispatch needs {required_gas} gas"
+			)),
 		}
 	}
 }
 
+/// Converts `weight` to gas with `Mapping`, saturating at `u64::MAX` instead of relying on the
+/// mapping not to wrap. A weight at least as large as the weight of `u64::MAX` gas is charged
+/// the whole gas range, so that an extreme weight cannot be under-metered.
+pub fn saturating_weight_to_gas<Mapping: GasWeightMapping>(weight: Weight) -> u64 {
+	let max_weight = Mapping::gas_to_weight(u64::MAX, true);
+	if weight.ref_time() >= max_weight.ref_time() {
+		return u64::MAX
+	}
+
+	Mapping::weight_to_gas(weight)
+}
+
 /// Helper functions requiring a Substrate runtime.
 /// This runtime must of course implement `pallet_evm::Config`.
 #[derive(Clone, Copy, Debug)]
@@ -66,7 +88,7 @@ where
 	) -> Result<(), ExitError> {
 		// Make sure there is enough gas.
 		let remaining_gas = handle.remaining_gas();
-		let required_gas = Runtime::GasWeightMapping::weight_to_gas(weight);
+		let required_gas = Self::weight_to_gas(weight);
 		if required_gas > remaining_gas {
 			return Err(ExitError::OutOfGas)
 		}
@@ -91,7 +113,7 @@ where
 		} else {
 			weight
 		};
-		let used_gas = Runtime::GasWeightMapping::weight_to_gas(used_weight);
+		let used_gas = Self::weight_to_gas(used_weight);
 		handle.record_cost(used_gas)?;
 		Ok(used_gas)
 	}
@@ -127,23 +149,46 @@ where
 
 		Ok(post_dispatch_info)
 	}
+
+	/// Like `try_dispatch`, but running out of gas reverts with a reason starting with
+	/// [`OUT_OF_GAS_REVERT_PREFIX`] and giving the gas the call costs, instead of halting.
+	/// Callers can then tell it apart from a failure of the call, which is worth retrying with
+	/// more gas while the failure is not.
+	pub fn try_dispatch_tagged<Call>(
+		handle: &mut impl PrecompileHandle,
+		origin: <Runtime::RuntimeCall as Dispatchable>::RuntimeOrigin,
+		call: Call,
+		storage_growth: u64,
+	) -> Result<PostDispatchInfo, TryDispatchError>
+	where
+		Runtime::RuntimeCall: From<Call>,
+	{
+		let call = Runtime::RuntimeCall::from(call);
+		let required_gas = Self::weight_to_gas(call.get_dispatch_info().weight);
+
+		Self::try_dispatch(handle, origin, call, storage_growth).map_err(|error| match error {
+			TryDispatchError::Evm(ExitError::OutOfGas) => TryDispatchError::OutOfGas(required_gas),
+			error => error,
+		})
+	}
 }
 
 impl<Runtime> RuntimeHelper<Runtime>
 where
 	Runtime: pallet_evm::Config,
 {
+	/// Cost of `weight` in gas, saturating instead of wrapping for extreme weights.
+	pub fn weight_to_gas(weight: Weight) -> u64 {
+		saturating_weight_to_gas::<<Runtime as pallet_evm::Config>::GasWeightMapping>(weight)
+	}
+
 	/// Cost of a Substrate DB write in gas.
 	pub fn db_write_gas_cost() -> u64 {
-		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
-			<Runtime as frame_system::Config>::DbWeight::get().writes(1),
-		)
+		Self::weight_to_gas(<Runtime as frame_system::Config>::DbWeight::get().writes(1))
 	}
 
 	/// Cost of a Substrate DB read in gas.
 	pub fn db_read_gas_cost() -> u64 {
-		<Runtime as pallet_evm::Config>::GasWeightMapping::weight_to_gas(
-			<Runtime as frame_system::Config>::DbWeight::get().reads(1),
-		)
+		Self::weight_to_gas(<Runtime as frame_system::Config>::DbWeight::get().reads(1))
 	}
 }
@@ -224,6 +224,14 @@ impl TryFrom<u64> for MockAssetId {
 	}
 }
 
+impl TryFrom<MockAssetId> for u64 {
+	type Error = ();
+
+	fn try_from(asset_id: MockAssetId) -> Result<Self, Self::Error> {
+		asset_id.0.try_into().map_err(|_| ())
+	}
+}
+
 impl peaq_primitives_xcm::AssetIdExt for MockAssetId {
 	fn is_native_token(&self) -> bool {
 		self.0 == 0
@@ -148,7 +148,12 @@ impl PrecompileHandle for MockHandle {
 	}
 
 	fn record_cost(&mut self, cost: u64) -> Result<(), ExitError> {
-		self.gas_used += cost;
+		// With the default limit of `u64::MAX`, the costs of a large batch could otherwise wrap
+		// the gas used around and pass the limit check.
+		self.gas_used = self
+			.gas_used
+			.checked_add(cost)
+			.ok_or_else(|| ExitError::Other("gas accounting overflow".into()))?;
 
 		if self.gas_used > self.gas_limit {
 			Err(ExitError::OutOfGas)
@@ -24,10 +24,13 @@ use crate::{
 		modifier::{check_function_modifier, FunctionModifier},
 		revert::Backtrace,
 	},
+	storage::{PrecompileStorageMap, StorageItemNames, PRECOMPILE_STORAGE_PREFIX},
+	substrate::saturating_weight_to_gas,
+	transient::{using_transient_storage, TransientStorage, TransientStorageMap},
 };
-use frame_support::traits::ConstU32;
+use frame_support::{traits::ConstU32, weights::Weight};
 use hex_literal::hex;
-use pallet_evm::Context;
+use pallet_evm::{Context, GasWeightMapping};
 use sp_core::{H160, H256, U256};
 use sp_std::convert::TryInto;
 use xcm::latest::{Junction, Junctions, NetworkId};
@@ -117,6 +120,30 @@ fn read_u128() {
 	assert_eq!(value, parsed);
 }
 
+#[test]
+fn write_bytes4() {
+	let value = Bytes4::from(0x12345678u32);
+
+	let writer_output = Writer::new().write(value).build();
+
+	let mut expected_output = [0u8; 32];
+	expected_output[..4].copy_from_slice(&[0x12, 0x34, 0x56, 0x78]);
+
+	assert_eq!(writer_output, expected_output);
+}
+
+#[test]
+fn read_bytes4() {
+	let value = Bytes4::from(0x12345678u32);
+	let writer_output = Writer::new().write(value).build();
+
+	let mut reader = Reader::new(&writer_output);
+	let parsed: Bytes4 = reader.read().expect("to correctly parse bytes4");
+
+	assert_eq!(value, parsed);
+	assert_eq!(u32::from(parsed), 0x12345678);
+}
+
 #[test]
 fn write_u256() {
 	let value = U256::from(42);
@@ -1023,3 +1050,129 @@ fn evm_data_solidity_types() {
 	// Struct encode like tuples
 	assert_eq!(MultiLocation::signature(), "(uint8,bytes[])");
 }
+
+struct FactoryCounter;
+impl StorageItemNames for FactoryCounter {
+	const PRECOMPILE: &'static str = "AssetsFactory";
+	const ITEM: &'static str = "Counter";
+}
+
+struct XcmUtilsCounter;
+impl StorageItemNames for XcmUtilsCounter {
+	const PRECOMPILE: &'static str = "XcmUtils";
+	const ITEM: &'static str = "Counter";
+}
+
+#[test]
+fn precompile_storage_round_trip() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		type Counter = PrecompileStorageMap<FactoryCounter, u64, (u32, H256)>;
+
+		assert_eq!(Counter::get(&7), None);
+		assert!(!Counter::contains_key(&7));
+
+		Counter::insert(&7, &(1, H256::repeat_byte(0x11)));
+		assert_eq!(Counter::get(&7), Some((1, H256::repeat_byte(0x11))));
+		assert!(Counter::contains_key(&7));
+		assert_eq!(Counter::get(&8), None);
+
+		Counter::set(&7, Some(&(2, H256::repeat_byte(0x22))));
+		assert_eq!(Counter::get(&7), Some((2, H256::repeat_byte(0x22))));
+
+		Counter::remove(&7);
+		assert_eq!(Counter::get(&7), None);
+		Counter::insert(&7, &(3, H256::zero()));
+		Counter::set(&7, None);
+		assert!(!Counter::contains_key(&7));
+
+		// Blake2_128Concat hash of the key, the key and the value.
+		assert_eq!(Counter::entry_max_encoded_len(), 16 + 8 + 4 + 32);
+	});
+}
+
+#[test]
+fn precompile_storage_is_isolated_between_precompiles() {
+	sp_io::TestExternalities::default().execute_with(|| {
+		type FactoryItem = PrecompileStorageMap<FactoryCounter, u64, u32>;
+		type XcmUtilsItem = PrecompileStorageMap<XcmUtilsCounter, u64, u32>;
+
+		assert_ne!(FactoryItem::prefix(), XcmUtilsItem::prefix());
+		assert!(FactoryItem::hashed_key(&7)
+			.starts_with(&sp_io::hashing::twox_128(PRECOMPILE_STORAGE_PREFIX)));
+
+		FactoryItem::insert(&7, &1);
+		assert_eq!(XcmUtilsItem::get(&7), None);
+
+		XcmUtilsItem::insert(&7, &2);
+		assert_eq!(FactoryItem::get(&7), Some(1));
+		assert_eq!(XcmUtilsItem::get(&7), Some(2));
+
+		FactoryItem::remove(&7);
+		assert_eq!(FactoryItem::get(&7), None);
+		assert_eq!(XcmUtilsItem::get(&7), Some(2));
+	});
+}
+
+#[test]
+fn transient_storage_is_scoped_to_its_execution() {
+	type FactoryItem = TransientStorageMap<FactoryCounter, u64, u32>;
+	type XcmUtilsItem = TransientStorageMap<XcmUtilsCounter, u64, u32>;
+
+	// No transient storage outside of an execution.
+	FactoryItem::insert(&7, &1);
+	assert_eq!(FactoryItem::get(&7), None);
+
+	let mut storage = TransientStorage::new();
+	using_transient_storage(&mut storage, || {
+		FactoryItem::insert(&7, &1);
+		assert_eq!(FactoryItem::get(&7), Some(1));
+		assert_eq!(XcmUtilsItem::get(&7), None);
+
+		// Nested executions share the storage of the outer one.
+		let mut nested = TransientStorage::new();
+		using_transient_storage(&mut nested, || {
+			assert_eq!(FactoryItem::get(&7), Some(1));
+			XcmUtilsItem::insert(&7, &2);
+		});
+		assert_eq!(XcmUtilsItem::get(&7), Some(2));
+		assert!(nested.is_empty());
+	});
+	assert_eq!(FactoryItem::get(&7), None);
+
+	using_transient_storage(&mut storage, || {
+		assert_eq!(FactoryItem::get(&7), Some(1));
+		FactoryItem::remove(&7);
+		assert_eq!(FactoryItem::get(&7), None);
+	});
+}
+
+/// Mapping charging 1000 gas per unit of weight, with a wrapping multiplication.
+struct WrappingGasWeightMapping;
+impl GasWeightMapping for WrappingGasWeightMapping {
+	fn gas_to_weight(gas: u64, _without_base_weight: bool) -> Weight {
+		Weight::from_parts(gas / 1000, 0)
+	}
+	fn weight_to_gas(weight: Weight) -> u64 {
+		weight.ref_time().wrapping_mul(1000)
+	}
+}
+
+#[test]
+fn weight_to_gas_saturates_instead_of_wrapping() {
+	let weight_to_gas = saturating_weight_to_gas::<WrappingGasWeightMapping>;
+
+	assert_eq!(weight_to_gas(Weight::from_parts(7, 0)), 7000);
+	assert_eq!(
+		weight_to_gas(Weight::from_parts(u64::MAX / 1000 - 1, 0)),
+		u64::MAX / 1000 * 1000 - 1000
+	);
+
+	// Weights whose cost exceeds the gas range are charged all of it.
+	assert_eq!(weight_to_gas(Weight::from_parts(u64::MAX / 1000, 0)), u64::MAX);
+	assert_eq!(weight_to_gas(Weight::from_parts(u64::MAX - 1, u64::MAX)), u64::MAX);
+
+	// Whereas the mapping alone wraps, here to no gas at all.
+	let extreme = Weight::from_parts(1 << 61, 0);
+	assert_eq!(WrappingGasWeightMapping::weight_to_gas(extreme), 0);
+	assert_eq!(weight_to_gas(extreme), u64::MAX);
+}
@@ -0,0 +1,69 @@
+// This file is part of Peaq.
+
+// Copyright (C) 2019-2023 Peaq Technologies Pte.Ltd.
+// SPDX-License-Identifier: GPL-3.0-or-later
+
+//! Storage owned by precompiles lasting for a single transaction.
+//!
+//! Frontier reverts the storage changes of a call frame when it fails, so state describing a
+//! failure cannot be kept in storage. A [`PrecompileSetBuilder`] is instead created for each
+//! transaction, and holds in memory the transient storage made available to the precompiles it
+//! executes. It is not reverted along with the call frames and is dropped with the transaction.
+//!
+//! Outside of the execution of a [`PrecompileSetBuilder`] there is no transient storage: reads
+//! return `None` and writes are ignored.
+//!
+//! [`PrecompileSetBuilder`]: crate::precompile_set::PrecompileSetBuilder
+
+use crate::storage::StorageItemNames;
+use parity_scale_codec::{Decode, Encode};
+use sp_std::{collections::btree_map::BTreeMap, marker::PhantomData, vec::Vec};
+
+/// Entries of the transient storage, by encoded key.
+pub type TransientStorage = BTreeMap<Vec<u8>, Vec<u8>>;
+
+environmental::environmental!(TRANSIENT_STORAGE: TransientStorage);
+
+/// Executes `f` with `storage` as the transient storage, unless a transient storage is already
+/// in use, like in the subcalls of a precompile.
+pub fn using_transient_storage<R, F: FnOnce() -> R>(storage: &mut TransientStorage, f: F) -> R {
+	if TRANSIENT_STORAGE::with(|_| ()).is_some() {
+		f()
+	} else {
+		TRANSIENT_STORAGE::using(storage, f)
+	}
+}
+
+/// Map from `Key` to `Value` owned by a precompile, kept in the transient storage.
+pub struct TransientStorageMap<Names, Key, Value>(PhantomData<(Names, Key, Value)>);
+
+impl<Names, Key, Value> TransientStorageMap<Names, Key, Value>
+where
+	Names: StorageItemNames,
+	Key: Encode,
+	Value: Encode + Decode,
+{
+	fn storage_key(key: &Key) -> Vec<u8> {
+		(Names::PRECOMPILE, Names::ITEM, key).encode()
+	}
+
+	/// Returns the value of `key`, if any.
+	pub fn get(key: &Key) -> Option<Value> {
+		TRANSIENT_STORAGE::with(|storage| {
+			storage
+				.get(&Self::storage_key(key))
+				.and_then(|value| Value::decode(&mut &value[..]).ok())
+		})
+		.flatten()
+	}
+
+	/// Sets the value of `key`.
+	pub fn insert(key: &Key, value: &Value) {
+		TRANSIENT_STORAGE::with(|storage| storage.insert(Self::storage_key(key), value.encode()));
+	}
+
+	/// Removes the value of `key`.
+	pub fn remove(key: &Key) {
+		TRANSIENT_STORAGE::with(|storage| storage.remove(&Self::storage_key(key)));
+	}
+}
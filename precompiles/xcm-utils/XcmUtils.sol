@@ -18,6 +18,15 @@ interface XcmUtils {
         bytes[] interior;
     }
 
+    /// Get the location of the account a given address is mapped to, as the runtime converts
+    /// accounts to locations
+    /// @custom:selector 2c6c182e
+    /// @param addr The address of the account
+    function addressToMultilocation(address addr)
+        external
+        view
+        returns (Multilocation memory location);
+
      /// Get the weight that a message will consume in our chain
     /// @custom:selector 25d54154
     /// @param message scale encoded xcm mversioned xcm message
@@ -26,6 +35,34 @@ interface XcmUtils {
         view
         returns (uint64 weight);
 
+    /// Get the weight of each instruction of a message, nested messages counting towards the
+    /// instruction they belong to
+    /// @custom:selector ce80064e
+    /// @param message scale encoded versioned xcm message
+    function instructionWeights(bytes memory message)
+        external
+        view
+        returns (uint64[] memory weights);
+
+    /// Get the fee, in the native token, of the weight a message will consume in our chain, as
+    /// the runtime charges its transactions
+    /// @custom:selector 21eae0c6
+    /// @param message scale encoded versioned xcm message
+    function quoteXcmFeeNative(bytes memory message)
+        external
+        view
+        returns (uint256 fee);
+
+    /// Get the names of the instructions of a message, in order, the instructions of nested
+    /// messages not being listed. Reverts if any instruction, nested ones included, fails to
+    /// decode, the message being decoded as a whole
+    /// @custom:selector b5b4a64a
+    /// @param message scale encoded versioned xcm message
+    function describeXcm(bytes memory message)
+        external
+        view
+        returns (string[] memory names);
+
     /// Get units per second charged for a given multilocation
     /// @custom:selector 3f0f65db
     /// @param multilocation scale encoded xcm mversioned xcm message
@@ -34,6 +71,84 @@ interface XcmUtils {
         view
         returns (uint256 unitsPerSecond);
 
+    /// Get the amount of a given asset charged for some weight, as derived from its units per
+    /// second, to buy exactly that weight with BuyExecution
+    /// @custom:selector 8524d790
+    /// @param asset The location of the asset paying for the weight
+    /// @param weight The ref time to buy
+    function feeAssetForWeight(Multilocation memory asset, uint64 weight)
+        external
+        view
+        returns (uint256 amount);
+
+    /// Get the maximum number of assets the holding register can hold, above which a message
+    /// traps its assets
+    /// @custom:selector cf791083
+    function maxAssetsIntoHolding() external view returns (uint32 maxAssets);
+
+    /// Get the version of XCM negotiated with a given destination, or type(uint32).max if none
+    /// has been negotiated yet
+    /// @custom:selector bbec1384
+    function xcmVersionOf(Multilocation memory dest)
+        external
+        view
+        returns (uint32 version);
+
+    /// Check whether a message can be routed to a given destination
+    /// @custom:selector db53ece3
+    /// @param dest The destination chain to check
+    function canReach(Multilocation memory dest)
+        external
+        view
+        returns (bool reachable);
+
+    /// Get a location with the bytes of its general keys beyond their length zeroed and the bytes
+    /// trailing its junctions dropped. Nothing else is rewritten, so locations reaching the same
+    /// place through other junctions still differ. Reverts if a general key is longer than 32
+    /// bytes
+    /// @custom:selector e8b43463
+    /// @param location The location to normalize
+    function normalizeLocation(Multilocation memory location)
+        external
+        view
+        returns (Multilocation memory normalized);
+
+    /// Build a message withdrawing fees, buying execution with them and transacting a call as the
+    /// sovereign account of the sender, to be passed to xcmSend
+    /// @custom:selector e9e6f0e0
+    /// @param feeAsset The asset paying for the execution
+    /// @param feeAmount The amount of the fee asset to withdraw
+    /// @param call The scale encoded call to transact
+    /// @param transactWeight The maximum weight the call may consume
+    /// @return message The scale encoded versioned message
+    function buildTransact(
+        Multilocation memory feeAsset,
+        uint64 feeAmount,
+        bytes memory call,
+        uint64 transactWeight
+    ) external view returns (bytes memory message);
+
+    /// Check whether a call would pass the filter the runtime applies to the calls transacted by
+    /// messages
+    /// @custom:selector c9268347
+    /// @param call The scale encoded call
+    function callIsSafe(bytes memory call) external view returns (bool safe);
+
+    /// Check whether assets dropped by a message from a given origin are trapped and can be claimed
+    /// @custom:selector 04a2fd42
+    /// @param origin The origin of the message that dropped the assets
+    /// @param assets The scale encoded versioned assets
+    function assetsTrapped(Multilocation memory origin, bytes memory assets)
+        external
+        view
+        returns (bool trapped);
+
+    /// Check whether assets dropped by a message from the location of the caller are trapped, so
+    /// that the caller can claim them
+    /// @custom:selector 6cb7faa9
+    /// @param assets The scale encoded versioned assets
+    function canClaimTrapped(bytes memory assets) external view returns (bool trapped);
+
     /// Execute custom xcm message
     /// @dev This function CANNOT be called from a smart contract
     /// @custom:selector 34334a02
@@ -46,4 +161,9 @@ interface XcmUtils {
     /// @param dest The destination chain to which send this message
     /// @param message The versioned message to be sent scale-encoded
     function xcmSend(Multilocation memory dest, bytes memory message) external;
+
+    /// Send custom xcm message to the relay chain
+    /// @custom:selector 79b15de0
+    /// @param message The versioned message to be sent scale-encoded
+    function sendToRelay(bytes memory message) external;
 }
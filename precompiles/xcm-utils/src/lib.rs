@@ -18,64 +18,136 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use fp_evm::PrecompileHandle;
+extern crate alloc;
+
+use fp_evm::{PrecompileFailure, PrecompileHandle};
 use frame_support::{
 	dispatch::{GetDispatchInfo, PostDispatchInfo},
-	traits::ConstU32,
+	traits::{ConstU32, Contains, Get},
+	weights::constants::WEIGHT_REF_TIME_PER_SECOND,
 };
 use pallet_evm::AddressMapping;
-use parity_scale_codec::{Decode, DecodeLimit, MaxEncodedLen};
+use parity_scale_codec::{Decode, DecodeLimit, Encode, MaxEncodedLen};
 use precompile_utils::{precompile_set::SelectorFilter, prelude::*};
 use sp_core::{H160, U256};
-use sp_runtime::traits::Dispatchable;
+use sp_runtime::traits::{BlakeTwo256, Convert, Dispatchable, Hash};
 use sp_std::{boxed::Box, marker::PhantomData, vec, vec::Vec};
 use sp_weights::Weight;
-use xcm::{latest::prelude::*, VersionedXcm, MAX_XCM_DECODE_DEPTH};
+use xcm::{
+	latest::prelude::*, GetVersion, VersionedMultiAssets, VersionedXcm, MAX_XCM_DECODE_DEPTH,
+};
 use xcm_executor::traits::{WeightBounds, WeightTrader};
 
 pub type SystemCallOf<Runtime> = <Runtime as frame_system::Config>::RuntimeCall;
 pub const XCM_SIZE_LIMIT: u32 = 2u32.pow(16);
 type GetXcmSizeLimit = ConstU32<XCM_SIZE_LIMIT>;
 
+/// Returned by `xcmVersionOf` for the destinations no version has been negotiated with.
+pub const UNKNOWN_XCM_VERSION: u32 = u32::MAX;
+
+/// Collects the name at the start of what is written to it, failing at the first character which
+/// cannot be part of a name so that the formatting stops there.
+#[derive(Default)]
+struct NameWriter(String);
+
+impl core::fmt::Write for NameWriter {
+	fn write_str(&mut self, s: &str) -> core::fmt::Result {
+		let len = s.find(|c: char| !c.is_ascii_alphanumeric()).unwrap_or(s.len());
+		self.0.push_str(&s[..len]);
+
+		if len < s.len() {
+			Err(core::fmt::Error)
+		} else {
+			Ok(())
+		}
+	}
+}
+
+/// Returns the name of `instruction`, e.g. `WithdrawAsset`, which its derived `Debug` output
+/// starts with.
+fn instruction_name<Call>(instruction: &Instruction<Call>) -> String {
+	let mut name = NameWriter::default();
+	// Fails once the name is written, without formatting the operands.
+	let _ = core::fmt::write(&mut name, format_args!("{instruction:?}"));
+
+	name.0
+}
+
 #[cfg(test)]
 mod mock;
 #[cfg(test)]
 mod tests;
 
+/// Filter allowing all the selectors except the ones `Selectors` returns, so that runtimes can
+/// choose which methods contracts cannot call.
 #[derive(Debug)]
-pub struct AllExceptXcmExecute<Runtime, XcmConfig>(PhantomData<(Runtime, XcmConfig)>);
+pub struct AllExcept<Selectors>(PhantomData<Selectors>);
 
-impl<Runtime, XcmConfig> SelectorFilter for AllExceptXcmExecute<Runtime, XcmConfig>
+impl<Selectors: Get<Vec<u32>>> SelectorFilter for AllExcept<Selectors> {
+	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
+		selector.map_or(true, |selector| !Selectors::get().contains(&selector))
+	}
+
+	fn description() -> String {
+		let blocked: Vec<_> = Selectors::get()
+			.iter()
+			.map(|selector| alloc::format!("{selector:#010x}"))
+			.collect();
+
+		alloc::format!("Allowed for all callers for all selectors except {}", blocked.join(", "))
+	}
+}
+
+/// Selectors of `xcmExecute`.
+#[derive(Debug)]
+pub struct XcmExecuteSelectors<Runtime, XcmConfig, AccountIdToLocation, WeightToFee>(
+	PhantomData<(Runtime, XcmConfig, AccountIdToLocation, WeightToFee)>,
+);
+
+impl<Runtime, XcmConfig, AccountIdToLocation, WeightToFee> Get<Vec<u32>>
+	for XcmExecuteSelectors<Runtime, XcmConfig, AccountIdToLocation, WeightToFee>
 where
 	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config,
 	XcmConfig: xcm_executor::Config,
+	AccountIdToLocation: Convert<Runtime::AccountId, MultiLocation>,
+	WeightToFee: frame_support::weights::WeightToFee,
+	WeightToFee::Balance: Into<U256>,
 	SystemCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + Decode + GetDispatchInfo,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
 	<Runtime as frame_system::Config>::RuntimeCall: From<pallet_xcm::Call<Runtime>>,
 {
-	fn is_allowed(_caller: H160, selector: Option<u32>) -> bool {
-		match selector {
-			None => true,
-			Some(selector) =>
-				!XcmUtilsPrecompileCall::<Runtime, XcmConfig>::xcm_execute_selectors()
-					.contains(&selector),
-		}
-	}
-
-	fn description() -> String {
-		"Allowed for all callers for all selectors except 'execute'".into()
+	fn get() -> Vec<u32> {
+		XcmUtilsPrecompileCall::<
+			Runtime,
+			XcmConfig,
+			AccountIdToLocation,
+			WeightToFee,
+		>::xcm_execute_selectors()
+		.to_vec()
 	}
 }
 
-/// A precompile to wrap the functionality from xcm-utils
-pub struct XcmUtilsPrecompile<Runtime, XcmConfig>(PhantomData<(Runtime, XcmConfig)>);
+/// Filter allowing all the selectors except the one of `xcmExecute`, which contracts cannot call.
+pub type AllExceptXcmExecute<Runtime, XcmConfig, AccountIdToLocation, WeightToFee> =
+	AllExcept<XcmExecuteSelectors<Runtime, XcmConfig, AccountIdToLocation, WeightToFee>>;
+
+/// A precompile to wrap the functionality from xcm-utils. `AccountIdToLocation` converts the
+/// accounts of the runtime to the locations other chains know them by, and `WeightToFee` converts
+/// weights to fees in the native token, as the runtime charges its transactions.
+pub struct XcmUtilsPrecompile<Runtime, XcmConfig, AccountIdToLocation, WeightToFee>(
+	PhantomData<(Runtime, XcmConfig, AccountIdToLocation, WeightToFee)>,
+);
 
 #[precompile_utils::precompile]
-impl<Runtime, XcmConfig> XcmUtilsPrecompile<Runtime, XcmConfig>
+impl<Runtime, XcmConfig, AccountIdToLocation, WeightToFee>
+	XcmUtilsPrecompile<Runtime, XcmConfig, AccountIdToLocation, WeightToFee>
 where
 	Runtime: pallet_evm::Config + frame_system::Config + pallet_xcm::Config,
 	XcmConfig: xcm_executor::Config,
+	AccountIdToLocation: Convert<Runtime::AccountId, MultiLocation>,
+	WeightToFee: frame_support::weights::WeightToFee,
+	WeightToFee::Balance: Into<U256>,
 	SystemCallOf<Runtime>: Dispatchable<PostInfo = PostDispatchInfo> + Decode + GetDispatchInfo,
 	<<Runtime as frame_system::Config>::RuntimeCall as Dispatchable>::RuntimeOrigin:
 		From<Option<Runtime::AccountId>>,
@@ -122,28 +194,273 @@ where
 		}
 	}
 
+	/// Returns the amount of `asset` the runtime charges for `weight`, as derived from its units
+	/// per second, so that contracts can build `BuyExecution` instructions buying exactly the
+	/// weight of their messages.
+	#[precompile::public("feeAssetForWeight((uint8,bytes[]),uint64)")]
+	#[precompile::view]
+	fn fee_asset_for_weight(
+		handle: &mut impl PrecompileHandle,
+		asset: MultiLocation,
+		weight: u64,
+	) -> EvmResult<U256> {
+		let units_per_second = Self::get_units_per_second(handle, asset).map_err(|e| match e {
+			PrecompileFailure::Revert { .. } => revert("asset not accepted for fees"),
+			e => e,
+		})?;
+
+		Ok(U256::from(weight) * units_per_second / U256::from(WEIGHT_REF_TIME_PER_SECOND))
+	}
+
+	/// Returns the location of the account `address` is mapped to, as the runtime converts
+	/// accounts to locations, e.g. for the beneficiaries of the transfers it sends.
+	#[precompile::public("addressToMultilocation(address)")]
+	#[precompile::view]
+	fn address_to_multilocation(
+		_handle: &mut impl PrecompileHandle,
+		address: Address,
+	) -> EvmResult<MultiLocation> {
+		let account = Runtime::AddressMapping::into_account_id(address.into());
+
+		Ok(AccountIdToLocation::convert(account))
+	}
+
 	#[precompile::public("weightMessage(bytes)")]
 	#[precompile::view]
 	fn weight_message(
 		_handle: &mut impl PrecompileHandle,
 		message: BoundedBytes<GetXcmSizeLimit>,
 	) -> EvmResult<u64> {
-		let message: Vec<u8> = message.into();
+		let mut message = Self::decode_message(message)?;
 
-		let msg =
-			VersionedXcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::decode_all_with_depth_limit(
-				MAX_XCM_DECODE_DEPTH,
-				&mut message.as_slice(),
-			)
-			.map(Xcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::try_from);
+		let weight =
+			XcmConfig::Weigher::weight(&mut message).map_err(|_| revert("failed weighting"))?;
 
-		let result = match msg {
-			Ok(Ok(mut x)) =>
-				XcmConfig::Weigher::weight(&mut x).map_err(|_| revert("failed weighting")),
-			_ => Err(RevertReason::custom("Failed decoding").in_field("message").into()),
-		};
+		Ok(weight.ref_time())
+	}
+
+	/// Returns the fee, in the native token, of the weight of a message, as `WeightToFee` converts
+	/// it. Unlike `getUnitsPerSecond`, it needs no asset to be registered as fee payment.
+	#[precompile::public("quoteXcmFeeNative(bytes)")]
+	#[precompile::view]
+	fn quote_xcm_fee_native(
+		_handle: &mut impl PrecompileHandle,
+		message: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<U256> {
+		let mut message = Self::decode_message(message)?;
+
+		let weight =
+			XcmConfig::Weigher::weight(&mut message).map_err(|_| revert("failed weighting"))?;
 
-		Ok(result?.ref_time())
+		Ok(WeightToFee::weight_to_fee(&weight).into())
+	}
+
+	/// Returns the weight of each instruction of a message, in order, as computed by the
+	/// weigher. Nested messages, like the one of `SetAppendix`, count towards the weight of the
+	/// instruction they belong to, so the weights add up to the one `weightMessage` returns.
+	#[precompile::public("instructionWeights(bytes)")]
+	#[precompile::view]
+	fn instruction_weights(
+		_handle: &mut impl PrecompileHandle,
+		message: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<Vec<u64>> {
+		let mut message = Self::decode_message(message)?;
+
+		// Weighting the whole message first enforces the limits of the weigher on it, e.g. its
+		// maximum number of instructions.
+		XcmConfig::Weigher::weight(&mut message).map_err(|_| revert("failed weighting"))?;
+
+		message
+			.0
+			.iter()
+			.enumerate()
+			.map(|(index, instruction)| {
+				XcmConfig::Weigher::instr_weight(instruction)
+					.map(|weight| weight.ref_time())
+					.map_err(|_| {
+						RevertReason::custom("failed weighting")
+							.in_array(index)
+							.in_field("message")
+							.into()
+					})
+			})
+			.collect()
+	}
+
+	/// Returns the names of the instructions of a message, in order, e.g. `WithdrawAsset`, to read
+	/// it without a SCALE decoder. The instructions of nested messages are not listed. The message
+	/// is decoded as a whole, so it reverts with "Failed decoding" if any of its instructions,
+	/// nested ones included, is unknown to the latest version, without naming the others.
+	#[precompile::public("describeXcm(bytes)")]
+	#[precompile::view]
+	fn describe_xcm(
+		_handle: &mut impl PrecompileHandle,
+		message: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<Vec<UnboundedString>> {
+		let message = Self::decode_message(message)?;
+
+		Ok(message
+			.0
+			.iter()
+			.map(|instruction| instruction_name(instruction).into())
+			.collect())
+	}
+
+	/// Returns the maximum number of assets the holding register of the executor can hold. A
+	/// message making it hold more traps its assets.
+	#[precompile::public("maxAssetsIntoHolding()")]
+	#[precompile::view]
+	fn max_assets_into_holding(_handle: &mut impl PrecompileHandle) -> EvmResult<u32> {
+		Ok(XcmConfig::MaxAssetsIntoHolding::get())
+	}
+
+	/// Returns the version of XCM negotiated with `dest`, or `UNKNOWN_XCM_VERSION` if there is
+	/// none yet, in which case `pallet_xcm` sends it messages of its safe version.
+	#[precompile::public("xcmVersionOf((uint8,bytes[]))")]
+	#[precompile::view]
+	fn xcm_version_of(handle: &mut impl PrecompileHandle, dest: MultiLocation) -> EvmResult<u32> {
+		// storage item: SupportedVersion
+		// max encoded len: hash (8) + u32 (4) + hash (16) + version (1) + Multilocation + u32 (4)
+		handle.record_db_read::<Runtime>(33 + MultiLocation::max_encoded_len())?;
+
+		Ok(pallet_xcm::Pallet::<Runtime>::get_version_for(&dest).unwrap_or(UNKNOWN_XCM_VERSION))
+	}
+
+	#[precompile::public("canReach((uint8,bytes[]))")]
+	#[precompile::view]
+	fn can_reach(handle: &mut impl PrecompileHandle, dest: MultiLocation) -> EvmResult<bool> {
+		// The router may read the state of the channel towards the destination.
+		handle.record_db_read::<Runtime>(MultiLocation::max_encoded_len())?;
+
+		Ok(validate_send::<<Runtime as pallet_xcm::Config>::XcmRouter>(dest, Xcm(vec![])).is_ok())
+	}
+
+	/// Returns `location` with the bytes of its general keys beyond their length zeroed, and the
+	/// bytes trailing its junctions dropped when decoding them, as neither is part of the location.
+	/// Nothing else is rewritten: locations reaching the same place through other junctions, e.g.
+	/// `(1, Parachain(own id))` and `(0, Here)`, still differ. Reverts if a general key is longer
+	/// than its 32 bytes of data.
+	#[precompile::public("normalizeLocation((uint8,bytes[]))")]
+	#[precompile::view]
+	fn normalize_location(
+		_handle: &mut impl PrecompileHandle,
+		location: MultiLocation,
+	) -> EvmResult<MultiLocation> {
+		let mut location = location;
+		for index in 0..location.interior.len() {
+			if let Some(Junction::GeneralKey { length, data }) = location.interior.at_mut(index) {
+				let length = usize::from(*length);
+				if length > data.len() {
+					return Err(RevertReason::custom("general key longer than 32 bytes")
+						.in_array(index)
+						.in_field("location")
+						.into())
+				}
+				data[length..].fill(0);
+			}
+		}
+
+		Ok(location)
+	}
+
+	/// Builds the SCALE-encoded versioned message withdrawing `fee_amount` of `fee_asset`, buying
+	/// execution with it and transacting `call` as the sovereign account of the sender, ready to
+	/// be passed to `xcmSend`.
+	#[precompile::public("buildTransact((uint8,bytes[]),uint64,bytes,uint64)")]
+	#[precompile::view]
+	fn build_transact(
+		_handle: &mut impl PrecompileHandle,
+		fee_asset: MultiLocation,
+		fee_amount: u64,
+		call: BoundedBytes<GetXcmSizeLimit>,
+		transact_weight: u64,
+	) -> EvmResult<UnboundedBytes> {
+		let fees: MultiAsset = (fee_asset, u128::from(fee_amount)).into();
+		let call: Vec<u8> = call.into();
+
+		let message = Xcm::<()>(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: Weight::from_parts(transact_weight, DEFAULT_PROOF_SIZE),
+				call: call.into(),
+			},
+		]);
+
+		Ok(VersionedXcm::V3(message).encode().into())
+	}
+
+	/// Returns whether `call`, SCALE-encoded, passes the `SafeCallFilter` of the executor, out of
+	/// which the calls of `Transact` instructions are not dispatched.
+	#[precompile::public("callIsSafe(bytes)")]
+	#[precompile::view]
+	fn call_is_safe(
+		_handle: &mut impl PrecompileHandle,
+		call: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<bool> {
+		let call: Vec<u8> = call.into();
+
+		// Decoded as the executor decodes the calls of `Transact` instructions.
+		let call = <XcmConfig as xcm_executor::Config>::RuntimeCall::decode_all_with_depth_limit(
+			MAX_XCM_DECODE_DEPTH,
+			&mut call.as_slice(),
+		)
+		.map_err(|_| RevertReason::custom("Failed decoding").in_field("call"))?;
+
+		Ok(XcmConfig::SafeCallFilter::contains(&call))
+	}
+
+	/// Returns whether `assets`, the SCALE-encoded versioned assets dropped by a message from
+	/// `origin`, are trapped and can be claimed.
+	#[precompile::public("assetsTrapped((uint8,bytes[]),bytes)")]
+	#[precompile::view]
+	fn assets_trapped(
+		handle: &mut impl PrecompileHandle,
+		origin: MultiLocation,
+		assets: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<bool> {
+		Self::trapped(handle, origin, assets)
+	}
+
+	/// Like `assetsTrapped`, with the location of the caller as the origin, so that the caller can
+	/// tell whether it can claim `assets` without building its own location.
+	#[precompile::public("canClaimTrapped(bytes)")]
+	#[precompile::view]
+	fn can_claim_trapped(
+		handle: &mut impl PrecompileHandle,
+		assets: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<bool> {
+		let caller = Runtime::AddressMapping::into_account_id(handle.context().caller);
+		let origin = AccountIdToLocation::convert(caller);
+
+		Self::trapped(handle, origin, assets)
+	}
+
+	/// Returns whether `assets`, SCALE-encoded, are trapped from `origin`.
+	fn trapped(
+		handle: &mut impl PrecompileHandle,
+		origin: MultiLocation,
+		assets: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<bool> {
+		// storage item: AssetTraps
+		// max encoded len: hash (32) + u32 (4)
+		handle.record_db_read::<Runtime>(36)?;
+
+		let assets: Vec<u8> = assets.into();
+		let assets = VersionedMultiAssets::decode_all_with_depth_limit(
+			MAX_XCM_DECODE_DEPTH,
+			&mut assets.as_slice(),
+		)
+		.ok()
+		.and_then(|assets| MultiAssets::try_from(assets).ok())
+		.ok_or_else(|| RevertReason::custom("Failed decoding").in_field("assets"))?;
+
+		// The assets are trapped under their latest version, like `pallet_xcm` does.
+		let versioned = VersionedMultiAssets::from(assets);
+		let hash = BlakeTwo256::hash_of(&(&origin, &versioned));
+
+		Ok(pallet_xcm::Pallet::<Runtime>::asset_trap(hash) > 0)
 	}
 
 	#[precompile::public("xcmExecute(bytes,uint64)")]
@@ -199,4 +516,28 @@ where
 
 		Ok(())
 	}
+
+	/// Like `xcmSend`, with the relay chain as the destination.
+	#[precompile::public("sendToRelay(bytes)")]
+	fn send_to_relay(
+		handle: &mut impl PrecompileHandle,
+		message: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult {
+		Self::xcm_send(handle, MultiLocation::parent(), message)
+	}
+
+	/// Decodes a SCALE-encoded versioned message into a message of the latest version.
+	fn decode_message(
+		message: BoundedBytes<GetXcmSizeLimit>,
+	) -> EvmResult<Xcm<<XcmConfig as xcm_executor::Config>::RuntimeCall>> {
+		let message: Vec<u8> = message.into();
+
+		VersionedXcm::<<XcmConfig as xcm_executor::Config>::RuntimeCall>::decode_all_with_depth_limit(
+			MAX_XCM_DECODE_DEPTH,
+			&mut message.as_slice(),
+		)
+		.ok()
+		.and_then(|message| Xcm::try_from(message).ok())
+		.ok_or_else(|| RevertReason::custom("Failed decoding").in_field("message").into())
+	}
 }
@@ -18,8 +18,11 @@
 use super::*;
 use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{ConstU32, EnsureOrigin, Everything, Nothing, OriginTrait, PalletInfo as _},
-	weights::{RuntimeDbWeight, Weight},
+	traits::{
+		ConstU128, ConstU32, Contains, EnsureOrigin, Everything, Nothing, OriginTrait,
+		PalletInfo as _,
+	},
+	weights::{ConstantMultiplier, RuntimeDbWeight, Weight},
 };
 use pallet_evm::{EnsureAddressNever, EnsureAddressRoot, GasWeightMapping};
 use precompile_utils::{precompile_set::*, testing::*};
@@ -212,18 +215,23 @@ impl pallet_xcm::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type ReachableDest = ReachableDest;
 }
+/// Fee of a weight in the native token: twice its `ref_time`.
+pub type WeightToFee = ConstantMultiplier<Balance, ConstU128<2>>;
+
 pub type Precompiles<R> = PrecompileSetBuilder<
 	R,
 	(
 		PrecompileAt<
 			AddressU64<1>,
-			XcmUtilsPrecompile<R, XcmConfig>,
-			CallableByContract<AllExceptXcmExecute<R, XcmConfig>>,
+			XcmUtilsPrecompile<R, XcmConfig, AccountIdToMultiLocation, WeightToFee>,
+			CallableByContract<
+				AllExceptXcmExecute<R, XcmConfig, AccountIdToMultiLocation, WeightToFee>,
+			>,
 		>,
 	),
 >;
 
-pub type PCall = XcmUtilsPrecompileCall<Runtime, XcmConfig>;
+pub type PCall = XcmUtilsPrecompileCall<Runtime, XcmConfig, AccountIdToMultiLocation, WeightToFee>;
 
 const MAX_POV_SIZE: u64 = 5 * 1024 * 1024;
 /// Block storage limit in bytes. Set to 40 KB.
@@ -313,19 +321,27 @@ thread_local! {
 pub fn sent_xcm() -> Vec<(MultiLocation, opaque::Xcm)> {
 	SENT_XCM.with(|q| (*q.borrow()).clone())
 }
+parameter_types! {
+	// Destination rejected by the router.
+	pub UnroutableLocation: MultiLocation = MultiLocation::new(1, Junctions::X1(Parachain(999)));
+}
 pub struct TestSendXcm;
 impl SendXcm for TestSendXcm {
-	type Ticket = ();
+	type Ticket = (MultiLocation, opaque::Xcm);
 
 	fn validate(
 		destination: &mut Option<MultiLocation>,
 		message: &mut Option<opaque::Xcm>,
 	) -> SendResult<Self::Ticket> {
-		SENT_XCM.with(|q| q.borrow_mut().push(((*destination).unwrap(), message.clone().unwrap())));
-		Ok(((), MultiAssets::new()))
+		if *destination == Some(UnroutableLocation::get()) {
+			return Err(SendError::NotApplicable)
+		}
+
+		Ok((((*destination).unwrap(), message.clone().unwrap()), MultiAssets::new()))
 	}
 
-	fn deliver(_: Self::Ticket) -> Result<XcmHash, SendError> {
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SENT_XCM.with(|q| q.borrow_mut().push(ticket));
 		Ok(XcmHash::default())
 	}
 }
@@ -413,7 +429,15 @@ impl xcm_executor::Config for XcmConfig {
 	type FeeManager = ();
 	type MessageExporter = ();
 	type UniversalAliases = Nothing;
-	type SafeCallFilter = Everything;
+	type SafeCallFilter = SafeCallFilter;
+}
+
+/// Lets the calls of `System` and `Balances` be transacted, and no other.
+pub struct SafeCallFilter;
+impl Contains<RuntimeCall> for SafeCallFilter {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(call, RuntimeCall::System(_) | RuntimeCall::Balances(_))
+	}
 }
 
 #[derive(Default)]
@@ -17,21 +17,40 @@
 use crate::mock::{
 	sent_xcm,
 	AccountId,
+	AccountIdToMultiLocation,
 	Balances,
 	ExtBuilder,
 	PCall,
 	// ParentAccount,
+	PolkadotXcm,
 	Precompiles,
 	PrecompilesValue,
 	Runtime,
+	RuntimeCall,
+	RuntimeOrigin,
 	//SiblingParachainAccount,
 	System,
+	UnroutableLocation,
+	WeightToFee,
+	XcmConfig,
+};
+use crate::{AllExcept, AllExceptXcmExecute, XcmUtilsPrecompile, UNKNOWN_XCM_VERSION};
+use fp_evm::Context;
+use frame_support::{
+	assert_ok, parameter_types,
+	traits::PalletInfo,
+	weights::{constants::WEIGHT_REF_TIME_PER_SECOND, Weight, WeightToFee as _},
+};
+use parity_scale_codec::{Decode, Encode};
+use precompile_utils::{
+	precompile_set::SelectorFilter,
+	prelude::{Address, UnboundedBytes, UnboundedString, DEFAULT_PROOF_SIZE},
+	solidity,
+	testing::*,
 };
-use frame_support::{traits::PalletInfo, weights::Weight};
-use parity_scale_codec::Encode;
-use precompile_utils::testing::*;
 use sp_core::{H160, U256};
-use xcm::prelude::*;
+use xcm::{prelude::*, VersionedMultiAssets};
+use xcm_executor::traits::{DropAssets, WeightBounds};
 
 fn precompiles() -> Precompiles<Runtime> {
 	PrecompilesValue::get()
@@ -39,8 +58,22 @@ fn precompiles() -> Precompiles<Runtime> {
 
 #[test]
 fn test_selector_enum() {
+	assert!(PCall::address_to_multilocation_selectors().contains(&0x2c6c182e));
 	assert!(PCall::weight_message_selectors().contains(&0x25d54154));
+	assert!(PCall::instruction_weights_selectors().contains(&0xce80064e));
+	assert!(PCall::describe_xcm_selectors().contains(&0xb5b4a64a));
+	assert!(PCall::quote_xcm_fee_native_selectors().contains(&0x21eae0c6));
+	assert!(PCall::max_assets_into_holding_selectors().contains(&0xcf791083));
 	assert!(PCall::get_units_per_second_selectors().contains(&0x3f0f65db));
+	assert!(PCall::fee_asset_for_weight_selectors().contains(&0x8524d790));
+	assert!(PCall::xcm_version_of_selectors().contains(&0xbbec1384));
+	assert!(PCall::can_reach_selectors().contains(&0xdb53ece3));
+	assert!(PCall::normalize_location_selectors().contains(&0xe8b43463));
+	assert!(PCall::build_transact_selectors().contains(&0xe9e6f0e0));
+	assert!(PCall::assets_trapped_selectors().contains(&0x04a2fd42));
+	assert!(PCall::can_claim_trapped_selectors().contains(&0x6cb7faa9));
+	assert!(PCall::call_is_safe_selectors().contains(&0xc9268347));
+	assert!(PCall::send_to_relay_selectors().contains(&0x79b15de0));
 }
 
 #[test]
@@ -52,8 +85,21 @@ fn modifiers() {
 			MockPeaqAccount::EVMu1Account,
 		);
 
+		tester.test_view_modifier(PCall::address_to_multilocation_selectors());
 		tester.test_view_modifier(PCall::weight_message_selectors());
+		tester.test_view_modifier(PCall::instruction_weights_selectors());
+		tester.test_view_modifier(PCall::describe_xcm_selectors());
+		tester.test_view_modifier(PCall::quote_xcm_fee_native_selectors());
+		tester.test_view_modifier(PCall::max_assets_into_holding_selectors());
 		tester.test_view_modifier(PCall::get_units_per_second_selectors());
+		tester.test_view_modifier(PCall::fee_asset_for_weight_selectors());
+		tester.test_view_modifier(PCall::xcm_version_of_selectors());
+		tester.test_view_modifier(PCall::can_reach_selectors());
+		tester.test_view_modifier(PCall::normalize_location_selectors());
+		tester.test_view_modifier(PCall::build_transact_selectors());
+		tester.test_view_modifier(PCall::assets_trapped_selectors());
+		tester.test_view_modifier(PCall::can_claim_trapped_selectors());
+		tester.test_view_modifier(PCall::call_is_safe_selectors());
 	});
 }
 
@@ -72,6 +118,128 @@ fn test_weight_message() {
 	});
 }
 
+#[test]
+fn test_quote_xcm_fee_native() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message: Vec<u8> =
+			xcm::VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin, ClearTopic])).encode();
+		let weight = <XcmConfig as xcm_executor::Config>::Weigher::weight(&mut Xcm(vec![
+			ClearOrigin,
+			ClearTopic,
+		]))
+		.unwrap();
+		// The mock charges twice the `ref_time` of the weight.
+		assert_eq!(WeightToFee::weight_to_fee(&weight), 2 * 2000);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::quote_xcm_fee_native { message: message.into() },
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(U256::from(WeightToFee::weight_to_fee(&weight)));
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::quote_xcm_fee_native { message: vec![1u8, 2u8, 3u8].into() },
+			)
+			.execute_reverts(|output| output == b"message: Failed decoding");
+	});
+}
+
+#[test]
+fn test_instruction_weights() {
+	ExtBuilder::default().build().execute_with(|| {
+		let message: Vec<u8> = xcm::VersionedXcm::<()>::V3(Xcm(vec![
+			ClearOrigin,
+			SetAppendix(Xcm(vec![ClearOrigin, ClearOrigin])),
+			ClearTopic,
+		]))
+		.encode();
+
+		// The nested message counts towards the weight of `SetAppendix`.
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::instruction_weights { message: message.clone().into() },
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(vec![1000u64, 3000u64, 1000u64]);
+
+		// Which add up to the weight of the whole message.
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::weight_message { message: message.into() },
+			)
+			.execute_returns(5000u64);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::instruction_weights { message: vec![1u8, 2u8, 3u8].into() },
+			)
+			.execute_reverts(|output| output == b"message: Failed decoding");
+	});
+}
+
+#[test]
+fn test_describe_xcm() {
+	ExtBuilder::default().build().execute_with(|| {
+		let fees: MultiAsset = (MultiLocation::parent(), 100u128).into();
+		let message: Vec<u8> = xcm::VersionedXcm::<()>::V3(Xcm(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Unlimited },
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: MultiLocation::parent() },
+			SetAppendix(Xcm(vec![ClearOrigin])),
+		]))
+		.encode();
+
+		// The instructions of the nested message are not listed.
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::describe_xcm { message: message.into() },
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(vec![
+				UnboundedString::from("WithdrawAsset"),
+				UnboundedString::from("BuyExecution"),
+				UnboundedString::from("DepositAsset"),
+				UnboundedString::from("SetAppendix"),
+			]);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::describe_xcm { message: vec![1u8, 2u8, 3u8].into() },
+			)
+			.execute_reverts(|output| output == b"message: Failed decoding");
+
+		// A single unknown instruction fails the whole message, the others are not named.
+		let mut message = xcm::VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin, ClearOrigin])).encode();
+		*message.last_mut().expect("the message is not empty") = 0xff;
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::describe_xcm { message: message.into() },
+			)
+			.execute_reverts(|output| output == b"message: Failed decoding");
+	});
+}
+
 #[test]
 fn test_get_units_per_second() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -85,6 +253,376 @@ fn test_get_units_per_second() {
 	});
 }
 
+#[test]
+fn test_fee_asset_for_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		let units_per_second = 1_000_000_000_000u128;
+		let weight = 123_456_789u64;
+
+		precompiles
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::fee_asset_for_weight { asset: MultiLocation::parent(), weight },
+			)
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(U256::from(
+				weight as u128 * units_per_second / WEIGHT_REF_TIME_PER_SECOND as u128,
+			));
+
+		// Only the relay token pays for the fees of the mock.
+		precompiles
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::fee_asset_for_weight { asset: MultiLocation::here(), weight },
+			)
+			.execute_reverts(|output| output == b"asset not accepted for fees");
+	});
+}
+
+#[test]
+fn test_address_to_multilocation() {
+	ExtBuilder::default().build().execute_with(|| {
+		let address: H160 = MockPeaqAccount::Alice.into();
+		let location = MultiLocation::new(
+			0,
+			X1(AccountKey20 { network: None, key: *address.as_fixed_bytes() }),
+		);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::address_to_multilocation { address: Address(address) },
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(location);
+
+		// The key of the location leads back to the address.
+		match location.interior {
+			X1(AccountKey20 { key, .. }) => assert_eq!(H160(key), address),
+			interior => panic!("unexpected interior {interior:?}"),
+		}
+	});
+}
+
+#[test]
+fn test_max_assets_into_holding() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::max_assets_into_holding {},
+			)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(64u32);
+	});
+}
+
+#[test]
+fn test_xcm_version_of() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		let xcm_version_of = |dest: MultiLocation| {
+			precompiles.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::xcm_version_of { dest },
+			)
+		};
+
+		xcm_version_of(MultiLocation::parent())
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(UNKNOWN_XCM_VERSION);
+
+		assert_ok!(PolkadotXcm::force_xcm_version(
+			RuntimeOrigin::root(),
+			Box::new(MultiLocation::parent()),
+			2
+		));
+		xcm_version_of(MultiLocation::parent())
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(2u32);
+
+		// The version is negotiated with each destination.
+		xcm_version_of(MultiLocation::new(1, X1(Parachain(2000))))
+			.execute_returns(UNKNOWN_XCM_VERSION);
+	});
+}
+
+#[test]
+fn test_can_reach() {
+	ExtBuilder::default().build().execute_with(|| {
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::can_reach { dest: MultiLocation::parent() },
+			)
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::can_reach { dest: UnroutableLocation::get() },
+			)
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		// Nothing is sent to a reachable destination either.
+		assert!(sent_xcm().is_empty());
+	});
+}
+
+#[test]
+fn test_normalize_location() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		let normalize_location = |location: MultiLocation| {
+			precompiles.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::normalize_location { location },
+			)
+		};
+		let general_key = |length: u8, tail: u8| {
+			let mut data = [tail; 32];
+			data[..2].copy_from_slice(&[1, 2]);
+			GeneralKey { length, data }
+		};
+
+		let normalized = MultiLocation::new(1, X2(Parachain(2000), general_key(2, 0)));
+		normalize_location(normalized)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(normalized);
+		normalize_location(MultiLocation::new(1, X2(Parachain(2000), general_key(2, 0xff))))
+			.execute_returns(normalized);
+
+		// The bytes trailing a junction are dropped.
+		let mut parachain = vec![0u8];
+		parachain.extend(2000u32.to_be_bytes());
+		parachain.extend([0xaa, 0xbb]);
+		let raw_location = |junction: Vec<u8>| {
+			solidity::encode_with_selector(
+				0xe8b43463u32,
+				((1u8, vec![UnboundedBytes::from(junction)]),),
+			)
+		};
+		precompiles
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				raw_location(parachain),
+			)
+			.execute_returns(MultiLocation::new(1, X1(Parachain(2000))));
+
+		normalize_location(MultiLocation::new(1, X2(Parachain(2000), general_key(33, 0))))
+			.execute_reverts(|output| output.ends_with(b"general key longer than 32 bytes"));
+		precompiles
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				raw_location(vec![0xff]),
+			)
+			.execute_reverts(|output| output.ends_with(b"Unknown Junction variant"));
+	});
+}
+
+#[test]
+fn test_build_transact() {
+	ExtBuilder::default().build().execute_with(|| {
+		let call = vec![1u8, 2, 3, 4];
+
+		let mut handle = MockHandle::new(
+			MockPeaqAccount::EVMu1Account.into(),
+			Context {
+				address: MockPeaqAccount::EVMu1Account.into(),
+				caller: MockPeaqAccount::Alice.into(),
+				apparent_value: U256::zero(),
+			},
+		);
+		let message: Vec<u8> =
+			XcmUtilsPrecompile::<Runtime, XcmConfig, AccountIdToMultiLocation>::build_transact(
+				&mut handle,
+				MultiLocation::parent(),
+				1000u64,
+				call.clone().into(),
+				5000u64,
+			)
+			.expect("message is built")
+			.into();
+
+		let fees: MultiAsset = (MultiLocation::parent(), 1000u128).into();
+		let expected = Xcm::<()>(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Unlimited },
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				require_weight_at_most: Weight::from_parts(5000u64, DEFAULT_PROOF_SIZE),
+				call: call.clone().into(),
+			},
+		]);
+		match VersionedXcm::<()>::decode(&mut message.as_slice()) {
+			Ok(VersionedXcm::V3(decoded)) => assert_eq!(decoded, expected),
+			other => panic!("unexpected message {other:?}"),
+		}
+
+		// The message is returned as solidity bytes.
+		let input = PCall::build_transact {
+			fee_asset: MultiLocation::parent(),
+			fee_amount: 1000u64,
+			call: call.into(),
+			transact_weight: 5000u64,
+		};
+		precompiles()
+			.prepare_test(MockPeaqAccount::Alice, MockPeaqAccount::EVMu1Account, input)
+			.expect_cost(0)
+			.expect_no_logs()
+			.execute_returns(UnboundedBytes::from(message));
+	});
+}
+
+#[test]
+fn test_call_is_safe() {
+	ExtBuilder::default().build().execute_with(|| {
+		let transfer = RuntimeCall::Balances(pallet_balances::Call::transfer {
+			dest: MockPeaqAccount::Bob,
+			value: 100,
+		});
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::call_is_safe { call: transfer.encode().into() },
+			)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		// The filter of the mock lets no call of `Timestamp` be transacted.
+		let set_timestamp = RuntimeCall::Timestamp(pallet_timestamp::Call::set { now: 1 });
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::call_is_safe { call: set_timestamp.encode().into() },
+			)
+			.expect_no_logs()
+			.execute_returns(false);
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::call_is_safe { call: vec![0xff, 0xff].into() },
+			)
+			.execute_reverts(|output| output == b"call: Failed decoding");
+	});
+}
+
+#[test]
+fn test_assets_trapped() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		let origin = MultiLocation::new(1, X1(Parachain(2000)));
+		let trapped: MultiAssets = vec![(MultiLocation::parent(), 100u128).into()].into();
+		let assets_trapped = |origin: MultiLocation, assets: Vec<u8>| {
+			precompiles.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::assets_trapped { origin, assets: assets.into() },
+			)
+		};
+
+		PolkadotXcm::drop_assets(
+			&origin,
+			trapped.clone().into(),
+			&XcmContext { origin: None, message_hash: [0; 32], topic: None },
+		);
+
+		assets_trapped(origin, VersionedMultiAssets::from(trapped.clone()).encode())
+			.expect_cost(1)
+			.expect_no_logs()
+			.execute_returns(true);
+
+		// Assets encoded in an older version are found as well.
+		let v2_trapped =
+			xcm::v2::MultiAssets::try_from(trapped.clone()).expect("convertible to v2");
+		assets_trapped(origin, VersionedMultiAssets::V2(v2_trapped).encode())
+			.expect_no_logs()
+			.execute_returns(true);
+
+		// Other assets, or the same ones trapped from another origin, are not.
+		let other: MultiAssets = vec![(MultiLocation::parent(), 99u128).into()].into();
+		assets_trapped(origin, VersionedMultiAssets::from(other).encode())
+			.expect_no_logs()
+			.execute_returns(false);
+		assets_trapped(MultiLocation::parent(), VersionedMultiAssets::from(trapped).encode())
+			.expect_no_logs()
+			.execute_returns(false);
+
+		assets_trapped(origin, vec![0xff, 0x01])
+			.execute_reverts(|output| output == b"assets: Failed decoding");
+	});
+}
+
+#[test]
+fn test_can_claim_trapped() {
+	ExtBuilder::default().build().execute_with(|| {
+		let precompiles = precompiles();
+		let trapped: MultiAssets = vec![(MultiLocation::parent(), 100u128).into()].into();
+		let can_claim_trapped = |caller: MockPeaqAccount, assets: Vec<u8>| {
+			precompiles.prepare_test(
+				caller,
+				MockPeaqAccount::EVMu1Account,
+				PCall::can_claim_trapped { assets: assets.into() },
+			)
+		};
+
+		// Assets trapped from the location of Alice's account.
+		let alice: H160 = MockPeaqAccount::Alice.into();
+		let origin =
+			MultiLocation::new(0, X1(AccountKey20 { network: None, key: *alice.as_fixed_bytes() }));
+		PolkadotXcm::drop_assets(
+			&origin,
+			trapped.clone().into(),
+			&XcmContext { origin: None, message_hash: [0; 32], topic: None },
+		);
+
+		can_claim_trapped(
+			MockPeaqAccount::Alice,
+			VersionedMultiAssets::from(trapped.clone()).encode(),
+		)
+		.expect_cost(1)
+		.expect_no_logs()
+		.execute_returns(true);
+
+		// Bob cannot claim them, nor can Alice claim other assets.
+		can_claim_trapped(MockPeaqAccount::Bob, VersionedMultiAssets::from(trapped).encode())
+			.expect_no_logs()
+			.execute_returns(false);
+		let other: MultiAssets = vec![(MultiLocation::parent(), 99u128).into()].into();
+		can_claim_trapped(MockPeaqAccount::Alice, VersionedMultiAssets::from(other).encode())
+			.expect_no_logs()
+			.execute_returns(false);
+
+		can_claim_trapped(MockPeaqAccount::Alice, vec![0xff, 0x01])
+			.execute_reverts(|output| output == b"assets: Failed decoding");
+	});
+}
+
 #[test]
 fn test_executor_clear_origin() {
 	ExtBuilder::default().build().execute_with(|| {
@@ -193,6 +731,29 @@ fn test_send_clear_origin() {
 	})
 }
 
+#[test]
+fn test_send_to_relay() {
+	ExtBuilder::default().build().execute_with(|| {
+		let xcm_to_send = VersionedXcm::<()>::V3(Xcm(vec![ClearOrigin])).encode();
+
+		precompiles()
+			.prepare_test(
+				MockPeaqAccount::Alice,
+				MockPeaqAccount::EVMu1Account,
+				PCall::send_to_relay { message: xcm_to_send.into() },
+			)
+			// Only the cost of TestWeightInfo
+			.expect_cost(100000000)
+			.expect_no_logs()
+			.execute_returns(());
+
+		assert_eq!(sent_xcm().len(), 1);
+		let (destination, sent_message) = sent_xcm().pop().unwrap();
+		assert_eq!(destination, MultiLocation::parent());
+		assert!(sent_message.0.contains(&ClearOrigin));
+	})
+}
+
 #[test]
 fn execute_fails_if_called_by_smart_contract() {
 	ExtBuilder::default()
@@ -215,6 +776,39 @@ fn execute_fails_if_called_by_smart_contract() {
 		})
 }
 
+parameter_types! {
+	pub ExecuteAndSend: Vec<u32> =
+		[PCall::xcm_execute_selectors(), PCall::xcm_send_selectors()].concat();
+	pub NothingBlocked: Vec<u32> = Vec::new();
+}
+
+#[test]
+fn all_except_blocks_only_the_given_selectors() {
+	type OnlyExecute =
+		AllExceptXcmExecute<Runtime, XcmConfig, AccountIdToMultiLocation, WeightToFee>;
+	let caller: H160 = MockPeaqAccount::Alice.into();
+	let execute = PCall::xcm_execute_selectors()[0];
+	let send = PCall::xcm_send_selectors()[0];
+	let send_to_relay = PCall::send_to_relay_selectors()[0];
+
+	assert!(!OnlyExecute::is_allowed(caller, Some(execute)));
+	assert!(OnlyExecute::is_allowed(caller, Some(send)));
+	assert!(OnlyExecute::is_allowed(caller, Some(send_to_relay)));
+	assert!(OnlyExecute::is_allowed(caller, None));
+
+	assert!(!AllExcept::<ExecuteAndSend>::is_allowed(caller, Some(execute)));
+	assert!(!AllExcept::<ExecuteAndSend>::is_allowed(caller, Some(send)));
+	assert!(AllExcept::<ExecuteAndSend>::is_allowed(caller, Some(send_to_relay)));
+	assert!(AllExcept::<ExecuteAndSend>::is_allowed(caller, None));
+	assert_eq!(
+		AllExcept::<ExecuteAndSend>::description(),
+		format!("Allowed for all callers for all selectors except {execute:#010x}, {send:#010x}")
+	);
+
+	assert!(AllExcept::<NothingBlocked>::is_allowed(caller, Some(execute)));
+	assert!(AllExcept::<NothingBlocked>::is_allowed(caller, Some(send)));
+}
+
 #[test]
 fn test_solidity_interface_has_all_function_selectors_documented_and_implemented() {
 	check_precompile_implements_solidity_interfaces(&["XcmUtils.sol"], PCall::supports_selector)
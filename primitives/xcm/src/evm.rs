@@ -1,5 +1,5 @@
 use crate::{AccountId, AssetId};
-use frame_support::ensure;
+use frame_support::{ensure, weights::Weight};
 use pallet_assets::AssetsCallback;
 use sp_core::{H160, U256};
 use sp_std::marker::PhantomData;
@@ -23,6 +23,12 @@ pub trait EVMAddressToAssetId<AssetId> {
 
 	// Get address from AssetId
 	fn asset_id_to_address(asset_id: AssetId) -> H160;
+
+	// Weight of the storage reads of `asset_id_to_address`, e.g. to look up the address of a
+	// remapped id. Zero for the derivations which only compute the address.
+	fn asset_id_to_address_weight() -> Weight {
+		Weight::zero()
+	}
 }
 
 pub struct EvmRevertCodeHandler<A, R>(PhantomData<(A, R)>);
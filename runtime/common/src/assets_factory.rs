@@ -0,0 +1,54 @@
+//! Configuration of the assets factory precompile shared by the runtimes. The values depending on
+//! the block time or on the precompile addresses of a runtime are set by the runtime itself.
+
+use frame_support::{parameter_types, traits::Contains};
+use sp_core::H160;
+use sp_runtime::traits::Convert;
+use sp_std::{marker::PhantomData, vec::Vec};
+
+/// Ids of the assets registered in `xc_asset_config`, which the assets factory reports as
+/// cross-chain assets.
+pub struct XcAssetIds<T>(PhantomData<T>);
+
+impl<T> Contains<u64> for XcAssetIds<T>
+where
+	T: xc_asset_config::Config,
+	T::AssetId: TryFrom<u64>,
+{
+	fn contains(id: &u64) -> bool {
+		T::AssetId::try_from(*id).map_or(false, |asset_id| {
+			xc_asset_config::Pallet::<T>::asset_id_to_location(asset_id).is_some()
+		})
+	}
+}
+
+/// Maps the accounts back to the addresses they claimed through `address_unification`. The other
+/// accounts are derived by hashing their address, which cannot be reversed.
+pub struct ClaimedEvmAddresses<T>(PhantomData<T>);
+
+impl<T: address_unification::Config> Convert<T::AccountId, Option<H160>>
+	for ClaimedEvmAddresses<T>
+{
+	fn convert(account: T::AccountId) -> Option<H160> {
+		address_unification::Pallet::<T>::evm_addresses(account)
+	}
+}
+
+parameter_types! {
+	pub const AssetsFactoryMaxCreatesPerBlock: Option<u32> = Some(10);
+	pub const AssetsFactoryMaxIdReservations: u32 = 10;
+	pub const AssetsFactoryEmitBatchEvents: bool = true;
+	// Transfer fees stay disabled until a treasury EVM address is decided on.
+	pub const AssetsFactoryMaxTransferFeeBps: u16 = 0;
+	pub AssetsFactoryTransferFeeTreasury: H160 = H160::zero();
+	pub const AssetsFactoryDefaultDecimals: u8 = 18;
+	pub const AssetsFactoryRolesMustBeDistinct: bool = false;
+	pub const AssetsFactoryOnlyInitiatorFinishesDestroy: bool = false;
+	// The minimum balances are in the units of each asset, which the existential deposit of the
+	// native token says nothing about, so there is no floor until one is decided on.
+	pub const AssetsFactoryMinBalanceFloor: Option<u128> = None;
+	// Assets are created without metadata, which their owners set.
+	pub AssetsFactoryDefaultMetadata: Option<(Vec<u8>, Vec<u8>)> = None;
+	// The migrations of `pallet_assets` run in `on_runtime_upgrade`, within a single block.
+	pub const AssetsFactoryAssetsMigrating: bool = false;
+}
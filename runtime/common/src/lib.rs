@@ -16,6 +16,8 @@ pub mod xcm_impls;
 pub use xcm_impls::*;
 pub mod wrapper;
 pub use wrapper::*;
+pub mod assets_factory;
+pub use assets_factory::*;
 pub mod zenlink;
 pub use zenlink::*;
 
@@ -1,4 +1,4 @@
-use crate::xcm_config::XcmConfig;
+use crate::{xcm_config::XcmConfig, WeightToFee};
 use frame_support::parameter_types;
 use pallet_evm_precompile_assets_erc20::Erc20AssetsPrecompileSet;
 use pallet_evm_precompile_assets_factory::AssetsFactoryPrecompile;
@@ -14,6 +14,7 @@ use pallet_evm_precompile_simple::{ECRecover, ECRecoverPublicKey, Identity, Ripe
 use pallet_evm_precompile_xcm_utils::XcmUtilsPrecompile;
 use pallet_evm_precompile_xtokens::XtokensPrecompile;
 use precompile_utils::precompile_set::*;
+use runtime_common::AccountIdToMultiLocation;
 
 type EthereumPrecompilesChecks = (AcceptDelegateCall, CallableByContract, CallableByPrecompile);
 
@@ -77,9 +78,14 @@ pub type PeaqPrecompiles<R> = PrecompileSetBuilder<
 				>,
 				PrecompileAt<
 					AddressU64<2052>,
-					XcmUtilsPrecompile<R, XcmConfig>,
+					XcmUtilsPrecompile<R, XcmConfig, AccountIdToMultiLocation, WeightToFee>,
 					CallableByContract<
-						pallet_evm_precompile_xcm_utils::AllExceptXcmExecute<R, XcmConfig>,
+						pallet_evm_precompile_xcm_utils::AllExceptXcmExecute<
+							R,
+							XcmConfig,
+							AccountIdToMultiLocation,
+							WeightToFee,
+						>,
 					>,
 				>,
 				PrecompileAt<
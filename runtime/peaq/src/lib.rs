@@ -2013,3 +2013,31 @@ impl EVMAddressToAssetId<AssetId> for Runtime {
 		AssetIdToEVMAddress::<EVMAssetPrefix>::convert(asset_id)
 	}
 }
+
+parameter_types! {
+	pub const AssetsFactoryDestroyTokenLifetime: BlockNumber = 10 * MINUTES;
+	pub const AssetsFactoryIdReservationLifetime: BlockNumber = 10 * MINUTES;
+	// The ERC20 facade of the assets is the assets-erc20 precompile set at `EVMAssetPrefix`, whose
+	// prefix is taken as is so that the two cannot drift apart.
+	pub AssetsFactoryErc20FacadePrefix: Option<[u8; 4]> = EVMAssetPrefix::get().try_into().ok();
+}
+
+impl pallet_evm_precompile_assets_factory::AssetsFactoryConfig for Runtime {
+	type ForceOrigin = EnsureRoot<AccountId>;
+	type MaxCreatesPerBlock = runtime_common::AssetsFactoryMaxCreatesPerBlock;
+	type DestroyTokenLifetime = AssetsFactoryDestroyTokenLifetime;
+	type IdReservationLifetime = AssetsFactoryIdReservationLifetime;
+	type MaxIdReservations = runtime_common::AssetsFactoryMaxIdReservations;
+	type EmitBatchEvents = runtime_common::AssetsFactoryEmitBatchEvents;
+	type MinBalanceFloor = runtime_common::AssetsFactoryMinBalanceFloor;
+	type XcAssetIds = runtime_common::XcAssetIds<Runtime>;
+	type AccountToAddress = runtime_common::ClaimedEvmAddresses<Runtime>;
+	type MaxTransferFeeBps = runtime_common::AssetsFactoryMaxTransferFeeBps;
+	type TransferFeeTreasury = runtime_common::AssetsFactoryTransferFeeTreasury;
+	type DefaultDecimals = runtime_common::AssetsFactoryDefaultDecimals;
+	type DefaultMetadata = runtime_common::AssetsFactoryDefaultMetadata;
+	type RolesMustBeDistinct = runtime_common::AssetsFactoryRolesMustBeDistinct;
+	type OnlyInitiatorFinishesDestroy = runtime_common::AssetsFactoryOnlyInitiatorFinishesDestroy;
+	type Erc20FacadePrefix = AssetsFactoryErc20FacadePrefix;
+	type AssetsMigrating = runtime_common::AssetsFactoryAssetsMigrating;
+}